@@ -30,6 +30,7 @@ fn create_mock_issues(count: usize) -> Vec<Issue> {
             },
             team: Some(format!("Team {}", i % 3)),
             team_id: format!("team-{}", i % 3),
+            ..Default::default()
         })
         .collect()
 }
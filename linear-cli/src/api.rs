@@ -0,0 +1,127 @@
+// ABOUTME: Argument parsing for `linear api`, the raw GraphQL passthrough command
+// ABOUTME: Reads the query document from an argument, a file, or stdin and parses -F variables
+
+use serde_json::Value;
+use std::io::{IsTerminal, Read};
+use std::str::FromStr;
+
+/// A `-F key=value` GraphQL variable
+///
+/// Values that parse as JSON (numbers, booleans, null, arrays, objects, quoted strings) are sent
+/// as that JSON; anything else is sent as a plain string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiField {
+    pub name: String,
+    pub value: Value,
+}
+
+impl FromStr for ApiField {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (name, raw) = input
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid field '{input}': expected key=value"))?;
+
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(format!(
+                "Invalid field '{input}': the variable name is empty"
+            ));
+        }
+
+        let value = serde_json::from_str(raw).unwrap_or_else(|_| Value::String(raw.to_string()));
+        Ok(Self {
+            name: name.to_string(),
+            value,
+        })
+    }
+}
+
+/// Load the GraphQL document: `@path` reads a file, `-` or no argument reads stdin, and
+/// anything else is the query itself
+pub fn read_query(source: Option<&str>) -> Result<String, String> {
+    let query = match source {
+        Some(path) if path.starts_with('@') => {
+            let path = &path[1..];
+            std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read query file '{path}': {e}"))?
+        }
+        Some(query) if query != "-" => query.to_string(),
+        _ => {
+            if std::io::stdin().is_terminal() {
+                return Err(
+                    "No query provided. Pass a query, @file, or pipe a query to stdin.".to_string(),
+                );
+            }
+            let mut buffer = String::new();
+            std::io::stdin()
+                .read_to_string(&mut buffer)
+                .map_err(|e| format!("Failed to read query from stdin: {e}"))?;
+            buffer
+        }
+    };
+
+    if query.trim().is_empty() {
+        return Err("GraphQL query cannot be empty".to_string());
+    }
+
+    Ok(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn field(input: &str) -> ApiField {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn test_field_values_parse_as_json_when_possible() {
+        assert_eq!(field("first=10").value, json!(10));
+        assert_eq!(field("archived=true").value, json!(true));
+        assert_eq!(field("cursor=null").value, json!(null));
+        assert_eq!(field("ids=[\"a\",\"b\"]").value, json!(["a", "b"]));
+        assert_eq!(
+            field("filter={\"number\":{\"eq\":1}}").value,
+            json!({ "number": { "eq": 1 } })
+        );
+    }
+
+    #[test]
+    fn test_field_values_fall_back_to_strings() {
+        assert_eq!(field("id=ENG-123").value, json!("ENG-123"));
+        assert_eq!(field("title=a=b").value, json!("a=b"));
+        assert_eq!(field("quoted=\"42\"").value, json!("42"));
+        assert_eq!(field("empty=").value, json!(""));
+        assert_eq!(field("id=ENG-123").name, "id");
+    }
+
+    #[test]
+    fn test_field_requires_key_and_value() {
+        assert!("first".parse::<ApiField>().is_err());
+        assert!("=10".parse::<ApiField>().is_err());
+    }
+
+    #[test]
+    fn test_read_query_from_argument_and_file() {
+        assert_eq!(
+            read_query(Some("{ viewer { id } }")).unwrap(),
+            "{ viewer { id } }"
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("query.graphql");
+        std::fs::write(&path, "query { teams { nodes { key } } }").unwrap();
+        let source = format!("@{}", path.display());
+        assert_eq!(
+            read_query(Some(&source)).unwrap(),
+            "query { teams { nodes { key } } }"
+        );
+
+        assert!(read_query(Some("@/nonexistent/query.graphql")).is_err());
+        assert!(read_query(Some("  ")).is_err());
+    }
+}
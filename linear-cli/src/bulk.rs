@@ -0,0 +1,435 @@
+// ABOUTME: Target selection and execution for bulk issue commands: IDs, `-` for stdin, --where
+// ABOUTME: Runs one mutation per issue concurrently behind a progress bar and reports failures
+
+use futures::stream::{self, StreamExt};
+use indicatif::{ProgressBar, ProgressStyle};
+use linear_sdk::{IssueFilters, LinearError, Result};
+use std::future::Future;
+use std::io::{BufRead, IsTerminal, Read};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::cli_output::CliOutput;
+use crate::constants::bulk::MAX_CONCURRENT_MUTATIONS;
+
+const WHERE_HELP: &str = "use key:value terms such as label:stale, state:todo, assignee:me, \
+     team:ENG, project:Web, cycle:current, priority:2, type:started, parent:ENG-1, \
+     updated:7d, created:2w, due:14d, is:overdue, is:unestimated, or is:archived";
+
+/// Set once `-` has read issue IDs from stdin, leaving nothing there to answer a prompt
+static STDIN_CONSUMED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(windows)]
+const TERMINAL_DEVICE: &str = "CONIN$";
+#[cfg(not(windows))]
+const TERMINAL_DEVICE: &str = "/dev/tty";
+
+const STATE_TYPES: &[&str] = &[
+    "triage",
+    "backlog",
+    "unstarted",
+    "started",
+    "completed",
+    "canceled",
+];
+
+/// Split a list of identifiers on whitespace and commas, dropping duplicates
+pub fn parse_ids(input: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in input.split(|c: char| c.is_whitespace() || c == ',') {
+        if !id.is_empty() && !ids.iter().any(|seen| seen == id) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+/// Replace a `-` argument with the identifiers piped to stdin
+pub fn expand_ids(ids: Vec<String>) -> std::result::Result<Vec<String>, String> {
+    expand_ids_with(ids, || {
+        if std::io::stdin().is_terminal() {
+            return Err("'-' reads issue IDs from stdin, but nothing was piped in".to_string());
+        }
+        let mut buffer = String::new();
+        std::io::stdin()
+            .read_to_string(&mut buffer)
+            .map_err(|e| format!("Failed to read issue IDs from stdin: {e}"))?;
+        STDIN_CONSUMED.store(true, Ordering::Relaxed);
+        Ok(buffer)
+    })
+}
+
+/// Read the answer to a confirmation prompt, from the terminal itself when `-` has already
+/// drained stdin
+pub fn read_confirmation() -> std::result::Result<String, String> {
+    read_confirmation_with(
+        STDIN_CONSUMED.load(Ordering::Relaxed),
+        || {
+            let mut line = String::new();
+            std::io::stdin().read_line(&mut line).map(|_| line)
+        },
+        || {
+            let mut line = String::new();
+            let terminal = std::fs::File::open(TERMINAL_DEVICE)?;
+            std::io::BufReader::new(terminal)
+                .read_line(&mut line)
+                .map(|_| line)
+        },
+    )
+}
+
+fn read_confirmation_with(
+    stdin_consumed: bool,
+    read_stdin: impl FnOnce() -> std::io::Result<String>,
+    read_terminal: impl FnOnce() -> std::io::Result<String>,
+) -> std::result::Result<String, String> {
+    if !stdin_consumed {
+        return read_stdin().map_err(|e| format!("Failed to read confirmation: {e}"));
+    }
+    read_terminal().map_err(|e| {
+        format!(
+            "Issue IDs were read from stdin and the terminal can't be opened to confirm ({e}); \
+             pass --force to skip confirmation"
+        )
+    })
+}
+
+fn expand_ids_with(
+    ids: Vec<String>,
+    read_stdin: impl FnOnce() -> std::result::Result<String, String>,
+) -> std::result::Result<Vec<String>, String> {
+    let mut read_stdin = Some(read_stdin);
+    let mut expanded = String::new();
+    for id in ids {
+        if id == "-" {
+            // Only the first `-` can read anything; stdin is empty afterwards
+            if let Some(read) = read_stdin.take() {
+                expanded.push_str(&read()?);
+            }
+        } else {
+            expanded.push_str(&id);
+        }
+        expanded.push('\n');
+    }
+    Ok(parse_ids(&expanded))
+}
+
+/// Whether a command-line argument names an issue rather than being free text
+pub fn looks_like_issue_id(value: &str) -> bool {
+    if value == "-" {
+        return true;
+    }
+    if value.len() == 36 && value.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
+        return true;
+    }
+    match value.rsplit_once('-') {
+        Some((team, number)) => {
+            !team.is_empty()
+                && team.chars().all(|c| c.is_ascii_alphanumeric())
+                && !number.is_empty()
+                && number.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Split a `--where` expression into terms, keeping double-quoted values together
+fn split_terms(expr: &str) -> std::result::Result<Vec<String>, String> {
+    let mut terms = Vec::new();
+    let mut term = String::new();
+    let mut quoted = false;
+
+    for c in expr.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            }
+            c => term.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(format!("Invalid filter '{expr}': unclosed quote"));
+    }
+    if !term.is_empty() {
+        terms.push(term);
+    }
+    Ok(terms)
+}
+
+/// Parse a `--where` expression such as `label:stale state:todo` into issue filters
+///
+/// Terms are ANDed together; `label:` may be repeated to require several labels.
+pub fn parse_where(expr: &str) -> std::result::Result<IssueFilters, String> {
+    let terms = split_terms(expr)?;
+    if terms.is_empty() {
+        return Err(format!("Empty filter: {WHERE_HELP}"));
+    }
+
+    let mut filters = IssueFilters::default();
+    for term in terms {
+        let (key, value) = term
+            .split_once(':')
+            .filter(|(_, value)| !value.is_empty())
+            .ok_or_else(|| format!("Invalid filter term '{term}': {WHERE_HELP}"))?;
+        let value = value.to_string();
+
+        match key.to_lowercase().as_str() {
+            "assignee" => filters.assignee = Some(value),
+            "creator" => filters.creator = Some(value),
+            "state" | "status" => filters.status = Some(value),
+            "team" => filters.team = Some(value),
+            "label" => filters.labels.push(value),
+            "project" => filters.project = Some(value),
+            "cycle" => filters.cycle = Some(value),
+            "parent" => filters.parent = Some(value),
+            "priority" => {
+                let priority = value
+                    .parse()
+                    .ok()
+                    .filter(|priority| (0..=4).contains(priority))
+                    .ok_or_else(|| format!("Invalid priority '{value}': use 0 through 4"))?;
+                filters.priority = Some(priority);
+            }
+            "type" => {
+                let state_type = value.to_lowercase();
+                if !STATE_TYPES.contains(&state_type.as_str()) {
+                    return Err(format!(
+                        "Invalid state type '{value}': use one of {}",
+                        STATE_TYPES.join(", ")
+                    ));
+                }
+                filters.state_types.push(state_type);
+            }
+            "created" => filters.created_after = Some(crate::dates::parse_since(&value)?),
+            "updated" => filters.updated_since = Some(crate::dates::parse_since(&value)?),
+            "due" => filters.due_before = Some(crate::dates::parse_until_date(&value)?),
+            "is" => match value.to_lowercase().as_str() {
+                "overdue" => filters.overdue = true,
+                "unestimated" => filters.no_estimate = true,
+                "archived" => filters.include_archived = true,
+                _ => return Err(format!("Invalid filter term '{term}': {WHERE_HELP}")),
+            },
+            _ => return Err(format!("Unknown filter key '{key}': {WHERE_HELP}")),
+        }
+    }
+
+    Ok(filters)
+}
+
+/// Outcome of running one operation per issue
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    pub succeeded: Vec<String>,
+    pub failed: Vec<(String, LinearError)>,
+}
+
+impl BulkReport {
+    /// Record the outcome of one issue's operation
+    pub fn record<T>(&mut self, id: String, result: Result<T>) {
+        match result {
+            Ok(_) => self.succeeded.push(id),
+            Err(error) => self.failed.push((id, error)),
+        }
+    }
+
+    /// Print what happened: identifiers on stdout and failures on stderr when piped, or a
+    /// summary line followed by each failure when interactive
+    pub fn print(&self, verb: &str, done: &str, use_color: bool, is_interactive: bool) {
+        let cli_output = CliOutput::with_color(use_color);
+        let count = |n: usize| format!("{n} {}", if n == 1 { "issue" } else { "issues" });
+
+        if is_interactive {
+            if !self.succeeded.is_empty() {
+                cli_output.success(&format!("{done} {}", count(self.succeeded.len())));
+            }
+            if !self.failed.is_empty() {
+                cli_output.error(&format!("Failed to {verb} {}:", count(self.failed.len())));
+                for (id, error) in &self.failed {
+                    eprintln!("  {id}: {error}");
+                }
+            }
+        } else {
+            for id in &self.succeeded {
+                println!("{id}");
+            }
+            for (id, error) in &self.failed {
+                eprintln!("{id}: {error}");
+            }
+        }
+    }
+}
+
+/// Progress bar for a bulk run; hidden when output isn't interactive
+fn progress_bar(len: usize, message: &str, is_interactive: bool) -> ProgressBar {
+    if !is_interactive {
+        return ProgressBar::hidden();
+    }
+
+    let pb = ProgressBar::new(len as u64);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("{msg} [{bar:30.blue}] {pos}/{len}")
+            .unwrap()
+            .progress_chars("=> "),
+    );
+    pb.set_message(message.to_string());
+    pb
+}
+
+/// Run `op` for every ID, a few at a time, and collect the results in input order
+///
+/// At most `MAX_CONCURRENT_MUTATIONS` run at once; the client paces them to the configured
+/// `requests_per_hour` and retries rate-limited requests after the reset.
+pub async fn run<T, F, Fut>(
+    ids: Vec<String>,
+    message: &str,
+    is_interactive: bool,
+    op: F,
+) -> Vec<(String, Result<T>)>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let progress = progress_bar(ids.len(), message, is_interactive);
+    let results = stream::iter(ids)
+        .map(|id| {
+            let operation = op(id.clone());
+            let progress = &progress;
+            async move {
+                let result = operation.await;
+                progress.inc(1);
+                (id, result)
+            }
+        })
+        .buffered(MAX_CONCURRENT_MUTATIONS)
+        .collect()
+        .await;
+    progress.finish_and_clear();
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_ids_splits_and_dedupes() {
+        assert_eq!(
+            parse_ids("ENG-1\nENG-2, ENG-3\n\n  ENG-1\t"),
+            ids(&["ENG-1", "ENG-2", "ENG-3"])
+        );
+        assert!(parse_ids("  \n").is_empty());
+    }
+
+    #[test]
+    fn test_expand_ids_reads_stdin_for_dash() {
+        let expanded =
+            expand_ids_with(
+                ids(&["ENG-1", "-", "ENG-9"]),
+                || Ok("ENG-2\nENG-1\n".into()),
+            );
+        assert_eq!(expanded.unwrap(), ids(&["ENG-1", "ENG-2", "ENG-9"]));
+
+        let untouched = expand_ids_with(ids(&["ENG-1"]), || panic!("stdin should not be read"));
+        assert_eq!(untouched.unwrap(), ids(&["ENG-1"]));
+
+        assert!(expand_ids_with(ids(&["-"]), || Err("no stdin".into())).is_err());
+    }
+
+    #[test]
+    fn test_read_confirmation_after_stdin_ids() {
+        let stdin_drained = || Ok(String::new());
+
+        let answer = read_confirmation_with(true, stdin_drained, || Ok("y\n".into()));
+        assert_eq!(answer.unwrap(), "y\n");
+
+        let answer = read_confirmation_with(
+            false,
+            || Ok("yes\n".into()),
+            || panic!("the terminal should not be opened"),
+        );
+        assert_eq!(answer.unwrap(), "yes\n");
+
+        let err = read_confirmation_with(true, stdin_drained, || {
+            Err(std::io::Error::new(std::io::ErrorKind::NotFound, "no tty"))
+        })
+        .unwrap_err();
+        assert!(err.contains("pass --force"));
+    }
+
+    #[test]
+    fn test_looks_like_issue_id() {
+        assert!(looks_like_issue_id("ENG-123"));
+        assert!(looks_like_issue_id("eng-1"));
+        assert!(looks_like_issue_id("-"));
+        assert!(looks_like_issue_id("123e4567-e89b-12d3-a456-426614174000"));
+        assert!(!looks_like_issue_id("Fixed in PR #42"));
+        assert!(!looks_like_issue_id("follow-up"));
+        assert!(!looks_like_issue_id("ENG-"));
+    }
+
+    #[test]
+    fn test_parse_where() {
+        let filters =
+            parse_where("label:stale label:\"needs review\" state:todo assignee:me priority:2")
+                .unwrap();
+        assert_eq!(filters.labels, ids(&["stale", "needs review"]));
+        assert_eq!(filters.status.as_deref(), Some("todo"));
+        assert_eq!(filters.assignee.as_deref(), Some("me"));
+        assert_eq!(filters.priority, Some(2));
+
+        let filters = parse_where("type:Started updated:7d is:overdue is:archived").unwrap();
+        assert_eq!(filters.state_types, ids(&["started"]));
+        assert_eq!(filters.updated_since.as_deref(), Some("-P7D"));
+        assert!(filters.overdue);
+        assert!(filters.include_archived);
+    }
+
+    #[test]
+    fn test_parse_where_rejects_bad_terms() {
+        assert!(parse_where("").is_err());
+        assert!(parse_where("stale").is_err());
+        assert!(parse_where("label:").is_err());
+        assert!(parse_where("color:red").is_err());
+        assert!(parse_where("priority:9").is_err());
+        assert!(parse_where("type:doing").is_err());
+        assert!(parse_where("is:blocked").is_err());
+        assert!(parse_where("label:\"needs review").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_keeps_input_order() {
+        let results = run(
+            ids(&["ENG-1", "ENG-2", "ENG-3"]),
+            "",
+            false,
+            |id| async move {
+                if id == "ENG-2" {
+                    Err(LinearError::IssueNotFound {
+                        identifier: id,
+                        suggestion: None,
+                    })
+                } else {
+                    Ok(())
+                }
+            },
+        )
+        .await;
+
+        let mut report = BulkReport::default();
+        for (id, result) in results {
+            report.record(id, result);
+        }
+
+        assert_eq!(report.succeeded, ids(&["ENG-1", "ENG-3"]));
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].0, "ENG-2");
+    }
+}
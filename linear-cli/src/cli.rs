@@ -1,7 +1,12 @@
 // ABOUTME: CLI argument definitions for Linear CLI application
 // ABOUTME: Defines the command-line interface structure using clap derive macros
 
-use clap::{Parser, Subcommand};
+use clap::{ArgGroup, Args, Parser, Subcommand, ValueEnum};
+use linear_sdk::IssueFilters;
+
+use crate::api::ApiField;
+use crate::columns::{ColumnSpec, SortSpec};
+use crate::formats::Format;
 
 #[derive(Parser, Debug)]
 #[command(name = "linear")]
@@ -25,32 +30,48 @@ pub struct Cli {
 }
 
 #[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)] // parsed once per run, so the size doesn't matter
 pub enum Commands {
     /// List issues
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
     Issues {
         /// Maximum number of issues to fetch
         #[arg(short, long, default_value = "20", value_parser = clap::value_parser!(i32).range(1..))]
         limit: i32,
 
-        /// Output as JSON
+        /// Fetch all issues, following pagination cursors
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
 
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{identifier}}\t{{title}}', the name of
+        /// a template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
         /// Pretty print JSON output
-        #[arg(long, requires = "json")]
+        #[arg(long, requires = "output_format")]
         pretty: bool,
 
-        /// Filter by assignee (use "me" for yourself)
-        #[arg(long)]
-        assignee: Option<String>,
+        #[command(flatten)]
+        filters: IssueFilterArgs,
 
-        /// Filter by status (case insensitive)
+        /// Sort by comma-separated columns, "-" prefix for descending (e.g. priority,-updated);
+        /// every match is fetched and sorted before --limit is applied
         #[arg(long)]
-        status: Option<String>,
+        sort: Option<SortSpec>,
 
-        /// Filter by team
+        /// Table columns to show (identifier,title,state,assignee,priority,estimate,labels,
+        /// cycle,project,team,due,created,updated)
         #[arg(long)]
-        team: Option<String>,
+        columns: Option<ColumnSpec>,
     },
     /// Show details for a single issue
     Issue {
@@ -64,6 +85,10 @@ pub enum Commands {
         /// Force raw markdown output (skip rich formatting)
         #[arg(long)]
         raw: bool,
+
+        /// Show the issue and its sub-issues as a tree
+        #[arg(long, conflicts_with = "raw")]
+        tree: bool,
     },
     /// Create a new issue
     Create {
@@ -95,6 +120,10 @@ pub enum Commands {
         #[arg(long, conflicts_with = "project")]
         project_id: Option<String>,
 
+        /// Milestone within the project to assign the issue to (name or ID)
+        #[arg(long)]
+        milestone: Option<String>,
+
         /// Estimate points for the issue
         #[arg(long)]
         estimate: Option<i64>,
@@ -107,6 +136,14 @@ pub enum Commands {
         #[arg(long)]
         cycle: Option<String>,
 
+        /// Parent issue to create this issue under (e.g., ENG-100)
+        #[arg(long)]
+        parent: Option<String>,
+
+        /// Due date (e.g. 2026-11-01, +3d, +2w, tomorrow, friday)
+        #[arg(long, value_parser = crate::dates::parse_due_date)]
+        due: Option<String>,
+
         /// Create issue from markdown file with frontmatter
         #[arg(long, short = 'f', value_name = "FILE")]
         from_file: Option<String>,
@@ -119,10 +156,15 @@ pub enum Commands {
         #[arg(long)]
         dry_run: bool,
     },
-    /// Update an existing issue
+    /// Update one or more issues
     Update {
-        /// Issue identifier (e.g., ENG-123)
-        id: String,
+        /// Issue identifiers (e.g., ENG-123 ENG-124), or - to read them from stdin; picks one
+        /// interactively when omitted
+        ids: Vec<String>,
+
+        /// Update every issue matching a filter (e.g. 'label:stale state:todo')
+        #[arg(long = "where", value_name = "FILTER", value_parser = crate::bulk::parse_where)]
+        filter: Option<IssueFilters>,
 
         /// New title for the issue
         #[arg(long)]
@@ -164,35 +206,124 @@ pub enum Commands {
         #[arg(long, conflicts_with = "project")]
         project_id: Option<String>,
 
+        /// Milestone to move the issue to, looked up in --project or the issue's own project
+        /// (use "none" to remove); updating several issues requires --project
+        #[arg(long)]
+        milestone: Option<String>,
+
+        /// Parent issue to move this issue under (use "none" to detach)
+        #[arg(long)]
+        parent: Option<String>,
+
+        /// Due date (e.g. 2026-11-01, +3d, friday; use "none" to clear)
+        #[arg(long, value_parser = crate::dates::parse_due_date)]
+        due: Option<String>,
+
         /// Skip confirmation prompt
         #[arg(long)]
         force: bool,
+
+        /// Show what would be updated without updating anything
+        #[arg(long)]
+        dry_run: bool,
     },
-    /// Close an issue (convenience command)
+    /// Edit an issue in $EDITOR as markdown with frontmatter
+    Edit {
+        /// Issue identifier (e.g., ENG-123); picks one interactively when omitted
+        id: Option<String>,
+
+        /// Apply the changes without asking for confirmation
+        #[arg(long)]
+        force: bool,
+    },
+    /// Close one or more issues (convenience command)
     Close {
-        /// Issue identifier (e.g., ENG-123)
-        id: String,
+        /// Issue identifiers (e.g., ENG-123 ENG-124), or - to read them from stdin; picks one
+        /// interactively when omitted
+        ids: Vec<String>,
+
+        /// Close every issue matching a filter (e.g. 'label:stale')
+        #[arg(long = "where", value_name = "FILTER", value_parser = crate::bulk::parse_where)]
+        filter: Option<IssueFilters>,
 
         /// Skip confirmation prompt
         #[arg(long)]
         force: bool,
+
+        /// Show what would be closed without closing anything
+        #[arg(long)]
+        dry_run: bool,
     },
-    /// Reopen an issue (convenience command)
+    /// Reopen one or more issues (convenience command)
     Reopen {
-        /// Issue identifier (e.g., ENG-123)
-        id: String,
+        /// Issue identifiers (e.g., ENG-123 ENG-124), or - to read them from stdin; picks one
+        /// interactively when omitted
+        ids: Vec<String>,
+
+        /// Reopen every issue matching a filter (e.g. 'state:done label:regression')
+        #[arg(long = "where", value_name = "FILTER", value_parser = crate::bulk::parse_where)]
+        filter: Option<IssueFilters>,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Show what would be reopened without reopening anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Archive one or more issues
+    Archive {
+        /// Issue identifiers (e.g., ENG-123 ENG-124)
+        #[arg(required = true, num_args = 1..)]
+        ids: Vec<String>,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+    /// Restore archived or deleted issues
+    Unarchive {
+        /// Issue identifiers (e.g., ENG-123 ENG-124)
+        #[arg(required = true, num_args = 1..)]
+        ids: Vec<String>,
+    },
+    /// Move one or more issues to the trash
+    Delete {
+        /// Issue identifiers (e.g., ENG-123 ENG-124)
+        #[arg(required = true, num_args = 1..)]
+        ids: Vec<String>,
+
+        /// Delete permanently instead of moving to the trash
+        #[arg(long)]
+        permanent: bool,
 
         /// Skip confirmation prompt
         #[arg(long)]
         force: bool,
     },
-    /// Add a comment to an issue
+    /// Add a comment to one or more issues
     Comment {
-        /// Issue identifier (e.g., ENG-123)
-        id: String,
+        /// Issue identifiers, or - to read them from stdin; a trailing non-identifier
+        /// argument is taken as the comment text (e.g. ENG-123 "Fixed in #42"). Picks one
+        /// interactively when omitted
+        ids: Vec<String>,
 
         /// Comment text (if not provided, will read from stdin)
+        #[arg(short, long)]
         message: Option<String>,
+
+        /// Comment on every issue matching a filter (e.g. 'label:stale')
+        #[arg(long = "where", value_name = "FILTER", value_parser = crate::bulk::parse_where)]
+        filter: Option<IssueFilters>,
+
+        /// Skip confirmation prompt when commenting on several issues
+        #[arg(long)]
+        force: bool,
+
+        /// Show what would be posted without posting anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Check connection to Linear
     Status {
@@ -214,30 +345,77 @@ pub enum Commands {
     #[cfg(feature = "oauth")]
     Logout,
     /// List projects
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
     Projects {
         /// Maximum number of projects to fetch
         #[arg(short, long, default_value = "20", value_parser = clap::value_parser!(i32).range(1..))]
         limit: i32,
 
-        /// Output as JSON
+        /// Fetch all projects, following pagination cursors
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+
+        /// Only projects this team takes part in (team key, e.g. ENG)
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Filter by project status name or type (e.g. "In Progress" or planned)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Filter by project lead name, or "me"
+        #[arg(long)]
+        lead: Option<String>,
+
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
 
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{identifier}}\t{{title}}', the name of
+        /// a template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
         /// Pretty print JSON output
-        #[arg(long, requires = "json")]
+        #[arg(long, requires = "output_format")]
         pretty: bool,
     },
+    /// Show, create, update, and archive projects
+    Project {
+        #[command(subcommand)]
+        command: ProjectCommand,
+    },
+    /// List, create, update, and delete a project's milestones
+    Milestones {
+        #[command(subcommand)]
+        command: MilestoneCommand,
+    },
     /// List teams
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
     Teams {
-        /// Output as JSON
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
 
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{identifier}}\t{{title}}', the name of
+        /// a template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
         /// Pretty print JSON output
-        #[arg(long, requires = "json")]
+        #[arg(long, requires = "output_format")]
         pretty: bool,
     },
     /// Show comments for an issue
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
     Comments {
         /// Issue identifier (e.g., ENG-123)
         id: String,
@@ -246,29 +424,95 @@ pub enum Commands {
         #[arg(short, long, default_value = "20", value_parser = clap::value_parser!(i32).range(1..))]
         limit: i32,
 
-        /// Output as JSON
+        /// Fetch all comments, following pagination cursors
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
 
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{identifier}}\t{{title}}', the name of
+        /// a template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
         /// Pretty print JSON output
-        #[arg(long, requires = "json")]
+        #[arg(long, requires = "output_format")]
         pretty: bool,
     },
     /// Show your assigned and created issues
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
     MyWork {
         /// Maximum number of issues to fetch per category
         #[arg(short, long, default_value = "20", value_parser = clap::value_parser!(i32).range(1..))]
         limit: i32,
 
-        /// Output as JSON
+        /// Fetch all issues in each category, following pagination cursors
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+
+        /// Only issues due before a date or relative time (e.g. 2026-11-01, 14d)
+        #[arg(long, value_parser = crate::dates::parse_until_date)]
+        due_before: Option<String>,
+
+        /// Only open issues whose due date has passed
+        #[arg(long, conflicts_with = "due_before")]
+        overdue: bool,
+
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
 
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{identifier}}\t{{title}}', the name of
+        /// a template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
         /// Pretty print JSON output
-        #[arg(long, requires = "json")]
+        #[arg(long, requires = "output_format")]
         pretty: bool,
     },
+    /// List cycles, show their progress, and plan issues into them
+    Cycles {
+        #[command(subcommand)]
+        command: CycleCommand,
+    },
+    /// Cycle burndown and velocity reports
+    Report {
+        #[command(subcommand)]
+        command: ReportCommand,
+    },
+    /// Mirror issues as a directory of markdown files
+    Sync {
+        #[command(subcommand)]
+        command: SyncCommand,
+    },
+    /// Pick an issue interactively and print its identifier
+    Pick,
+    /// Browse and triage issues in a full-screen terminal UI
+    Tui {
+        /// Maximum number of issues to load
+        #[arg(short, long, default_value = "50", value_parser = clap::value_parser!(i32).range(1..))]
+        limit: i32,
+
+        /// Load all matching issues, following pagination cursors
+        #[arg(long, conflicts_with = "limit")]
+        all: bool,
+
+        #[command(flatten)]
+        filters: IssueFilterArgs,
+    },
     /// Search across issues, projects, and comments
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
     Search {
         /// Search query string
         query: String,
@@ -289,12 +533,21 @@ pub enum Commands {
         #[arg(short, long, default_value = "10", value_parser = clap::value_parser!(i32).range(1..=100))]
         limit: i32,
 
-        /// Output as JSON
+        /// Output as JSON (shorthand for --format json)
         #[arg(long)]
         json: bool,
 
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{identifier}}\t{{title}}', the name of
+        /// a template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
         /// Pretty print JSON output
-        #[arg(long, requires = "json")]
+        #[arg(long, requires = "output_format")]
         pretty: bool,
 
         /// Include archived results
@@ -327,6 +580,20 @@ pub enum Commands {
         #[arg(long)]
         title: Option<String>,
     },
+    /// Send a raw GraphQL request to the Linear API and print the JSON response
+    Api {
+        /// GraphQL query, @path to read it from a file, or - to read stdin (the default)
+        query: Option<String>,
+
+        /// GraphQL variable as key=value (repeatable; JSON values like 10, true, or [..] are
+        /// sent as JSON, anything else as a string)
+        #[arg(short = 'F', long = "field", value_name = "KEY=VALUE")]
+        fields: Vec<ApiField>,
+
+        /// Follow pageInfo cursors through the query's $after variable and merge every page
+        #[arg(long)]
+        paginate: bool,
+    },
     /// Generate shell completions
     Completions {
         /// Shell to generate completions for
@@ -334,3 +601,465 @@ pub enum Commands {
         shell: crate::completions::Shell,
     },
 }
+
+/// `linear cycles` subcommands
+#[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)] // parsed once per run, so the size doesn't matter
+pub enum CycleCommand {
+    /// List a team's cycles with their dates and scope
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
+    List {
+        #[command(flatten)]
+        view: CycleViewArgs,
+    },
+    /// Show a cycle's dates, scope, and issues grouped by state
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
+    Show {
+        /// "current", "next", "previous", a cycle number, or a cycle name
+        cycle: String,
+
+        #[command(flatten)]
+        view: CycleViewArgs,
+    },
+    /// Show the team's active cycle
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
+    Current {
+        #[command(flatten)]
+        view: CycleViewArgs,
+    },
+    /// Show the team's upcoming cycle
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
+    Next {
+        #[command(flatten)]
+        view: CycleViewArgs,
+    },
+    /// Move issues into a cycle
+    Add {
+        /// Issue identifiers (e.g., ENG-123 ENG-124), or - to read them from stdin; picks one
+        /// interactively when omitted
+        ids: Vec<String>,
+
+        /// Cycle to move the issues into: "current", "next", a cycle number, or a cycle name
+        #[arg(long)]
+        cycle: String,
+
+        /// Move every issue matching a filter (e.g. 'label:planned state:todo')
+        #[arg(long = "where", value_name = "FILTER", value_parser = crate::bulk::parse_where)]
+        filter: Option<IssueFilters>,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Show what would be moved without moving anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Move the active cycle's unfinished issues into the next cycle
+    Rollover {
+        /// Team key (e.g., ENG) or UUID
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Show what would be moved without moving anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// `linear project` subcommands
+#[derive(Subcommand, Debug)]
+pub enum ProjectCommand {
+    /// Show a project's description, people, milestones, and issues grouped by state
+    Show {
+        /// Project name or ID
+        project: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Force raw markdown output (skip rich formatting)
+        #[arg(long)]
+        raw: bool,
+    },
+    /// Create a project
+    Create {
+        /// Project name
+        name: String,
+
+        /// Team key (e.g., ENG) or UUID
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Project description (markdown)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Project lead name or email, or "me"
+        #[arg(long)]
+        lead: Option<String>,
+
+        /// Target date: YYYY-MM-DD, today, tomorrow, a weekday, or +3d/+2w/+1m
+        #[arg(long)]
+        target_date: Option<String>,
+
+        /// Project status name or type (e.g. Planned or started)
+        #[arg(long)]
+        state: Option<String>,
+    },
+    /// Update a project
+    Update {
+        /// Project name or ID
+        project: String,
+
+        /// New project name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New project description (markdown)
+        #[arg(long)]
+        description: Option<String>,
+
+        /// New project lead name or email, "me", or "none" to remove the lead
+        #[arg(long)]
+        lead: Option<String>,
+
+        /// Add the project to this team (key or UUID); its other teams are kept
+        #[arg(long)]
+        team: Option<String>,
+
+        /// New target date: YYYY-MM-DD, today, tomorrow, a weekday, +3d/+2w/+1m, or "none"
+        /// to clear it
+        #[arg(long)]
+        target_date: Option<String>,
+
+        /// New project status name or type (e.g. Completed or started)
+        #[arg(long)]
+        state: Option<String>,
+    },
+    /// Post a status update on a project, reading the markdown body from stdin or $EDITOR
+    UpdatePost {
+        /// Project name or ID
+        project: String,
+
+        /// Project health to report with the update
+        #[arg(long, value_enum)]
+        health: Option<HealthArg>,
+    },
+    /// List a project's status updates, newest first
+    Updates {
+        /// Project name or ID
+        project: String,
+
+        /// Maximum number of updates to show
+        #[arg(short, long, default_value = "10", value_parser = clap::value_parser!(i64).range(1..=250))]
+        limit: i64,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Force raw markdown output (skip rich formatting)
+        #[arg(long)]
+        raw: bool,
+    },
+    /// Archive a project
+    Archive {
+        /// Project name or ID
+        project: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// Project health accepted by `linear project update-post --health`
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HealthArg {
+    OnTrack,
+    AtRisk,
+    OffTrack,
+}
+
+impl From<HealthArg> for linear_sdk::ProjectHealth {
+    fn from(health: HealthArg) -> Self {
+        match health {
+            HealthArg::OnTrack => linear_sdk::ProjectHealth::OnTrack,
+            HealthArg::AtRisk => linear_sdk::ProjectHealth::AtRisk,
+            HealthArg::OffTrack => linear_sdk::ProjectHealth::OffTrack,
+        }
+    }
+}
+
+/// `linear milestones` subcommands
+#[derive(Subcommand, Debug)]
+pub enum MilestoneCommand {
+    /// List a project's milestones with their target dates and progress
+    #[command(group = ArgGroup::new("output_format").args(["json", "format", "template"]))]
+    List {
+        /// Project name or ID
+        #[arg(long)]
+        project: String,
+
+        /// Output as JSON (shorthand for --format json)
+        #[arg(long)]
+        json: bool,
+
+        /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+        #[arg(long)]
+        format: Option<Format>,
+
+        /// Render each result with a template such as '{{name}}\t{{targetDate}}', the name of a
+        /// template from the config file, or @path to read one from a file
+        #[arg(long, value_parser = Format::template)]
+        template: Option<Format>,
+
+        /// Pretty print JSON output
+        #[arg(long, requires = "output_format")]
+        pretty: bool,
+    },
+    /// Create a milestone in a project
+    Create {
+        /// Milestone name
+        name: String,
+
+        /// Project name or ID
+        #[arg(long)]
+        project: String,
+
+        /// Milestone description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Target date: YYYY-MM-DD, today, tomorrow, a weekday, or +3d/+2w/+1m
+        #[arg(long)]
+        target_date: Option<String>,
+    },
+    /// Rename a milestone or change its description or target date
+    Update {
+        /// Milestone name or ID
+        milestone: String,
+
+        /// Project name or ID
+        #[arg(long)]
+        project: String,
+
+        /// New milestone name
+        #[arg(long)]
+        name: Option<String>,
+
+        /// New milestone description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// New target date: YYYY-MM-DD, today, tomorrow, a weekday, +3d/+2w/+1m, or "none"
+        /// to clear it
+        #[arg(long)]
+        target_date: Option<String>,
+    },
+    /// Delete a milestone; its issues stay in the project
+    Delete {
+        /// Milestone name or ID
+        milestone: String,
+
+        /// Project name or ID
+        #[arg(long)]
+        project: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+/// `linear report` subcommands
+#[derive(Subcommand, Debug)]
+pub enum ReportCommand {
+    /// Chart a cycle's remaining points day by day
+    Burndown {
+        /// Team key (e.g., ENG) or UUID
+        #[arg(long)]
+        team: Option<String>,
+
+        /// "current", "previous", a cycle number, or a cycle name
+        #[arg(long, default_value = "current")]
+        cycle: String,
+
+        /// Output format: table (a chart), json, yaml, csv, or tsv
+        #[arg(long, default_value = "table", value_parser = Format::report)]
+        format: Format,
+    },
+    /// Summarize the points completed in recent cycles
+    Velocity {
+        /// Team key (e.g., ENG) or UUID
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Number of completed cycles to include
+        #[arg(long, default_value = "6", value_parser = clap::value_parser!(u32).range(1..))]
+        last: u32,
+
+        /// Output format: table (a chart), json, yaml, csv, or tsv
+        #[arg(long, default_value = "table", value_parser = Format::report)]
+        format: Format,
+    },
+}
+
+/// Team and output options shared by the `linear cycles` views
+#[derive(Args, Debug, Clone, Default)]
+pub struct CycleViewArgs {
+    /// Team key (e.g., ENG) or UUID
+    #[arg(long)]
+    pub team: Option<String>,
+
+    /// Output as JSON (shorthand for --format json)
+    #[arg(long)]
+    pub json: bool,
+
+    /// Output format: table, json, yaml, csv, tsv, ndjson, ids, or template=<TEMPLATE>
+    #[arg(long)]
+    pub format: Option<Format>,
+
+    /// Render each result with a template such as '{{number}}\t{{name}}', the name of a
+    /// template from the config file, or @path to read one from a file
+    #[arg(long, value_parser = Format::template)]
+    pub template: Option<Format>,
+
+    /// Pretty print JSON output
+    #[arg(long, requires = "output_format")]
+    pub pretty: bool,
+}
+
+/// `linear sync` subcommands
+#[derive(Subcommand, Debug)]
+#[allow(clippy::large_enum_variant)] // parsed once per run, so the size doesn't matter
+pub enum SyncCommand {
+    /// Write each matching issue to <DIR>/<IDENTIFIER>.md
+    Pull {
+        /// Directory to write issue files to
+        #[arg(long, default_value = ".")]
+        dir: String,
+
+        #[command(flatten)]
+        filters: IssueFilterArgs,
+
+        /// Overwrite files with local changes that haven't been pushed
+        #[arg(long)]
+        force: bool,
+    },
+    /// Create issues for new files and update issues whose files changed since the last sync
+    Push {
+        /// Directory to read issue files from
+        #[arg(long, default_value = ".")]
+        dir: String,
+
+        /// Team for new files that don't name one in their frontmatter
+        #[arg(long)]
+        team: Option<String>,
+
+        /// Show what would be created and updated without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// Issue filters shared by `linear issues`, `linear tui`, and `linear sync pull`
+#[derive(Args, Debug, Clone, Default)]
+pub struct IssueFilterArgs {
+    /// Filter by assignee (use "me" for yourself)
+    #[arg(long)]
+    pub assignee: Option<String>,
+
+    /// Filter by status (case insensitive)
+    #[arg(long, alias = "state")]
+    pub status: Option<String>,
+
+    /// Filter by team
+    #[arg(long)]
+    pub team: Option<String>,
+
+    /// Filter by label (repeatable; issues must have every label)
+    #[arg(long = "label", action = clap::ArgAction::Append)]
+    pub labels: Vec<String>,
+
+    /// Filter by project name or ID
+    #[arg(long)]
+    pub project: Option<String>,
+
+    /// Filter by cycle ("current", "next", or a cycle number)
+    #[arg(long)]
+    pub cycle: Option<String>,
+
+    /// Filter by priority (0=None, 1=Urgent, 2=High, 3=Normal, 4=Low)
+    #[arg(long, value_parser = clap::value_parser!(i64).range(0..=4))]
+    pub priority: Option<i64>,
+
+    /// Filter by creator (use "me" for yourself)
+    #[arg(long)]
+    pub creator: Option<String>,
+
+    /// Filter by workflow state type (repeatable)
+    #[arg(
+        long = "state-type",
+        action = clap::ArgAction::Append,
+        value_parser = ["triage", "backlog", "unstarted", "started", "completed", "canceled"]
+    )]
+    pub state_types: Vec<String>,
+
+    /// Only issues created after a date or relative time (e.g. 2026-01-31, 7d, 2w)
+    #[arg(long, value_parser = crate::dates::parse_since)]
+    pub created_after: Option<String>,
+
+    /// Only issues updated after a date or relative time (e.g. 2026-01-31, 7d, 12h)
+    #[arg(long, value_parser = crate::dates::parse_since)]
+    pub updated_since: Option<String>,
+
+    /// Only issues due before a date or relative time (e.g. 2026-11-01, 14d)
+    #[arg(long, value_parser = crate::dates::parse_until_date)]
+    pub due_before: Option<String>,
+
+    /// Only open issues whose due date has passed
+    #[arg(long, conflicts_with = "due_before")]
+    pub overdue: bool,
+
+    /// Only sub-issues of this parent issue (e.g. ENG-123)
+    #[arg(long)]
+    pub parent: Option<String>,
+
+    /// Only issues without an estimate
+    #[arg(long)]
+    pub no_estimate: bool,
+
+    /// Include archived issues
+    #[arg(long)]
+    pub include_archived: bool,
+}
+
+impl From<IssueFilterArgs> for IssueFilters {
+    fn from(args: IssueFilterArgs) -> Self {
+        IssueFilters {
+            assignee: args.assignee,
+            status: args.status,
+            team: args.team,
+            labels: args.labels,
+            project: args.project,
+            cycle: args.cycle,
+            priority: args.priority,
+            creator: args.creator,
+            state_types: args.state_types,
+            created_after: args.created_after,
+            updated_since: args.updated_since,
+            due_before: args.due_before,
+            overdue: args.overdue,
+            parent: args.parent,
+            no_estimate: args.no_estimate,
+            include_archived: args.include_archived,
+        }
+    }
+}
@@ -0,0 +1,377 @@
+// ABOUTME: Column selection and multi-key sorting for issue table output
+// ABOUTME: Parses specs like "identifier,title,due" and "priority,-updated" into typed keys
+
+use linear_sdk::Issue;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// A column that can be shown in an issue table or used as a sort key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueColumn {
+    Identifier,
+    Title,
+    State,
+    Assignee,
+    Priority,
+    Estimate,
+    Labels,
+    Cycle,
+    Project,
+    Team,
+    Due,
+    Created,
+    Updated,
+}
+
+/// Columns shown when neither `--columns` nor the config file choose any
+pub const DEFAULT_ISSUE_COLUMNS: &[IssueColumn] = &[
+    IssueColumn::Identifier,
+    IssueColumn::Title,
+    IssueColumn::State,
+    IssueColumn::Assignee,
+];
+
+const COLUMN_NAMES: &str =
+    "identifier, title, state, assignee, priority, estimate, labels, cycle, project, team, due, created, updated";
+
+impl IssueColumn {
+    /// Header text used in table output
+    pub fn header(&self) -> &'static str {
+        match self {
+            IssueColumn::Identifier => "Issue",
+            IssueColumn::Title => "Title",
+            IssueColumn::State => "Status",
+            IssueColumn::Assignee => "Assignee",
+            IssueColumn::Priority => "Priority",
+            IssueColumn::Estimate => "Estimate",
+            IssueColumn::Labels => "Labels",
+            IssueColumn::Cycle => "Cycle",
+            IssueColumn::Project => "Project",
+            IssueColumn::Team => "Team",
+            IssueColumn::Due => "Due",
+            IssueColumn::Created => "Created",
+            IssueColumn::Updated => "Updated",
+        }
+    }
+
+    /// Uncolored, untruncated cell value for exports such as CSV
+    pub fn plain_value(&self, issue: &Issue) -> String {
+        match self {
+            IssueColumn::Identifier => issue.identifier.clone(),
+            IssueColumn::Title => issue.title.clone(),
+            IssueColumn::State => issue.status.clone(),
+            IssueColumn::Assignee => issue.assignee.clone().unwrap_or_default(),
+            IssueColumn::Priority => match issue.priority {
+                Some(0) | None => String::new(),
+                Some(priority) => issue
+                    .priority_label
+                    .clone()
+                    .unwrap_or_else(|| priority.to_string()),
+            },
+            IssueColumn::Estimate => issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+            IssueColumn::Labels => issue
+                .labels
+                .iter()
+                .map(|label| label.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            IssueColumn::Cycle => issue
+                .cycle
+                .as_ref()
+                .map(|cycle| cycle.number.to_string())
+                .unwrap_or_default(),
+            IssueColumn::Project => issue.project.clone().unwrap_or_default(),
+            IssueColumn::Team => issue.team.clone().unwrap_or_default(),
+            IssueColumn::Due => issue.due_date.clone().unwrap_or_default(),
+            IssueColumn::Created => issue.created_at.clone().unwrap_or_default(),
+            IssueColumn::Updated => issue.updated_at.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Compare two issues by this column, always placing missing values last
+    fn compare(&self, a: &Issue, b: &Issue, descending: bool) -> Ordering {
+        let directed = |ordering: Ordering| {
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        };
+
+        match self {
+            IssueColumn::Identifier => {
+                directed(identifier_key(&a.identifier).cmp(&identifier_key(&b.identifier)))
+            }
+            IssueColumn::Title => directed(a.title.to_lowercase().cmp(&b.title.to_lowercase())),
+            IssueColumn::State => directed(a.status.to_lowercase().cmp(&b.status.to_lowercase())),
+            IssueColumn::Assignee => compare_present(
+                a.assignee.as_ref().map(|s| s.to_lowercase()),
+                b.assignee.as_ref().map(|s| s.to_lowercase()),
+                directed,
+            ),
+            // Priority 0 means "No priority" in Linear, so it sorts with the missing values
+            IssueColumn::Priority => compare_present(
+                a.priority.filter(|p| *p > 0),
+                b.priority.filter(|p| *p > 0),
+                directed,
+            ),
+            IssueColumn::Estimate => match (a.estimate, b.estimate) {
+                (Some(x), Some(y)) => directed(x.total_cmp(&y)),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            },
+            IssueColumn::Labels => compare_present(
+                first_label(a).map(|s| s.to_lowercase()),
+                first_label(b).map(|s| s.to_lowercase()),
+                directed,
+            ),
+            IssueColumn::Cycle => compare_present(
+                a.cycle.as_ref().map(|c| c.number),
+                b.cycle.as_ref().map(|c| c.number),
+                directed,
+            ),
+            IssueColumn::Project => compare_present(
+                a.project.as_ref().map(|s| s.to_lowercase()),
+                b.project.as_ref().map(|s| s.to_lowercase()),
+                directed,
+            ),
+            IssueColumn::Team => compare_present(a.team.as_ref(), b.team.as_ref(), directed),
+            IssueColumn::Due => compare_present(a.due_date.as_ref(), b.due_date.as_ref(), directed),
+            IssueColumn::Created => {
+                compare_present(a.created_at.as_ref(), b.created_at.as_ref(), directed)
+            }
+            IssueColumn::Updated => {
+                compare_present(a.updated_at.as_ref(), b.updated_at.as_ref(), directed)
+            }
+        }
+    }
+}
+
+impl FromStr for IssueColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "identifier" | "id" | "issue" => Ok(IssueColumn::Identifier),
+            "title" => Ok(IssueColumn::Title),
+            "state" | "status" => Ok(IssueColumn::State),
+            "assignee" => Ok(IssueColumn::Assignee),
+            "priority" => Ok(IssueColumn::Priority),
+            "estimate" => Ok(IssueColumn::Estimate),
+            "labels" | "label" => Ok(IssueColumn::Labels),
+            "cycle" => Ok(IssueColumn::Cycle),
+            "project" => Ok(IssueColumn::Project),
+            "team" => Ok(IssueColumn::Team),
+            "due" | "due-date" => Ok(IssueColumn::Due),
+            "created" => Ok(IssueColumn::Created),
+            "updated" => Ok(IssueColumn::Updated),
+            other => Err(format!(
+                "Unknown column '{other}'. Available columns: {COLUMN_NAMES}"
+            )),
+        }
+    }
+}
+
+/// One key of a `--sort` spec; a leading `-` sorts that key in descending order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub column: IssueColumn,
+    pub descending: bool,
+}
+
+/// Column list accepted by `--columns` and the `[columns]` config section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSpec(pub Vec<IssueColumn>);
+
+impl FromStr for ColumnSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_columns(s).map(ColumnSpec)
+    }
+}
+
+/// Sort keys accepted by `--sort`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortSpec(pub Vec<SortKey>);
+
+impl FromStr for SortSpec {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_sort(s).map(SortSpec)
+    }
+}
+
+/// Parse a comma-separated column list such as `identifier,title,priority`
+pub fn parse_columns(spec: &str) -> Result<Vec<IssueColumn>, String> {
+    let columns = spec
+        .split(',')
+        .filter(|part| !part.trim().is_empty())
+        .map(IssueColumn::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if columns.is_empty() {
+        return Err(format!(
+            "At least one column is required. Available columns: {COLUMN_NAMES}"
+        ));
+    }
+
+    Ok(columns)
+}
+
+/// Parse a comma-separated sort spec such as `priority,-updated`
+pub fn parse_sort(spec: &str) -> Result<Vec<SortKey>, String> {
+    let keys = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (descending, name) = match part.strip_prefix('-') {
+                Some(name) => (true, name),
+                None => (false, part.strip_prefix('+').unwrap_or(part)),
+            };
+            Ok(SortKey {
+                column: name.parse()?,
+                descending,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    if keys.is_empty() {
+        return Err("At least one sort key is required, e.g. priority,-updated".to_string());
+    }
+
+    Ok(keys)
+}
+
+/// Stable sort of issues by each key in turn, later keys breaking ties of earlier ones
+pub fn sort_issues(issues: &mut [Issue], keys: &[SortKey]) {
+    issues.sort_by(|a, b| {
+        keys.iter().fold(Ordering::Equal, |ordering, key| {
+            ordering.then_with(|| key.column.compare(a, b, key.descending))
+        })
+    });
+}
+
+/// Order present values with `directed` and put missing values after them
+fn compare_present<T: Ord>(
+    a: Option<T>,
+    b: Option<T>,
+    directed: impl Fn(Ordering) -> Ordering,
+) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => directed(a.cmp(&b)),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Split "ENG-42" into ("ENG", 42) so identifiers sort numerically within a team
+fn identifier_key(identifier: &str) -> (String, u64) {
+    match identifier.rsplit_once('-') {
+        Some((team, number)) => (team.to_string(), number.parse().unwrap_or(0)),
+        None => (identifier.to_string(), 0),
+    }
+}
+
+fn first_label(issue: &Issue) -> Option<&String> {
+    issue.labels.first().map(|label| &label.name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(identifier: &str, priority: i64, updated_at: &str) -> Issue {
+        Issue {
+            id: identifier.to_lowercase(),
+            identifier: identifier.to_string(),
+            title: format!("Issue {identifier}"),
+            status: "Todo".to_string(),
+            priority: Some(priority),
+            updated_at: Some(updated_at.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn identifiers(issues: &[Issue]) -> Vec<&str> {
+        issues.iter().map(|i| i.identifier.as_str()).collect()
+    }
+
+    #[test]
+    fn test_parse_columns() {
+        assert_eq!(
+            parse_columns("identifier, title,STATUS,due").unwrap(),
+            vec![
+                IssueColumn::Identifier,
+                IssueColumn::Title,
+                IssueColumn::State,
+                IssueColumn::Due
+            ]
+        );
+        assert!(parse_columns("identifier,bogus")
+            .unwrap_err()
+            .contains("Unknown column 'bogus'"));
+        assert!(parse_columns(" , ").is_err());
+    }
+
+    #[test]
+    fn test_parse_sort() {
+        assert_eq!(
+            parse_sort("priority,-updated").unwrap(),
+            vec![
+                SortKey {
+                    column: IssueColumn::Priority,
+                    descending: false
+                },
+                SortKey {
+                    column: IssueColumn::Updated,
+                    descending: true
+                },
+            ]
+        );
+        assert!(parse_sort("-nope").is_err());
+        assert!(parse_sort("").is_err());
+    }
+
+    #[test]
+    fn test_sort_by_priority_puts_no_priority_last() {
+        let mut issues = vec![
+            issue("ENG-1", 0, "2026-01-01T00:00:00Z"),
+            issue("ENG-2", 3, "2026-01-01T00:00:00Z"),
+            issue("ENG-3", 1, "2026-01-01T00:00:00Z"),
+        ];
+
+        sort_issues(&mut issues, &parse_sort("priority").unwrap());
+        assert_eq!(identifiers(&issues), vec!["ENG-3", "ENG-2", "ENG-1"]);
+
+        sort_issues(&mut issues, &parse_sort("-priority").unwrap());
+        assert_eq!(identifiers(&issues), vec!["ENG-2", "ENG-3", "ENG-1"]);
+    }
+
+    #[test]
+    fn test_sort_multiple_keys() {
+        let mut issues = vec![
+            issue("ENG-1", 2, "2026-01-01T00:00:00Z"),
+            issue("ENG-2", 1, "2026-01-01T00:00:00Z"),
+            issue("ENG-3", 2, "2026-03-01T00:00:00Z"),
+        ];
+
+        sort_issues(&mut issues, &parse_sort("priority,-updated").unwrap());
+        assert_eq!(identifiers(&issues), vec!["ENG-2", "ENG-3", "ENG-1"]);
+    }
+
+    #[test]
+    fn test_sort_identifiers_numerically() {
+        let mut issues = vec![
+            issue("ENG-10", 0, "2026-01-01T00:00:00Z"),
+            issue("ENG-9", 0, "2026-01-01T00:00:00Z"),
+        ];
+
+        sort_issues(&mut issues, &parse_sort("identifier").unwrap());
+        assert_eq!(identifiers(&issues), vec!["ENG-9", "ENG-10"]);
+    }
+}
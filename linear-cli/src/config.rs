@@ -5,6 +5,15 @@ use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::columns::{parse_columns, IssueColumn};
+use crate::constants::requests::{DEFAULT_MAX_CONCURRENT_REQUESTS, DEFAULT_REQUESTS_PER_HOUR};
+use crate::formats::Format;
+use crate::output_template::OutputTemplate;
+
+/// Commands whose table columns can be set in the `[columns]` section
+const COLUMN_COMMANDS: &[&str] = &["issues"];
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Default)]
 pub struct Config {
@@ -17,9 +26,20 @@ pub struct Config {
     #[serde(default)]
     pub api_url: Option<String>,
     #[serde(default)]
+    pub max_concurrent_requests: Option<usize>,
+    #[serde(default)]
+    pub requests_per_hour: Option<u32>,
+    /// Longest rate-limit reset to wait out, e.g. "30m" or "1h"
+    #[serde(default, deserialize_with = "validate_duration")]
+    pub max_rate_limit_wait: Option<String>,
+    #[serde(default)]
     pub aliases: Option<ConfigAliases>,
     #[serde(default)]
     pub completions: Option<ConfigCompletions>,
+    #[serde(default)]
+    pub columns: Option<ConfigColumns>,
+    #[serde(default)]
+    pub templates: Option<ConfigTemplates>,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize)]
@@ -28,6 +48,20 @@ pub struct ConfigAliases {
     pub commands: HashMap<String, Vec<String>>,
 }
 
+/// Table column specs keyed by command name, e.g. `issues = "identifier,title,priority"`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ConfigColumns {
+    #[serde(flatten)]
+    pub commands: HashMap<String, String>,
+}
+
+/// Named output templates, e.g. `brief = "{{identifier}} {{title}}"`, used as `--template brief`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ConfigTemplates {
+    #[serde(flatten)]
+    pub named: HashMap<String, String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct ConfigCompletions {
     #[serde(default, deserialize_with = "validate_duration")]
@@ -115,6 +149,11 @@ impl Config {
             default_assignee: other.default_assignee.or(self.default_assignee),
             preferred_format: other.preferred_format.or(self.preferred_format),
             api_url: other.api_url.or(self.api_url),
+            max_concurrent_requests: other
+                .max_concurrent_requests
+                .or(self.max_concurrent_requests),
+            requests_per_hour: other.requests_per_hour.or(self.requests_per_hour),
+            max_rate_limit_wait: other.max_rate_limit_wait.or(self.max_rate_limit_wait),
             aliases: match (self.aliases, other.aliases) {
                 (Some(base), Some(other)) => Some(base.merge(other)),
                 (Some(base), None) => Some(base),
@@ -122,17 +161,100 @@ impl Config {
                 (None, None) => None,
             },
             completions: other.completions.or(self.completions),
+            columns: match (self.columns, other.columns) {
+                (Some(mut base), Some(other)) => {
+                    base.commands.extend(other.commands);
+                    Some(base)
+                }
+                (base, other) => other.or(base),
+            },
+            templates: match (self.templates, other.templates) {
+                (Some(mut base), Some(other)) => {
+                    base.named.extend(other.named);
+                    Some(base)
+                }
+                (base, other) => other.or(base),
+            },
         }
     }
 
     /// Validate the entire configuration
     pub fn validate(&self) -> Result<()> {
+        if self.max_concurrent_requests == Some(0) {
+            return Err(anyhow!("max_concurrent_requests must be at least 1"));
+        }
+
         if let Some(ref aliases) = self.aliases {
             aliases.validate().context("Invalid alias configuration")?;
         }
 
+        if let Some(ref columns) = self.columns {
+            for (command, spec) in &columns.commands {
+                if !COLUMN_COMMANDS.contains(&command.as_str()) {
+                    return Err(anyhow!(
+                        "Unknown command '{command}' in [columns]. Columns can be set for: {}",
+                        COLUMN_COMMANDS.join(", ")
+                    ));
+                }
+                parse_columns(spec).map_err(|e| anyhow!("Invalid columns for '{command}': {e}"))?;
+            }
+        }
+
+        if let Some(ref templates) = self.templates {
+            for (name, source) in &templates.named {
+                OutputTemplate::parse(source)
+                    .map_err(|e| anyhow!("Invalid output template '{name}': {e}"))?;
+            }
+        }
+
         Ok(())
     }
+
+    /// Output format to use when a command isn't given `--json` or `--format`
+    pub fn preferred_output_format(&self) -> Option<Format> {
+        self.preferred_format.as_deref().and_then(Format::from_name)
+    }
+
+    /// Most requests the client keeps in flight at once
+    pub fn max_concurrent_requests(&self) -> usize {
+        self.max_concurrent_requests
+            .unwrap_or(DEFAULT_MAX_CONCURRENT_REQUESTS)
+    }
+
+    /// Requests per hour the client paces itself to; 0 turns pacing off
+    pub fn requests_per_hour(&self) -> u32 {
+        self.requests_per_hour.unwrap_or(DEFAULT_REQUESTS_PER_HOUR)
+    }
+
+    /// Longest rate-limit reset the client waits for before giving up
+    pub fn max_rate_limit_wait(&self) -> Duration {
+        self.max_rate_limit_wait
+            .as_deref()
+            .and_then(parse_duration)
+            .unwrap_or(linear_sdk::constants::retry::MAX_RATE_LIMIT_WAIT)
+    }
+
+    /// Source of a named output template from the `[templates]` section
+    pub fn output_template(&self, name: &str) -> Option<&str> {
+        self.templates.as_ref()?.named.get(name).map(String::as_str)
+    }
+
+    /// Names of the templates configured under `[templates]`, sorted
+    pub fn template_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .templates
+            .as_ref()
+            .map(|t| t.named.keys().map(String::as_str).collect())
+            .unwrap_or_default();
+        names.sort_unstable();
+        names
+    }
+
+    /// Table columns configured for a command, if any
+    pub fn columns_for(&self, command: &str) -> Option<Vec<IssueColumn>> {
+        let spec = self.columns.as_ref()?.commands.get(command)?;
+        parse_columns(spec).ok()
+    }
 }
 
 impl ConfigAliases {
@@ -174,9 +296,9 @@ where
 
     if let Some(ref format) = value {
         match format.as_str() {
-            "table" | "json" | "yaml" => Ok(value),
+            "table" | "json" | "yaml" | "csv" | "tsv" | "ndjson" => Ok(value),
             _ => Err(D::Error::custom(format!(
-                "Invalid format '{format}'. Must be one of: table, json, yaml"
+                "Invalid format '{format}'. Must be one of: table, json, yaml, csv, tsv, ndjson"
             ))),
         }
     } else {
@@ -184,6 +306,23 @@ where
     }
 }
 
+/// Parse a duration such as "90s", "30m", "1h", or "2d", as accepted by `validate_duration`
+fn parse_duration(duration: &str) -> Option<Duration> {
+    let (amount, unit) = duration.split_at(duration.len().checked_sub(1)?);
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        _ => return None,
+    };
+    amount
+        .parse::<u64>()
+        .ok()?
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+}
+
 // Custom deserializer for duration validation
 fn validate_duration<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
 where
@@ -251,4 +390,157 @@ mod tests {
         assert_eq!(merged.default_assignee, Some("base_user".to_string()));
         assert_eq!(merged.api_url, Some("https://custom.api.com".to_string()));
     }
+
+    #[test]
+    fn test_preferred_output_format() {
+        let config: Config = toml::from_str(r#"preferred_format = "csv""#).unwrap();
+        assert_eq!(config.preferred_output_format(), Some(Format::Csv));
+        assert_eq!(Config::default().preferred_output_format(), None);
+        assert!(toml::from_str::<Config>(r#"preferred_format = "xml""#).is_err());
+    }
+
+    #[test]
+    fn test_request_limits_config() {
+        let defaults = Config::default();
+        assert_eq!(
+            defaults.max_concurrent_requests(),
+            DEFAULT_MAX_CONCURRENT_REQUESTS
+        );
+        assert_eq!(defaults.requests_per_hour(), DEFAULT_REQUESTS_PER_HOUR);
+
+        let config: Config =
+            toml::from_str("max_concurrent_requests = 2\nrequests_per_hour = 600").unwrap();
+        assert_eq!(config.max_concurrent_requests(), 2);
+        assert_eq!(config.requests_per_hour(), 600);
+
+        let merged = config.merge(Config {
+            requests_per_hour: Some(0),
+            ..Default::default()
+        });
+        assert_eq!(merged.max_concurrent_requests(), 2);
+        assert_eq!(merged.requests_per_hour(), 0);
+
+        let zero: Config = toml::from_str("max_concurrent_requests = 0").unwrap();
+        assert!(zero.validate().is_err());
+    }
+
+    #[test]
+    fn test_max_rate_limit_wait_config() {
+        assert_eq!(
+            Config::default().max_rate_limit_wait(),
+            linear_sdk::constants::retry::MAX_RATE_LIMIT_WAIT
+        );
+
+        let config: Config = toml::from_str(r#"max_rate_limit_wait = "90m""#).unwrap();
+        assert_eq!(config.max_rate_limit_wait(), Duration::from_secs(90 * 60));
+
+        let config: Config = toml::from_str(r#"max_rate_limit_wait = "45s""#).unwrap();
+        assert_eq!(config.max_rate_limit_wait(), Duration::from_secs(45));
+
+        assert!(toml::from_str::<Config>(r#"max_rate_limit_wait = "soon""#).is_err());
+    }
+
+    #[test]
+    fn test_templates_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [templates]
+            brief = "{{identifier}} {{title | truncate 40}}"
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        assert_eq!(
+            config.output_template("brief"),
+            Some("{{identifier}} {{title | truncate 40}}")
+        );
+        assert_eq!(config.output_template("missing"), None);
+
+        let invalid: Config = toml::from_str(
+            r#"
+            [templates]
+            broken = "{{title | sparkle}}"
+            "#,
+        )
+        .unwrap();
+        let err = invalid.validate().unwrap_err().to_string();
+        assert!(err.contains("Invalid output template 'broken'"));
+    }
+
+    #[test]
+    fn test_columns_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [columns]
+            issues = "identifier,title,priority,due"
+            "#,
+        )
+        .unwrap();
+        config.validate().unwrap();
+
+        assert_eq!(
+            config.columns_for("issues"),
+            Some(vec![
+                IssueColumn::Identifier,
+                IssueColumn::Title,
+                IssueColumn::Priority,
+                IssueColumn::Due,
+            ])
+        );
+        assert_eq!(config.columns_for("my-work"), None);
+    }
+
+    #[test]
+    fn test_invalid_columns_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [columns]
+            issues = "identifier,colour"
+            "#,
+        )
+        .unwrap();
+
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("Invalid columns for 'issues'"));
+    }
+
+    #[test]
+    fn test_merge_columns_config() {
+        let base = Config {
+            columns: Some(ConfigColumns {
+                commands: HashMap::from([("issues".to_string(), "identifier,title".to_string())]),
+            }),
+            ..Default::default()
+        };
+        let project = Config {
+            columns: Some(ConfigColumns {
+                commands: HashMap::from([("issues".to_string(), "title".to_string())]),
+            }),
+            ..Default::default()
+        };
+
+        let kept = base.clone().merge(Config::default());
+        assert_eq!(
+            kept.columns_for("issues"),
+            Some(vec![IssueColumn::Identifier, IssueColumn::Title])
+        );
+
+        let merged = base.merge(project);
+        assert_eq!(merged.columns_for("issues"), Some(vec![IssueColumn::Title]));
+    }
+
+    #[test]
+    fn test_unknown_columns_command() {
+        let config: Config = toml::from_str(
+            r#"
+            [columns]
+            search = "identifier,title"
+            "#,
+        )
+        .unwrap();
+
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("Unknown command 'search' in [columns]"));
+    }
 }
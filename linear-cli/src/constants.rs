@@ -49,6 +49,57 @@ pub mod ui {
     pub const OSC8_HYPERLINK_FORMAT: &str = "\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\";
 }
 
+/// Bulk issue operations
+pub mod bulk {
+    /// Mutations kept in flight at once by `update`, `close`, `reopen`, and `comment`
+    pub const MAX_CONCURRENT_MUTATIONS: usize = 4;
+}
+
+/// Client-side request limits, overridable in the config file
+pub mod requests {
+    /// Requests kept in flight at once across the whole run
+    pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 4;
+
+    /// Sustained request rate, matching Linear's hourly quota for API keys
+    pub const DEFAULT_REQUESTS_PER_HOUR: u32 = 1500;
+}
+
+/// Full-screen terminal UI
+pub mod tui {
+    /// How long to wait for a key press before checking for background work
+    pub const POLL_INTERVAL_MS: u64 = 250;
+
+    /// How often to fetch issues changed since the last refresh
+    pub const REFRESH_INTERVAL_SECS: u64 = 30;
+}
+
+/// Interactive issue picker
+pub mod picker {
+    /// Recently viewed issues remembered for the picker
+    pub const MAX_RECENT_ISSUES: usize = 20;
+
+    /// Assigned and created issues each fetched for the picker
+    pub const MY_WORK_LIMIT: i32 = 50;
+}
+
+/// Markdown directory sync
+pub mod sync {
+    /// File in the sync directory recording what each issue file looked like when last synced
+    pub const STATE_FILE: &str = ".linear-sync.json";
+}
+
+/// Cycle burndown and velocity reports
+pub mod report {
+    /// Rows in a burndown chart
+    pub const CHART_HEIGHT: usize = 10;
+
+    /// Columns a burndown chart's days are spread across
+    pub const CHART_WIDTH: usize = 60;
+
+    /// Width of the longest bar in a velocity report
+    pub const VELOCITY_BAR_WIDTH: usize = 40;
+}
+
 /// Status name aliases for improved UX
 #[allow(dead_code)] // Reserved for future CLI enhancement
 pub static STATUS_ALIASES: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
@@ -113,6 +164,17 @@ mod tests {
         assert_eq!(urls::OAUTH_CALLBACK_BASE, "http://localhost");
     }
 
+    #[test]
+    fn test_bulk_constants() {
+        assert_eq!(bulk::MAX_CONCURRENT_MUTATIONS, 4);
+    }
+
+    #[test]
+    fn test_request_constants() {
+        assert_eq!(requests::DEFAULT_MAX_CONCURRENT_REQUESTS, 4);
+        assert_eq!(requests::DEFAULT_REQUESTS_PER_HOUR, 1500);
+    }
+
     #[test]
     fn test_ui_constants() {
         assert_eq!(ui::BORDER_LINE_LENGTH, 50);
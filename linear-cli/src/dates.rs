@@ -0,0 +1,190 @@
+// ABOUTME: Parses absolute and relative date arguments such as "2026-01-01", "7d", or "friday"
+// ABOUTME: Converts them into the timestamps, durations, and due dates Linear's API accepts
+
+use chrono::{DateTime, Datelike, Days, Local, Months, NaiveDate, Weekday};
+
+const RELATIVE_HELP: &str =
+    "use a date like 2026-01-31 or a relative amount like 12h, 7d, 2w, 3m (months), or 1y";
+
+const DUE_HELP: &str =
+    "use a date like 2026-11-01, an offset like +3d or +2w, today, tomorrow, a weekday, or none";
+
+/// Split a relative amount like "7d" into its count and unit
+fn parse_relative(input: &str) -> Option<(u32, char)> {
+    let input = input.strip_prefix('+').unwrap_or(input);
+    let unit = input.chars().last()?.to_ascii_lowercase();
+    let amount = input[..input.len() - unit.len_utf8()].parse().ok()?;
+    matches!(unit, 'h' | 'd' | 'w' | 'm' | 'y').then_some((amount, unit))
+}
+
+fn iso_duration(amount: u32, unit: char) -> String {
+    match unit {
+        'h' => format!("PT{amount}H"),
+        unit => format!("P{amount}{}", unit.to_ascii_uppercase()),
+    }
+}
+
+/// Parse a lower time bound such as `7d` (seven days ago), `2026-01-01`, or an RFC 3339
+/// timestamp into a value accepted by Linear's `DateComparator`
+pub fn parse_since(input: &str) -> Result<String, String> {
+    let input = input.trim();
+
+    if let Some((amount, unit)) = parse_relative(input) {
+        return Ok(format!("-{}", iso_duration(amount, unit)));
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Ok(format!("{date}T00:00:00.000Z"));
+    }
+
+    if DateTime::parse_from_rfc3339(input).is_ok() {
+        return Ok(input.to_string());
+    }
+
+    Err(format!("Invalid date '{input}': {RELATIVE_HELP}"))
+}
+
+/// Parse an upper date bound such as `7d` (seven days from now) or `2026-11-01` into a value
+/// accepted by Linear's `TimelessDateComparator`
+pub fn parse_until_date(input: &str) -> Result<String, String> {
+    let input = input.trim();
+
+    match parse_relative(input) {
+        Some((_, 'h')) => {
+            return Err(format!(
+                "Invalid date '{input}': due dates have no time of day, use days or longer"
+            ))
+        }
+        Some((amount, unit)) => return Ok(iso_duration(amount, unit)),
+        None => {}
+    }
+
+    if NaiveDate::parse_from_str(input, "%Y-%m-%d").is_ok() {
+        return Ok(input.to_string());
+    }
+
+    Err(format!("Invalid date '{input}': {RELATIVE_HELP}"))
+}
+
+/// Parse a `--due` value into a YYYY-MM-DD date, or an empty string for "none" (clear it)
+pub fn parse_due_date(input: &str) -> Result<String, String> {
+    resolve_due_date(input, Local::now().date_naive())
+}
+
+/// Resolve a due date relative to `today`. Weekday names mean the next such day after today.
+fn resolve_due_date(input: &str, today: NaiveDate) -> Result<String, String> {
+    let input = input.trim();
+    let invalid = || format!("Invalid due date '{input}': {DUE_HELP}");
+
+    let date = match input.to_lowercase().as_str() {
+        "none" | "clear" => return Ok(String::new()),
+        "today" => today,
+        "tomorrow" => today + Days::new(1),
+        lowered => {
+            if let Ok(weekday) = lowered.parse::<Weekday>() {
+                let ahead = (7 + weekday.num_days_from_monday()
+                    - today.weekday().num_days_from_monday())
+                    % 7;
+                today + Days::new(if ahead == 0 { 7 } else { u64::from(ahead) })
+            } else if let Some((amount, unit)) = parse_relative(input) {
+                match unit {
+                    'd' => today.checked_add_days(Days::new(amount.into())),
+                    'w' => today.checked_add_days(Days::new(u64::from(amount) * 7)),
+                    'm' => today.checked_add_months(Months::new(amount)),
+                    'y' => amount
+                        .checked_mul(12)
+                        .and_then(|months| today.checked_add_months(Months::new(months))),
+                    _ => None,
+                }
+                .ok_or_else(invalid)?
+            } else {
+                NaiveDate::parse_from_str(input, "%Y-%m-%d").map_err(|_| invalid())?
+            }
+        }
+    };
+
+    Ok(date.format("%Y-%m-%d").to_string())
+}
+
+/// Whether a YYYY-MM-DD due date is before today
+pub fn is_overdue(due_date: &str) -> bool {
+    NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+        .is_ok_and(|date| date < Local::now().date_naive())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_since_relative() {
+        assert_eq!(parse_since("7d").unwrap(), "-P7D");
+        assert_eq!(parse_since("2w").unwrap(), "-P2W");
+        assert_eq!(parse_since("12h").unwrap(), "-PT12H");
+        assert_eq!(parse_since("3m").unwrap(), "-P3M");
+        assert_eq!(parse_since("1Y").unwrap(), "-P1Y");
+    }
+
+    #[test]
+    fn test_parse_since_absolute() {
+        assert_eq!(
+            parse_since("2026-01-31").unwrap(),
+            "2026-01-31T00:00:00.000Z"
+        );
+        assert_eq!(
+            parse_since("2026-01-31T09:30:00Z").unwrap(),
+            "2026-01-31T09:30:00Z"
+        );
+    }
+
+    #[test]
+    fn test_parse_since_invalid() {
+        assert!(parse_since("yesterday").is_err());
+        assert!(parse_since("7").is_err());
+        assert!(parse_since("d").is_err());
+        assert!(parse_since("2026-13-01").is_err());
+    }
+
+    #[test]
+    fn test_parse_until_date() {
+        assert_eq!(parse_until_date("7d").unwrap(), "P7D");
+        assert_eq!(parse_until_date("+2w").unwrap(), "P2W");
+        assert_eq!(parse_until_date("2026-11-01").unwrap(), "2026-11-01");
+        assert!(parse_until_date("12h").is_err());
+        assert!(parse_until_date("soon").is_err());
+    }
+
+    #[test]
+    fn test_resolve_due_date() {
+        // 2026-10-14 is a Wednesday
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        let due = |input: &str| resolve_due_date(input, today);
+
+        assert_eq!(due("2026-11-01").unwrap(), "2026-11-01");
+        assert_eq!(due("today").unwrap(), "2026-10-14");
+        assert_eq!(due("Tomorrow").unwrap(), "2026-10-15");
+        assert_eq!(due("+3d").unwrap(), "2026-10-17");
+        assert_eq!(due("2w").unwrap(), "2026-10-28");
+        assert_eq!(due("+1m").unwrap(), "2026-11-14");
+        assert_eq!(due("friday").unwrap(), "2026-10-16");
+        assert_eq!(due("mon").unwrap(), "2026-10-19");
+        assert_eq!(due("wednesday").unwrap(), "2026-10-21");
+        assert_eq!(due("none").unwrap(), "");
+    }
+
+    #[test]
+    fn test_resolve_due_date_invalid() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+        assert!(resolve_due_date("+12h", today).is_err());
+        assert!(resolve_due_date("someday", today).is_err());
+        assert!(resolve_due_date("2026-02-30", today).is_err());
+        assert!(resolve_due_date("+400000000y", today).is_err());
+    }
+
+    #[test]
+    fn test_is_overdue() {
+        assert!(is_overdue("2020-01-01"));
+        assert!(!is_overdue("2999-01-01"));
+        assert!(!is_overdue("not a date"));
+    }
+}
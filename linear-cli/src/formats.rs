@@ -0,0 +1,781 @@
+// ABOUTME: Output formats selected with --format (yaml, csv, tsv, ndjson, ids, template=...)
+// ABOUTME: Each format is an OutputFormat implementation chosen alongside table and JSON output
+
+use linear_sdk::{
+    Cycle, CycleDetail, DetailedIssue, Issue, IssueWithComments, LinearError, MyWork, Project,
+    ProjectMilestone, Result, SearchResult, Team,
+};
+use serde::Serialize;
+use std::str::FromStr;
+
+use crate::columns::IssueColumn;
+use crate::output::{cycle_status, JsonFormatter, OutputFormat, TableFormatter, TemplateFormatter};
+use crate::output_template::OutputTemplate;
+
+/// Output formats accepted by `--format` and `preferred_format`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Format {
+    Table,
+    Json,
+    Yaml,
+    Csv,
+    Tsv,
+    Ndjson,
+    /// Bare identifiers, one per line, for piping into bulk commands
+    Ids,
+    /// A user-defined template's source, or the name of one defined in the config file
+    Template(String),
+}
+
+impl Format {
+    /// Parse a format name as written in the config file
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "table" => Some(Format::Table),
+            "json" => Some(Format::Json),
+            "yaml" => Some(Format::Yaml),
+            "csv" => Some(Format::Csv),
+            "tsv" => Some(Format::Tsv),
+            "ndjson" => Some(Format::Ndjson),
+            "ids" => Some(Format::Ids),
+            _ => None,
+        }
+    }
+
+    /// Build a template format from `--template` or `template=...`; `@path` reads a file
+    pub fn template(spec: &str) -> std::result::Result<Self, String> {
+        let source = match spec.strip_prefix('@') {
+            Some(path) => std::fs::read_to_string(path)
+                .map_err(|e| format!("Failed to read template file '{path}': {e}"))?,
+            None => spec.to_string(),
+        };
+        OutputTemplate::parse(&source)?;
+        Ok(Format::Template(source))
+    }
+
+    /// Parse a `linear report` format; reports are charts or whole documents, so line-oriented
+    /// formats don't apply
+    pub fn report(spec: &str) -> std::result::Result<Self, String> {
+        match Format::from_name(spec) {
+            Some(
+                format @ (Format::Table | Format::Json | Format::Yaml | Format::Csv | Format::Tsv),
+            ) => Ok(format),
+            _ => Err(format!(
+                "Invalid report format '{spec}'. Must be one of: table, json, yaml, csv, tsv"
+            )),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if let Some(spec) = s.strip_prefix("template=") {
+            return Format::template(spec);
+        }
+        Format::from_name(s).ok_or_else(|| {
+            format!(
+                "Invalid format '{s}'. Must be one of: table, json, yaml, csv, tsv, ndjson, ids, \
+                 template=<template>"
+            )
+        })
+    }
+}
+
+/// Settings shared by every formatter that `create_formatter` may build
+pub struct FormatterOptions {
+    pub pretty: bool,
+    pub use_color: bool,
+    pub is_interactive: bool,
+    pub columns: Vec<IssueColumn>,
+}
+
+/// Build the formatter for `format`
+pub fn create_formatter(
+    format: Format,
+    options: FormatterOptions,
+) -> Result<Box<dyn OutputFormat>> {
+    Ok(match format {
+        Format::Table => Box::new(
+            TableFormatter::new_with_interactive(options.use_color, options.is_interactive)
+                .with_columns(options.columns),
+        ),
+        Format::Json => Box::new(JsonFormatter::new(options.pretty)),
+        Format::Yaml => Box::new(YamlFormatter),
+        Format::Ndjson => Box::new(NdjsonFormatter),
+        Format::Ids => Box::new(IdsFormatter),
+        Format::Csv => Box::new(DelimitedFormatter::csv(options.columns)),
+        Format::Tsv => Box::new(DelimitedFormatter::tsv(options.columns)),
+        Format::Template(source) => Box::new(TemplateFormatter::new(&source, options.use_color)?),
+    })
+}
+
+pub(crate) fn output_error(err: impl std::fmt::Display) -> LinearError {
+    LinearError::InvalidInput {
+        message: format!("Failed to format output: {err}"),
+    }
+}
+
+/// Serialize `value` and add a field naming where it came from, e.g. `"category": "assigned"`
+pub(crate) fn tagged<T: Serialize>(value: &T, key: &str, tag: &str) -> Result<serde_json::Value> {
+    let mut value = serde_json::to_value(value)?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert(key.to_string(), tag.into());
+    }
+    Ok(value)
+}
+
+pub struct YamlFormatter;
+
+impl YamlFormatter {
+    fn to_yaml<T: Serialize + ?Sized>(&self, value: &T) -> Result<String> {
+        let yaml = serde_yaml::to_string(value).map_err(output_error)?;
+        Ok(yaml.trim_end().to_string())
+    }
+}
+
+impl OutputFormat for YamlFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        self.to_yaml(issues)
+    }
+
+    fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
+        self.to_yaml(issue)
+    }
+
+    fn format_detailed_issue_rich(
+        &self,
+        issue: &DetailedIssue,
+        _is_interactive: bool,
+    ) -> Result<String> {
+        self.format_detailed_issue(issue)
+    }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        self.to_yaml(projects)
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        self.to_yaml(teams)
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        self.to_yaml(issue)
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        self.to_yaml(my_work)
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        self.to_yaml(results)
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        self.to_yaml(cycles)
+    }
+
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String> {
+        self.to_yaml(cycle)
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        self.to_yaml(milestones)
+    }
+}
+
+/// Newline-delimited JSON: one compact object per line, ready for `jq -c` or log pipelines
+pub struct NdjsonFormatter;
+
+impl NdjsonFormatter {
+    fn lines<T: Serialize>(&self, items: impl IntoIterator<Item = T>) -> Result<String> {
+        let lines = items
+            .into_iter()
+            .map(|item| serde_json::to_string(&item))
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(lines.join("\n"))
+    }
+}
+
+impl OutputFormat for NdjsonFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        self.lines(issues)
+    }
+
+    fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
+        self.lines([issue])
+    }
+
+    fn format_detailed_issue_rich(
+        &self,
+        issue: &DetailedIssue,
+        _is_interactive: bool,
+    ) -> Result<String> {
+        self.format_detailed_issue(issue)
+    }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        self.lines(projects)
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        self.lines(teams)
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        let comments = issue
+            .comments
+            .iter()
+            .map(|comment| tagged(comment, "issue", &issue.identifier))
+            .collect::<Result<Vec<_>>>()?;
+        self.lines(comments)
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        let assigned = my_work
+            .assigned_issues
+            .iter()
+            .map(|issue| tagged(issue, "category", "assigned"));
+        let created = my_work
+            .created_issues
+            .iter()
+            .map(|issue| tagged(issue, "category", "created"));
+        self.lines(assigned.chain(created).collect::<Result<Vec<_>>>()?)
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        let issues = results
+            .issues
+            .iter()
+            .map(|issue| tagged(issue, "type", "issue"));
+        let documents = results
+            .documents
+            .iter()
+            .map(|doc| tagged(doc, "type", "document"));
+        let projects = results
+            .projects
+            .iter()
+            .map(|project| tagged(project, "type", "project"));
+        self.lines(
+            issues
+                .chain(documents)
+                .chain(projects)
+                .collect::<Result<Vec<_>>>()?,
+        )
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        self.lines(cycles)
+    }
+
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String> {
+        self.lines(&cycle.issues)
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        self.lines(milestones)
+    }
+}
+
+/// One identifier per line: issue identifiers, team keys, or IDs for everything else
+pub struct IdsFormatter;
+
+impl IdsFormatter {
+    fn lines<'a>(&self, ids: impl IntoIterator<Item = &'a str>) -> Result<String> {
+        Ok(ids.into_iter().collect::<Vec<_>>().join("\n"))
+    }
+}
+
+impl OutputFormat for IdsFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        self.lines(issues.iter().map(|issue| issue.identifier.as_str()))
+    }
+
+    fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
+        Ok(issue.identifier.clone())
+    }
+
+    fn format_detailed_issue_rich(
+        &self,
+        issue: &DetailedIssue,
+        _is_interactive: bool,
+    ) -> Result<String> {
+        self.format_detailed_issue(issue)
+    }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        self.lines(projects.iter().map(|project| project.id.as_str()))
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        self.lines(teams.iter().map(|team| team.key.as_str()))
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        self.lines(issue.comments.iter().map(|comment| comment.id.as_str()))
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        let issues = my_work
+            .assigned_issues
+            .iter()
+            .chain(&my_work.created_issues);
+        self.lines(issues.map(|issue| issue.identifier.as_str()))
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        self.format_issues(&results.issues)
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        self.lines(cycles.iter().map(|cycle| cycle.id.as_str()))
+    }
+
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String> {
+        self.lines(cycle.issues.iter().map(|issue| issue.identifier.as_str()))
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        self.lines(milestones.iter().map(|milestone| milestone.id.as_str()))
+    }
+}
+
+/// CSV or TSV output with a header row, suitable for spreadsheets
+pub struct DelimitedFormatter {
+    delimiter: u8,
+    columns: Vec<IssueColumn>,
+}
+
+impl DelimitedFormatter {
+    pub fn csv(columns: Vec<IssueColumn>) -> Self {
+        Self {
+            delimiter: b',',
+            columns,
+        }
+    }
+
+    pub fn tsv(columns: Vec<IssueColumn>) -> Self {
+        Self {
+            delimiter: b'\t',
+            columns,
+        }
+    }
+
+    fn write<I, R>(&self, headers: &[&str], rows: I) -> Result<String>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = String>,
+    {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .from_writer(Vec::new());
+
+        writer.write_record(headers).map_err(output_error)?;
+        for row in rows {
+            writer
+                .write_record(row.into_iter().collect::<Vec<_>>())
+                .map_err(output_error)?;
+        }
+
+        let bytes = writer.into_inner().map_err(output_error)?;
+        let output = String::from_utf8(bytes).map_err(output_error)?;
+        Ok(output.trim_end().to_string())
+    }
+
+    fn issue_headers(&self) -> Vec<&'static str> {
+        self.columns.iter().map(|column| column.header()).collect()
+    }
+
+    fn issue_row(&self, issue: &Issue) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.plain_value(issue))
+            .collect()
+    }
+}
+
+impl OutputFormat for DelimitedFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        self.write(
+            &self.issue_headers(),
+            issues.iter().map(|issue| self.issue_row(issue)),
+        )
+    }
+
+    fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
+        self.write(
+            &[
+                "Issue", "Title", "Status", "Assignee", "Team", "Project", "Priority", "Labels",
+                "Created", "Updated", "URL",
+            ],
+            [vec![
+                issue.identifier.clone(),
+                issue.title.clone(),
+                issue.state.name.clone(),
+                issue
+                    .assignee
+                    .as_ref()
+                    .map(|a| a.name.clone())
+                    .unwrap_or_default(),
+                issue
+                    .team
+                    .as_ref()
+                    .map(|t| t.key.clone())
+                    .unwrap_or_default(),
+                issue
+                    .project
+                    .as_ref()
+                    .map(|p| p.name.clone())
+                    .unwrap_or_default(),
+                issue.priority_label.clone().unwrap_or_default(),
+                issue
+                    .labels
+                    .iter()
+                    .map(|l| l.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                issue.created_at.clone(),
+                issue.updated_at.clone(),
+                issue.url.clone(),
+            ]],
+        )
+    }
+
+    fn format_detailed_issue_rich(
+        &self,
+        issue: &DetailedIssue,
+        _is_interactive: bool,
+    ) -> Result<String> {
+        self.format_detailed_issue(issue)
+    }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        self.write(
+            &["ID", "Name", "State", "Progress", "Lead", "URL"],
+            projects.iter().map(|p| {
+                vec![
+                    p.id.clone(),
+                    p.name.clone(),
+                    p.state.clone(),
+                    p.progress.map(|v| v.to_string()).unwrap_or_default(),
+                    p.lead.as_ref().map(|l| l.name.clone()).unwrap_or_default(),
+                    p.url.clone(),
+                ]
+            }),
+        )
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        self.write(
+            &["Key", "Name", "Members", "ID"],
+            teams.iter().map(|t| {
+                vec![
+                    t.key.clone(),
+                    t.name.clone(),
+                    t.members.len().to_string(),
+                    t.id.clone(),
+                ]
+            }),
+        )
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        self.write(
+            &["Issue", "Author", "Created", "Body"],
+            issue.comments.iter().map(|c| {
+                vec![
+                    issue.identifier.clone(),
+                    c.user.name.clone(),
+                    c.created_at.clone(),
+                    c.body.clone(),
+                ]
+            }),
+        )
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        let mut headers = vec!["Category"];
+        headers.extend(self.issue_headers());
+
+        let tagged = |category: &'static str, issues: &'_ [Issue]| {
+            issues
+                .iter()
+                .map(move |issue| {
+                    let mut row = vec![category.to_string()];
+                    row.extend(self.issue_row(issue));
+                    row
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut rows = tagged("assigned", &my_work.assigned_issues);
+        rows.extend(tagged("created", &my_work.created_issues));
+        self.write(&headers, rows)
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        let issues = results.issues.iter().map(|issue| {
+            vec![
+                "issue".to_string(),
+                issue.identifier.clone(),
+                issue.title.clone(),
+                String::new(),
+            ]
+        });
+        let documents = results.documents.iter().map(|doc| {
+            vec![
+                "document".to_string(),
+                String::new(),
+                doc.title.clone(),
+                doc.url.clone(),
+            ]
+        });
+        let projects = results.projects.iter().map(|project| {
+            vec![
+                "project".to_string(),
+                String::new(),
+                project.name.clone(),
+                project.url.clone(),
+            ]
+        });
+
+        self.write(
+            &["Type", "Identifier", "Title", "URL"],
+            issues.chain(documents).chain(projects),
+        )
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        self.write(
+            &[
+                "Number",
+                "Name",
+                "Starts",
+                "Ends",
+                "Status",
+                "Scope",
+                "Started",
+                "Completed",
+                "Issues",
+                "Completed Issues",
+                "Progress",
+                "ID",
+            ],
+            cycles.iter().map(|c| {
+                vec![
+                    c.number.to_string(),
+                    c.name.clone().unwrap_or_default(),
+                    c.starts_at.clone(),
+                    c.ends_at.clone(),
+                    cycle_status(c).to_string(),
+                    c.scope.to_string(),
+                    c.started_scope.to_string(),
+                    c.completed_scope.to_string(),
+                    c.issue_count.to_string(),
+                    c.completed_issue_count.to_string(),
+                    c.progress.to_string(),
+                    c.id.clone(),
+                ]
+            }),
+        )
+    }
+
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String> {
+        self.write(
+            &["Issue", "Title", "State", "Estimate", "Assignee"],
+            cycle.issues.iter().map(|issue| {
+                vec![
+                    issue.identifier.clone(),
+                    issue.title.clone(),
+                    issue.state.name.clone(),
+                    issue.estimate.map(|e| e.to_string()).unwrap_or_default(),
+                    issue.assignee.clone().unwrap_or_default(),
+                ]
+            }),
+        )
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        self.write(
+            &["Name", "Target Date", "Status", "Progress", "ID"],
+            milestones.iter().map(|m| {
+                vec![
+                    m.name.clone(),
+                    m.target_date.clone().unwrap_or_default(),
+                    m.status.clone(),
+                    m.progress.to_string(),
+                    m.id.clone(),
+                ]
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::columns::DEFAULT_ISSUE_COLUMNS;
+
+    fn issue(identifier: &str, title: &str, assignee: Option<&str>) -> Issue {
+        Issue {
+            id: format!("id-{identifier}"),
+            identifier: identifier.to_string(),
+            title: title.to_string(),
+            status: "Todo".to_string(),
+            assignee: assignee.map(str::to_string),
+            priority: Some(2),
+            priority_label: Some("High".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_format_from_name() {
+        assert_eq!(Format::from_name("yaml"), Some(Format::Yaml));
+        assert_eq!(Format::from_name("CSV"), Some(Format::Csv));
+        assert_eq!(Format::from_name("ndjson"), Some(Format::Ndjson));
+        assert_eq!(Format::from_name("ids"), Some(Format::Ids));
+        assert_eq!(Format::from_name("xml"), None);
+        assert_eq!(
+            "template={{identifier}}".parse::<Format>(),
+            Ok(Format::Template("{{identifier}}".to_string()))
+        );
+        assert!("template={{identifier | nope}}".parse::<Format>().is_err());
+        assert!("template=@/nonexistent/template.txt"
+            .parse::<Format>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        let formatter = DelimitedFormatter::csv(DEFAULT_ISSUE_COLUMNS.to_vec());
+        let issues = vec![
+            issue("ENG-1", "Fix login, again", Some("Alice")),
+            issue("ENG-2", "Say \"hi\"", None),
+        ];
+
+        let output = formatter.format_issues(&issues).unwrap();
+
+        assert_eq!(
+            output,
+            "Issue,Title,Status,Assignee\n\
+             ENG-1,\"Fix login, again\",Todo,Alice\n\
+             ENG-2,\"Say \"\"hi\"\"\",Todo,"
+        );
+    }
+
+    #[test]
+    fn test_tsv_uses_selected_columns() {
+        let formatter =
+            DelimitedFormatter::tsv(vec![IssueColumn::Identifier, IssueColumn::Priority]);
+
+        let output = formatter
+            .format_issues(&[issue("ENG-1", "Title", None)])
+            .unwrap();
+
+        assert_eq!(output, "Issue\tPriority\nENG-1\tHigh");
+    }
+
+    #[test]
+    fn test_csv_my_work_has_category_column() {
+        let formatter = DelimitedFormatter::csv(vec![IssueColumn::Identifier]);
+        let my_work = MyWork {
+            assigned_issues: vec![issue("ENG-1", "Mine", None)],
+            created_issues: vec![issue("ENG-2", "Filed", None)],
+        };
+
+        let output = formatter.format_my_work(&my_work).unwrap();
+
+        assert_eq!(output, "Category,Issue\nassigned,ENG-1\ncreated,ENG-2");
+    }
+
+    #[test]
+    fn test_yaml_issues() {
+        let output = YamlFormatter
+            .format_issues(&[issue("ENG-1", "Title", Some("Alice"))])
+            .unwrap();
+
+        let parsed: serde_yaml::Value = serde_yaml::from_str(&output).unwrap();
+        assert_eq!(parsed[0]["identifier"], "ENG-1");
+        assert_eq!(parsed[0]["assignee"], "Alice");
+        assert_eq!(parsed[0]["priorityLabel"], "High");
+    }
+
+    #[test]
+    fn test_ndjson_one_object_per_line() {
+        let my_work = MyWork {
+            assigned_issues: vec![issue("ENG-1", "Mine", None)],
+            created_issues: vec![issue("ENG-2", "Filed", None)],
+        };
+
+        let output = NdjsonFormatter.format_my_work(&my_work).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["identifier"], "ENG-1");
+        assert_eq!(lines[0]["category"], "assigned");
+        assert_eq!(lines[1]["category"], "created");
+    }
+
+    #[test]
+    fn test_cycle_lines_list_its_issues() {
+        let cycle = CycleDetail {
+            cycle: Cycle {
+                id: "cycle-42".to_string(),
+                number: 42,
+                name: None,
+                starts_at: "2026-10-05T00:00:00.000Z".to_string(),
+                ends_at: "2026-10-19T00:00:00.000Z".to_string(),
+                completed_at: None,
+                is_active: true,
+                progress: 0.0,
+                scope: 2.0,
+                started_scope: 0.0,
+                completed_scope: 0.0,
+                issue_count: 1,
+                completed_issue_count: 0,
+            },
+            team: linear_sdk::IssueTeam {
+                id: "team-1".to_string(),
+                key: "ENG".to_string(),
+                name: "Engineering".to_string(),
+            },
+            issues: vec![linear_sdk::CycleIssue {
+                id: "issue-1".to_string(),
+                identifier: "ENG-1".to_string(),
+                title: "Plan, then ship".to_string(),
+                estimate: Some(2.0),
+                state: linear_sdk::IssueState {
+                    id: "state-1".to_string(),
+                    name: "Todo".to_string(),
+                    type_: "unstarted".to_string(),
+                },
+                assignee: None,
+            }],
+        };
+
+        assert_eq!(IdsFormatter.format_cycle(&cycle).unwrap(), "ENG-1");
+        assert_eq!(
+            DelimitedFormatter::csv(Vec::new())
+                .format_cycle(&cycle)
+                .unwrap(),
+            "Issue,Title,State,Estimate,Assignee\nENG-1,\"Plan, then ship\",Todo,2,"
+        );
+        assert_eq!(
+            IdsFormatter.format_cycles(&[cycle.cycle.clone()]).unwrap(),
+            "cycle-42"
+        );
+    }
+
+    #[test]
+    fn test_ids_one_identifier_per_line() {
+        let issues = vec![issue("ENG-1", "One", None), issue("ENG-2", "Two", None)];
+
+        assert_eq!(IdsFormatter.format_issues(&issues).unwrap(), "ENG-1\nENG-2");
+        assert_eq!(IdsFormatter.format_issues(&[]).unwrap(), "");
+    }
+}
@@ -1,7 +1,8 @@
-// ABOUTME: Frontmatter parsing for markdown files used in issue creation
-// ABOUTME: Handles YAML frontmatter extraction and validation for Linear CLI
+// ABOUTME: Frontmatter parsing and rendering for markdown files used to create and edit issues
+// ABOUTME: Handles YAML frontmatter extraction, validation, and field-level diffs for Linear CLI
 
 use anyhow::{anyhow, Result};
+use linear_sdk::DetailedIssue;
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -12,19 +13,44 @@ pub struct IssueFrontmatter {
     /// Issue title (required)
     pub title: String,
     /// Team key or UUID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub team: Option<String>,
+    /// Workflow state name (e.g., "In Progress")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
     /// Assignee (can be "me", user ID, or email)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee: Option<String>,
     /// Priority level (1-4: Urgent, High, Normal, Low)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<i64>,
     /// Estimate points
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub estimate: Option<i64>,
     /// Labels for the issue
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub labels: Option<Vec<String>>,
     /// Cycle (use "current" for active cycle, or cycle number/name)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cycle: Option<String>,
     /// Project name or ID
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// Milestone name within the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<String>,
+    /// Parent issue identifier (e.g., ENG-100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// Due date (e.g., 2026-11-01, +3d, friday)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// Linear's ID for the issue, written by `linear sync`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// When the issue last changed in Linear as of the last sync
+    #[serde(rename = "updatedAt", skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
 }
 
 /// Represents a parsed markdown file with frontmatter and content
@@ -123,6 +149,101 @@ pub fn parse_markdown_content(content: &str) -> Result<MarkdownFile> {
     })
 }
 
+impl MarkdownFile {
+    /// An existing issue in the same shape `parse_markdown_content` reads
+    pub fn from_issue(issue: &DetailedIssue) -> Self {
+        let labels: Vec<String> = issue.labels.iter().map(|l| l.name.clone()).collect();
+        Self {
+            frontmatter: IssueFrontmatter {
+                title: issue.title.clone(),
+                team: issue.team.as_ref().map(|team| team.key.clone()),
+                state: Some(issue.state.name.clone()),
+                assignee: issue.assignee.as_ref().map(|a| a.email.clone()),
+                priority: issue.priority.filter(|p| *p > 0),
+                estimate: issue.estimate.map(|e| e.round() as i64),
+                labels: Some(labels).filter(|labels| !labels.is_empty()),
+                cycle: issue.cycle.as_ref().map(|c| c.number.to_string()),
+                project: issue.project.as_ref().map(|p| p.name.clone()),
+                milestone: issue.milestone.clone(),
+                parent: issue.parent.as_ref().map(|p| p.identifier.clone()),
+                due: issue.due_date.clone(),
+                id: None,
+                updated_at: None,
+            },
+            content: issue.description.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Render as `---`-delimited YAML frontmatter followed by the markdown body
+    pub fn to_markdown(&self) -> Result<String> {
+        let yaml = serde_yaml::to_string(&self.frontmatter)
+            .map_err(|e| anyhow!("Failed to serialize frontmatter: {e}"))?;
+        let content = self.content.trim_end();
+        if content.is_empty() {
+            Ok(format!("---\n{yaml}---\n"))
+        } else {
+            Ok(format!("---\n{yaml}---\n\n{content}\n"))
+        }
+    }
+}
+
+/// A frontmatter field or the description that differs between two versions of a file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// Frontmatter key, or "description" for the markdown body
+    pub field: &'static str,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Fields that differ between `original` and `edited`, in frontmatter order
+///
+/// The sync bookkeeping fields `id` and `updatedAt` are not compared.
+pub fn diff_markdown(original: &MarkdownFile, edited: &MarkdownFile) -> Vec<FieldChange> {
+    let (a, b) = (&original.frontmatter, &edited.frontmatter);
+    let labels = |labels: &Option<Vec<String>>| {
+        labels
+            .as_ref()
+            .filter(|labels| !labels.is_empty())
+            .map(|labels| labels.join(", "))
+    };
+    let description = |content: &str| Some(content.trim().to_string()).filter(|c| !c.is_empty());
+
+    let fields = [
+        ("title", Some(a.title.clone()), Some(b.title.clone())),
+        ("team", a.team.clone(), b.team.clone()),
+        ("state", a.state.clone(), b.state.clone()),
+        ("assignee", a.assignee.clone(), b.assignee.clone()),
+        (
+            "priority",
+            a.priority.map(|p| p.to_string()),
+            b.priority.map(|p| p.to_string()),
+        ),
+        (
+            "estimate",
+            a.estimate.map(|e| e.to_string()),
+            b.estimate.map(|e| e.to_string()),
+        ),
+        ("labels", labels(&a.labels), labels(&b.labels)),
+        ("cycle", a.cycle.clone(), b.cycle.clone()),
+        ("project", a.project.clone(), b.project.clone()),
+        ("milestone", a.milestone.clone(), b.milestone.clone()),
+        ("parent", a.parent.clone(), b.parent.clone()),
+        ("due", a.due.clone(), b.due.clone()),
+        (
+            "description",
+            description(&original.content),
+            description(&edited.content),
+        ),
+    ];
+
+    fields
+        .into_iter()
+        .filter(|(_, old, new)| old != new)
+        .map(|(field, old, new)| FieldChange { field, old, new })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -167,6 +288,8 @@ labels:
   - high-priority
   - authentication
 project: "Web App Stability"
+parent: ENG-100
+due: 2026-11-01
 ---
 
 Found a race condition in authentication.
@@ -190,6 +313,8 @@ Found a race condition in authentication.
             result.frontmatter.project,
             Some("Web App Stability".to_string())
         );
+        assert_eq!(result.frontmatter.parent, Some("ENG-100".to_string()));
+        assert_eq!(result.frontmatter.due, Some("2026-11-01".to_string()));
         assert_eq!(
             result.content,
             "Found a race condition in authentication.\n"
@@ -213,6 +338,7 @@ Just a title.
         assert_eq!(result.frontmatter.priority, None);
         assert_eq!(result.frontmatter.labels, None);
         assert_eq!(result.frontmatter.project, None);
+        assert_eq!(result.frontmatter.parent, None);
         assert_eq!(result.content, "Just a title.\n");
     }
 
@@ -444,12 +570,18 @@ This issue was loaded from a file.
         let frontmatter = IssueFrontmatter {
             title: "Test Serialization".to_string(),
             team: Some("ENG".to_string()),
+            state: Some("In Progress".to_string()),
             assignee: Some("me".to_string()),
             priority: Some(2),
             estimate: None,
             labels: Some(vec!["test".to_string(), "serialization".to_string()]),
             cycle: None,
             project: Some("Test Project".to_string()),
+            milestone: Some("Beta".to_string()),
+            parent: Some("ENG-100".to_string()),
+            due: Some("2026-11-01".to_string()),
+            id: Some("issue-1".to_string()),
+            updated_at: Some("2026-10-02T00:00:00Z".to_string()),
         };
 
         // Test that we can serialize and deserialize
@@ -465,12 +597,18 @@ This issue was loaded from a file.
             frontmatter: IssueFrontmatter {
                 title: "Test".to_string(),
                 team: Some("ENG".to_string()),
+                state: None,
                 assignee: None,
                 priority: None,
                 estimate: None,
                 labels: None,
                 cycle: None,
                 project: None,
+                milestone: None,
+                parent: None,
+                due: None,
+                id: None,
+                updated_at: None,
             },
             content: "Test content".to_string(),
         };
@@ -479,12 +617,18 @@ This issue was loaded from a file.
             frontmatter: IssueFrontmatter {
                 title: "Test".to_string(),
                 team: Some("ENG".to_string()),
+                state: None,
                 assignee: None,
                 priority: None,
                 estimate: None,
                 labels: None,
                 cycle: None,
                 project: None,
+                milestone: None,
+                parent: None,
+                due: None,
+                id: None,
+                updated_at: None,
             },
             content: "Test content".to_string(),
         };
@@ -554,4 +698,91 @@ if !self.sessions.contains_key(&user_id) {
         assert!(result.content.contains("session_manager.rs:45"));
         assert!(result.content.contains("- [ ] Multiple simultaneous"));
     }
+
+    fn detailed_issue() -> DetailedIssue {
+        use linear_sdk::{IssueAssignee, IssueCycle, IssueLabel, IssueState, IssueTeam};
+
+        DetailedIssue {
+            id: "issue-1".to_string(),
+            identifier: "ENG-123".to_string(),
+            title: "Fix login".to_string(),
+            description: Some("Steps:\n\n1. Log in".to_string()),
+            state: IssueState {
+                id: "state-1".to_string(),
+                name: "In Progress".to_string(),
+                type_: "started".to_string(),
+            },
+            assignee: Some(IssueAssignee {
+                name: "Ada".to_string(),
+                email: "ada@example.com".to_string(),
+            }),
+            team: Some(IssueTeam {
+                id: "team-1".to_string(),
+                key: "ENG".to_string(),
+                name: "Engineering".to_string(),
+            }),
+            project: None,
+            milestone: None,
+            labels: vec![IssueLabel {
+                name: "bug".to_string(),
+                color: "#f00".to_string(),
+            }],
+            priority: Some(0),
+            priority_label: Some("No priority".to_string()),
+            estimate: Some(3.0),
+            cycle: Some(IssueCycle {
+                number: 12,
+                name: None,
+            }),
+            due_date: Some("2026-11-01".to_string()),
+            created_at: "2026-10-01T00:00:00Z".to_string(),
+            updated_at: "2026-10-02T00:00:00Z".to_string(),
+            url: "https://linear.app/acme/issue/ENG-123".to_string(),
+            parent: None,
+            children: vec![],
+        }
+    }
+
+    #[test]
+    fn test_issue_markdown_round_trips() {
+        let file = MarkdownFile::from_issue(&detailed_issue());
+        let markdown = file.to_markdown().unwrap();
+
+        assert!(markdown.starts_with("---\ntitle: Fix login\nteam: ENG\nstate: In Progress\n"));
+        assert!(!markdown.contains("priority"));
+        assert!(!markdown.contains("null"));
+        assert!(markdown.ends_with("---\n\nSteps:\n\n1. Log in\n"));
+
+        let parsed = parse_markdown_content(&markdown).unwrap();
+        assert_eq!(parsed.frontmatter, file.frontmatter);
+        assert!(diff_markdown(&file, &parsed).is_empty());
+    }
+
+    #[test]
+    fn test_diff_markdown_reports_changed_fields() {
+        let original = MarkdownFile::from_issue(&detailed_issue());
+        let mut edited = original.clone();
+        edited.frontmatter.state = Some("Done".to_string());
+        edited.frontmatter.priority = Some(2);
+        edited.frontmatter.labels = Some(vec![]);
+        edited.frontmatter.milestone = Some("Beta".to_string());
+        edited.frontmatter.due = None;
+        edited.content = "Steps:\n\n1. Log in\n".to_string();
+
+        let changes = diff_markdown(&original, &edited);
+        let summary: Vec<_> = changes
+            .iter()
+            .map(|c| (c.field, c.old.as_deref(), c.new.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("state", Some("In Progress"), Some("Done")),
+                ("priority", None, Some("2")),
+                ("labels", Some("bug"), None),
+                ("milestone", None, Some("Beta")),
+                ("due", Some("2026-11-01"), None),
+            ]
+        );
+    }
 }
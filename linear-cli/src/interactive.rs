@@ -1,10 +1,11 @@
 // ABOUTME: Interactive prompts for collecting missing command-line arguments
-// ABOUTME: Provides user-friendly terminal-based input for the create command
+// ABOUTME: Provides user-friendly terminal-based input for the create command and issue picker
 
-use crate::preferences::{ContextDefaults, PreferencesManager};
+use crate::constants::picker::MY_WORK_LIMIT;
+use crate::preferences::{ContextDefaults, PreferencesManager, RecentIssue};
 use crate::templates::TemplateManager;
-use dialoguer::{Confirm, Editor, Input, Select};
-use linear_sdk::{LinearClient, LinearError, Result as SdkResult, Team, User};
+use dialoguer::{Confirm, Editor, FuzzySelect, Input, Select};
+use linear_sdk::{LinearClient, LinearError, MyWork, Result as SdkResult, Team, User};
 use std::collections::HashMap;
 use std::io::IsTerminal;
 use std::sync::{Arc, RwLock};
@@ -856,7 +857,7 @@ impl<'a> InteractivePrompter<'a> {
     }
 
     /// Resolve assignee (user) input to user ID
-    async fn resolve_assignee(&self, assignee: &str) -> SdkResult<Option<String>> {
+    pub async fn resolve_assignee(&self, assignee: &str) -> SdkResult<Option<String>> {
         // Handle special cases
         if assignee.eq_ignore_ascii_case("me") || assignee.eq_ignore_ascii_case("self") {
             let viewer_data = self.client.execute_viewer_query().await?;
@@ -983,6 +984,46 @@ impl<'a> InteractivePrompter<'a> {
         })
     }
 
+    /// Let the user fuzzy-search recently viewed issues and their open work for an issue
+    ///
+    /// Returns the chosen identifier, or `None` if the picker was dismissed.
+    pub async fn pick_issue(&self) -> SdkResult<Option<String>> {
+        if !self.should_prompt() {
+            return Err(LinearError::InvalidInput {
+                message: "Interactive prompts not available in non-TTY environment".to_string(),
+            });
+        }
+
+        let my_work = self.client.get_my_work(MY_WORK_LIMIT).await?;
+        let recent = self
+            .preferences_manager
+            .load_preferences()
+            .map(|preferences| preferences.recent_issues)
+            .unwrap_or_default();
+
+        let choices = issue_choices(&recent, &my_work);
+        if choices.is_empty() {
+            return Err(LinearError::InvalidInput {
+                message: "No recent or assigned issues to pick from".to_string(),
+            });
+        }
+
+        let labels: Vec<String> = choices
+            .iter()
+            .map(|choice| format!("{}  {}", choice.identifier, choice.title))
+            .collect();
+        let selection = FuzzySelect::new()
+            .with_prompt("Issue")
+            .items(&labels)
+            .default(0)
+            .interact_opt()
+            .map_err(|e| LinearError::InvalidInput {
+                message: format!("Failed to select issue: {e}"),
+            })?;
+
+        Ok(selection.map(|index| choices[index].identifier.clone()))
+    }
+
     /// Suggest similar project names for improved UX
     pub fn suggest_similar_projects(
         &self,
@@ -1006,6 +1047,29 @@ impl<'a> InteractivePrompter<'a> {
     }
 }
 
+/// Recently viewed issues first, then assigned and created work, without duplicates
+fn issue_choices(recent: &[RecentIssue], my_work: &MyWork) -> Vec<RecentIssue> {
+    let mut choices: Vec<RecentIssue> = Vec::new();
+    let work = my_work
+        .assigned_issues
+        .iter()
+        .chain(&my_work.created_issues)
+        .map(|issue| RecentIssue {
+            identifier: issue.identifier.clone(),
+            title: issue.title.clone(),
+        });
+
+    for choice in recent.iter().cloned().chain(work) {
+        if !choices
+            .iter()
+            .any(|seen| seen.identifier == choice.identifier)
+        {
+            choices.push(choice);
+        }
+    }
+    choices
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1066,7 +1130,11 @@ mod tests {
                             "updatedAt": "2023-05-01T00:00:00Z",
                             "lead": null
                         }
-                    ]
+                    ],
+                    "pageInfo": {
+                        "hasNextPage": false,
+                        "endCursor": "proj-999"
+                    }
                 }
             }
         })
@@ -1076,7 +1144,11 @@ mod tests {
         json!({
             "data": {
                 "projects": {
-                    "nodes": []
+                    "nodes": [],
+                    "pageInfo": {
+                        "hasNextPage": false,
+                        "endCursor": null
+                    }
                 }
             }
         })
@@ -1110,6 +1182,7 @@ mod tests {
                 name: "John Doe".to_string(),
                 display_name: "John Doe".to_string(),
             }),
+            target_date: None,
         }
     }
 
@@ -1332,4 +1405,27 @@ mod tests {
             .unwrap();
         assert!(suggestions.is_empty() || suggestions.len() <= 3); // Should limit suggestions
     }
+
+    #[test]
+    fn test_issue_choices_puts_recent_first_without_duplicates() {
+        let issue = |identifier: &str, title: &str| linear_sdk::Issue {
+            identifier: identifier.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        };
+        let recent = vec![RecentIssue {
+            identifier: "ENG-2".to_string(),
+            title: "Viewed".to_string(),
+        }];
+        let my_work = MyWork {
+            assigned_issues: vec![issue("ENG-1", "Assigned"), issue("ENG-2", "Viewed")],
+            created_issues: vec![issue("ENG-3", "Created"), issue("ENG-1", "Assigned")],
+        };
+
+        let identifiers: Vec<_> = issue_choices(&recent, &my_work)
+            .into_iter()
+            .map(|choice| choice.identifier)
+            .collect();
+        assert_eq!(identifiers, ["ENG-2", "ENG-1", "ENG-3"]);
+    }
 }
@@ -2,17 +2,26 @@
 // ABOUTME: Makes internal modules available to integration tests and benchmarks
 
 pub mod aliases;
+pub mod api;
+pub mod bulk;
 pub mod cli;
 pub mod cli_output;
+pub mod columns;
 pub mod completions;
 pub mod config;
 pub mod constants;
+pub mod dates;
+pub mod formats;
 pub mod frontmatter;
 pub mod interactive;
 pub mod output;
+pub mod output_template;
 pub mod preferences;
+pub mod report;
 pub mod search;
+pub mod sync;
 pub mod templates;
+pub mod tui;
 pub mod types;
 
 // Re-export CLI types for testing
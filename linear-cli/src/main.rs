@@ -2,35 +2,57 @@
 // ABOUTME: Provides command-line interface for Linear issue tracking
 
 use clap::Parser;
+use futures::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
+use linear_sdk::constants::pagination::MAX_PAGE_SIZE;
 use linear_sdk::constants::status::{DEFAULT_DONE_STATE, DEFAULT_TODO_STATE};
-use linear_sdk::{IssueFilters, LinearClient, LinearError, Result};
+use linear_sdk::graphql::QueryBuilder;
+use linear_sdk::{IssueFilters, LinearClient, LinearError, PageLimit, Result};
 use owo_colors::OwoColorize;
 use secrecy::SecretString;
+use std::collections::HashMap;
 use std::env;
 use std::io::IsTerminal;
+use std::path::Path;
 
 mod aliases;
+mod api;
+mod bulk;
 mod cli;
 mod cli_output;
+mod columns;
 mod completions;
 mod config;
 mod constants;
+mod dates;
+mod formats;
 mod frontmatter;
 mod interactive;
 mod output;
+mod output_template;
 mod preferences;
+mod report;
 mod search;
+mod sync;
 mod templates;
 #[cfg(test)]
 mod tests;
+mod tui;
 mod types;
 
 use crate::aliases::AliasExpander;
-use crate::cli::{Cli, Commands};
+use crate::api::ApiField;
+use crate::bulk::BulkReport;
+use crate::cli::{
+    Cli, Commands, CycleCommand, HealthArg, IssueFilterArgs, MilestoneCommand, ProjectCommand,
+    ReportCommand, SyncCommand,
+};
 use crate::cli_output::CliOutput;
+use crate::columns::{sort_issues, ColumnSpec, IssueColumn, SortKey, DEFAULT_ISSUE_COLUMNS};
 use crate::config::Config;
+use crate::formats::{create_formatter, Format, FormatterOptions};
 use crate::output::{JsonFormatter, OutputFormat, TableFormatter};
+use crate::preferences::PreferencesManager;
 
 fn determine_use_color(no_color_flag: bool, force_color_flag: bool, is_tty: bool) -> bool {
     if force_color_flag {
@@ -77,7 +99,15 @@ impl Drop for SpinnerGuard {
 
 fn display_error(error: &LinearError, use_color: bool) {
     let cli = CliOutput::with_color(use_color);
-    cli.error(&error.to_string());
+    match error {
+        LinearError::GraphQL { errors, .. } if errors.len() > 1 => {
+            cli.error("The Linear API returned several errors:");
+            for graphql_error in errors {
+                eprintln!("  - {}", graphql_error.user_message());
+            }
+        }
+        _ => cli.error(&error.to_string()),
+    }
 
     let help = error.help_text();
     if !help.is_empty() {
@@ -86,6 +116,15 @@ fn display_error(error: &LinearError, use_color: bool) {
     }
 }
 
+/// Translate `--limit`/`--all` into the SDK's pagination limit
+fn page_limit(limit: i32, all: bool) -> PageLimit {
+    if all {
+        PageLimit::All
+    } else {
+        PageLimit::from(limit)
+    }
+}
+
 /// Prompt the user for yes/no confirmation. Returns true if user confirms.
 /// Skips prompt and returns true if `force` is set or not interactive.
 fn confirm_action(action: &str, force: bool, is_interactive: bool) -> bool {
@@ -96,13 +135,26 @@ fn confirm_action(action: &str, force: bool, is_interactive: bool) -> bool {
     print!("Continue with {action}? [y/N]: ");
     std::io::Write::flush(&mut std::io::stdout()).unwrap();
 
-    let mut response = String::new();
-    std::io::stdin().read_line(&mut response).unwrap();
-    let response = response.trim().to_lowercase();
+    let response = match bulk::read_confirmation() {
+        Ok(response) => response.trim().to_lowercase(),
+        Err(message) => {
+            println!();
+            CliOutput::new().error(&message);
+            std::process::exit(1);
+        }
+    };
 
     response == "y" || response == "yes"
 }
 
+/// How `linear issues` presents its results
+struct IssueListOutput {
+    format: Format,
+    pretty: bool,
+    sort: Vec<SortKey>,
+    columns: Vec<IssueColumn>,
+}
+
 struct CreateCommandArgs {
     title: Option<String>,
     description: Option<String>,
@@ -114,6 +166,9 @@ struct CreateCommandArgs {
     cycle: Option<String>,
     project: Option<String>,
     project_id: Option<String>,
+    milestone: Option<String>,
+    parent: Option<String>,
+    due: Option<String>,
     from_file: Option<String>,
     open: bool,
     dry_run: bool,
@@ -205,6 +260,9 @@ struct DryRunPreview<'a> {
     labels: &'a [String],
     cycle: Option<&'a str>,
     project: Option<&'a str>,
+    milestone: Option<&'a str>,
+    parent: Option<&'a str>,
+    due: Option<&'a str>,
 }
 
 fn print_dry_run_preview(cli_output: &CliOutput, preview: &DryRunPreview) {
@@ -234,6 +292,15 @@ fn print_dry_run_preview(cli_output: &CliOutput, preview: &DryRunPreview) {
     if let Some(project) = preview.project {
         println!("  Project: {project}");
     }
+    if let Some(milestone) = preview.milestone {
+        println!("  Milestone: {milestone}");
+    }
+    if let Some(parent) = preview.parent {
+        println!("  Parent: {parent}");
+    }
+    if let Some(due) = preview.due {
+        println!("  Due: {due}");
+    }
 }
 
 /// Display a successfully created issue
@@ -291,6 +358,55 @@ async fn resolve_project_to_id(
     }
 }
 
+/// Resolve a parent issue identifier to its ID.
+/// "none" resolves to an empty string, which detaches the issue from its parent on update.
+async fn resolve_parent_to_id(
+    client: &LinearClient,
+    cli_output: &CliOutput,
+    parent: Option<&str>,
+) -> Option<String> {
+    let parent = parent?.trim();
+    if parent.is_empty() || parent.eq_ignore_ascii_case("none") {
+        return Some(String::new());
+    }
+
+    match client.resolve_issue_id(parent).await {
+        Ok(id) => Some(id),
+        Err(e) => {
+            cli_output.error(&format!("Failed to resolve parent issue '{parent}': {e}"));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Resolve a milestone name to its ID within the given project.
+/// "none" resolves to an empty string, which removes the issue from its milestone on update.
+async fn resolve_milestone_to_id(
+    client: &LinearClient,
+    cli_output: &CliOutput,
+    project_id: Option<&str>,
+    milestone: Option<&str>,
+) -> Option<String> {
+    let milestone = milestone?.trim();
+    if milestone.is_empty() || milestone.eq_ignore_ascii_case("none") {
+        return Some(String::new());
+    }
+    let Some(project_id) = project_id else {
+        cli_output.error(&format!(
+            "Milestone '{milestone}' needs a project (use --project or --project-id)"
+        ));
+        std::process::exit(1);
+    };
+
+    match client.resolve_milestone_to_id(project_id, milestone).await {
+        Ok(id) => Some(id),
+        Err(e) => {
+            cli_output.error(&format!("Failed to resolve milestone '{milestone}': {e}"));
+            std::process::exit(1);
+        }
+    }
+}
+
 fn main() -> Result<()> {
     env_logger::init();
 
@@ -456,6 +572,33 @@ async fn handle_create_from_file(
         .as_ref()
         .or(markdown_file.frontmatter.cycle.as_ref())
         .cloned();
+    let parent = args
+        .parent
+        .as_ref()
+        .or(markdown_file.frontmatter.parent.as_ref())
+        .cloned();
+    // --project-id stands in for a project name, so it also overrides the frontmatter
+    let project = args.project.as_ref().or(markdown_file
+        .frontmatter
+        .project
+        .as_ref()
+        .filter(|_| args.project_id.is_none()));
+    let milestone = args
+        .milestone
+        .as_ref()
+        .or(markdown_file.frontmatter.milestone.as_ref());
+    let due = match (&args.due, &markdown_file.frontmatter.due) {
+        (Some(due), _) => Some(due.clone()),
+        (None, Some(due)) => match crate::dates::parse_due_date(due) {
+            Ok(due) => Some(due),
+            Err(e) => {
+                cli_output.error(&e);
+                std::process::exit(1);
+            }
+        },
+        (None, None) => None,
+    }
+    .filter(|due| !due.is_empty());
 
     // Validate required fields
     if title.trim().is_empty() {
@@ -491,7 +634,10 @@ async fn handle_create_from_file(
                 input: &input,
                 labels: &labels,
                 cycle: cycle.as_deref(),
-                project: markdown_file.frontmatter.project.as_deref(),
+                project: project.or(args.project_id.as_ref()).map(String::as_str),
+                milestone: milestone.map(String::as_str),
+                parent: parent.as_deref(),
+                due: due.as_deref(),
             },
         );
         return Ok(());
@@ -501,10 +647,18 @@ async fn handle_create_from_file(
     let project_id = resolve_project_to_id(
         client,
         &cli_output,
-        args.project.as_deref(),
+        project.map(String::as_str),
         args.project_id.clone(),
     )
     .await?;
+    let project_milestone_id = resolve_milestone_to_id(
+        client,
+        &cli_output,
+        project_id.as_deref(),
+        milestone.map(String::as_str),
+    )
+    .await
+    .filter(|id| !id.is_empty());
 
     // Resolve labels if provided
     let label_ids = if !labels.is_empty() {
@@ -520,17 +674,31 @@ async fn handle_create_from_file(
         None
     };
 
+    let parent_id = resolve_parent_to_id(client, &cli_output, parent.as_deref())
+        .await
+        .filter(|id| !id.is_empty());
+
+    // Resolve the workflow state if the file names one
+    let state_id = match &markdown_file.frontmatter.state {
+        Some(state) => Some(client.resolve_status_to_state_id(&team_id, state).await?),
+        None => None,
+    };
+
     // Build the SDK create input
     let sdk_input = linear_sdk::CreateIssueInput {
         title: input.title,
         description: input.description,
         team_id: Some(input.team_id),
+        state_id,
         assignee_id: input.assignee_id,
         priority: input.priority,
         project_id,
+        project_milestone_id,
         label_ids,
         estimate: input.estimate,
         cycle_id,
+        parent_id,
+        due_date: due,
     };
 
     // Create the issue
@@ -641,7 +809,10 @@ async fn handle_create_command(
                 input: &input,
                 labels: &args.labels,
                 cycle: args.cycle.as_deref(),
-                project: None,
+                project: args.project.as_deref().or(args.project_id.as_deref()),
+                milestone: args.milestone.as_deref(),
+                parent: args.parent.as_deref(),
+                due: args.due.as_deref().filter(|due| !due.is_empty()),
             },
         );
         return Ok(());
@@ -655,6 +826,14 @@ async fn handle_create_command(
         args.project_id.clone(),
     )
     .await?;
+    let project_milestone_id = resolve_milestone_to_id(
+        client,
+        &cli_output,
+        project_id.as_deref(),
+        args.milestone.as_deref(),
+    )
+    .await
+    .filter(|id| !id.is_empty());
 
     // Resolve labels if provided
     let label_ids = if !args.labels.is_empty() {
@@ -678,17 +857,25 @@ async fn handle_create_command(
         None
     };
 
+    let parent_id = resolve_parent_to_id(client, &cli_output, args.parent.as_deref())
+        .await
+        .filter(|id| !id.is_empty());
+
     // Build the SDK create input
     let sdk_input = linear_sdk::CreateIssueInput {
         title: input.title,
         description: input.description,
         team_id: Some(input.team_id),
+        state_id: None,
         assignee_id: input.assignee_id,
         priority: input.priority,
         project_id,
+        project_milestone_id,
         label_ids,
         estimate: input.estimate,
         cycle_id,
+        parent_id,
+        due_date: args.due.filter(|due| !due.is_empty()),
     };
 
     // Create the issue
@@ -708,8 +895,224 @@ async fn handle_create_command(
     Ok(())
 }
 
+/// Ask the user to pick an issue from their recent and assigned work
+///
+/// Exits when there is no terminal to prompt on or the picker is dismissed.
+async fn pick_issue(client: &LinearClient, use_color: bool) -> String {
+    use crate::interactive::InteractivePrompter;
+
+    let cli_output = CliOutput::with_color(use_color);
+    let prompter = match InteractivePrompter::new(client) {
+        Ok(prompter) => prompter,
+        Err(e) => {
+            cli_output.error(&format!("Failed to initialize interactive prompter: {e}"));
+            std::process::exit(1);
+        }
+    };
+
+    if !prompter.should_prompt() {
+        cli_output.error("No issue ID given");
+        eprintln!("Pass an issue ID, - to read IDs from stdin, or --where FILTER");
+        std::process::exit(1);
+    }
+
+    match prompter.pick_issue().await {
+        Ok(Some(identifier)) => identifier,
+        Ok(None) => std::process::exit(1),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Expand `-` and `--where` into the identifiers a bulk command acts on, or pick one issue
+/// interactively when neither IDs nor a filter were given
+///
+/// Reports bad input and exits; an empty result means nothing matched, which is not an error.
+async fn resolve_targets(
+    client: &LinearClient,
+    ids: Vec<String>,
+    filter: Option<IssueFilters>,
+    use_color: bool,
+    is_interactive: bool,
+) -> Vec<String> {
+    let cli_output = CliOutput::with_color(use_color);
+    if ids.is_empty() && filter.is_none() {
+        return vec![pick_issue(client, use_color).await];
+    }
+
+    let mut targets = match bulk::expand_ids(ids) {
+        Ok(targets) => targets,
+        Err(message) => {
+            cli_output.error(&message);
+            std::process::exit(1);
+        }
+    };
+
+    if let Some(filter) = filter {
+        let spinner = SpinnerGuard::new("Finding matching issues...", is_interactive);
+        match client
+            .list_issues_filtered(PageLimit::All, Some(filter))
+            .await
+        {
+            Ok(issues) => {
+                drop(spinner);
+                for issue in issues {
+                    if !targets.contains(&issue.identifier) {
+                        targets.push(issue.identifier);
+                    }
+                }
+            }
+            Err(e) => {
+                drop(spinner);
+                display_error(&e, use_color);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if targets.is_empty() {
+        cli_output.info("No matching issues");
+    }
+    targets
+}
+
+/// "issue ENG-1" or "3 issues: ENG-1, ENG-2, ENG-3" for previews
+fn describe_targets(targets: &[String]) -> String {
+    match targets {
+        [id] => format!("issue {id}"),
+        _ => format!("{} issues: {}", targets.len(), targets.join(", ")),
+    }
+}
+
+/// Status, labels, and cycle are named per team, so they resolve against each issue's team
+#[derive(Default)]
+struct TeamScopedFields {
+    status: Option<String>,
+    labels: Vec<String>,
+    cycle: Option<String>,
+}
+
+impl TeamScopedFields {
+    fn is_empty(&self) -> bool {
+        self.status.is_none() && self.labels.is_empty() && self.cycle.is_none()
+    }
+
+    /// Fill in the state, label, and cycle IDs that `team_id` uses for these names
+    async fn resolve(
+        &self,
+        client: &LinearClient,
+        team_id: &str,
+        input: &mut linear_sdk::UpdateIssueInput,
+    ) -> Result<()> {
+        if let Some(ref status_name) = self.status {
+            input.state_id = Some(
+                client
+                    .resolve_status_to_state_id(team_id, status_name)
+                    .await?,
+            );
+        }
+        if !self.labels.is_empty() {
+            input.label_ids = Some(
+                client
+                    .resolve_label_names_to_ids(team_id, &self.labels)
+                    .await?,
+            );
+        }
+        if let Some(ref cycle) = self.cycle {
+            input.cycle_id = Some(client.resolve_cycle_to_id(team_id, cycle).await?);
+        }
+        Ok(())
+    }
+}
+
+/// Apply `input` to several issues concurrently, resolving team-scoped fields once per team
+async fn bulk_update_issues(
+    client: &LinearClient,
+    ids: Vec<String>,
+    input: linear_sdk::UpdateIssueInput,
+    team_fields: &TeamScopedFields,
+    wording: (&str, &str, &str),
+    use_color: bool,
+    is_interactive: bool,
+) {
+    let (verb, done, progress) = wording;
+    let mut report = BulkReport::default();
+
+    let mut inputs = HashMap::new();
+    if team_fields.is_empty() {
+        inputs.extend(ids.iter().map(|id| (id.clone(), input.clone())));
+    } else {
+        let teams = bulk::run(
+            ids.clone(),
+            "Looking up issues",
+            is_interactive,
+            |id| async move {
+                let issue = client.get_issue(id).await?;
+                Ok(issue.team.map(|team| team.id))
+            },
+        )
+        .await;
+
+        let mut team_inputs: HashMap<String, std::result::Result<_, String>> = HashMap::new();
+        for (id, team) in teams {
+            let team_id = match team {
+                Ok(Some(team_id)) => team_id,
+                Ok(None) => {
+                    let message = format!("Issue {id} has no team");
+                    report.record::<()>(id, Err(LinearError::InvalidInput { message }));
+                    continue;
+                }
+                Err(e) => {
+                    report.record::<()>(id, Err(e));
+                    continue;
+                }
+            };
+
+            if !team_inputs.contains_key(&team_id) {
+                let mut team_input = input.clone();
+                let resolved = team_fields
+                    .resolve(client, &team_id, &mut team_input)
+                    .await
+                    .map(|()| team_input)
+                    .map_err(|e| e.to_string());
+                team_inputs.insert(team_id.clone(), resolved);
+            }
+            match &team_inputs[&team_id] {
+                Ok(team_input) => {
+                    inputs.insert(id, team_input.clone());
+                }
+                Err(message) => {
+                    let message = message.clone();
+                    report.record::<()>(id, Err(LinearError::InvalidInput { message }));
+                }
+            }
+        }
+    }
+
+    let ready = ids
+        .into_iter()
+        .filter(|id| inputs.contains_key(id))
+        .collect();
+    let results = bulk::run(ready, progress, is_interactive, |id| {
+        let input = inputs[&id].clone();
+        async move { client.update_issue(id, input).await }
+    })
+    .await;
+    for (id, result) in results {
+        report.record(id, result);
+    }
+
+    report.print(verb, done, use_color, is_interactive);
+    if !report.failed.is_empty() {
+        std::process::exit(1);
+    }
+}
+
 struct UpdateCommandArgs {
-    id: String,
+    ids: Vec<String>,
+    filter: Option<IssueFilters>,
     title: Option<String>,
     description: Option<String>,
     assignee: Option<String>,
@@ -720,7 +1123,69 @@ struct UpdateCommandArgs {
     cycle: Option<String>,
     project: Option<String>,
     project_id: Option<String>,
+    milestone: Option<String>,
+    parent: Option<String>,
+    due: Option<String>,
     force: bool,
+    dry_run: bool,
+}
+
+/// List the changes `linear update` is about to make
+fn print_update_preview(targets: &[String], args: &UpdateCommandArgs) {
+    println!("Would update {}:", describe_targets(targets));
+    if let Some(ref title) = args.title {
+        println!("  Title: {title}");
+    }
+    if let Some(ref description) = args.description {
+        println!("  Description: {description}");
+    }
+    if let Some(ref assignee) = args.assignee {
+        if assignee.trim().is_empty() || assignee.eq_ignore_ascii_case("unassigned") {
+            println!("  Assignee: Unassigned");
+        } else {
+            println!("  Assignee: {assignee}");
+        }
+    }
+    if let Some(ref status_name) = args.status {
+        println!("  Status: {status_name}");
+    }
+    if let Some(priority) = args.priority {
+        println!("  Priority: {priority}");
+    }
+    if let Some(estimate) = args.estimate {
+        println!("  Estimate: {estimate}");
+    }
+    if !args.labels.is_empty() {
+        println!("  Labels: {}", args.labels.join(", "));
+    }
+    if let Some(ref cycle) = args.cycle {
+        println!("  Cycle: {cycle}");
+    }
+    if let Some(project) = args.project.as_ref().or(args.project_id.as_ref()) {
+        println!("  Project: {project}");
+    }
+    if let Some(ref milestone) = args.milestone {
+        if milestone.trim().is_empty() || milestone.eq_ignore_ascii_case("none") {
+            println!("  Milestone: None");
+        } else {
+            println!("  Milestone: {milestone}");
+        }
+    }
+    if let Some(ref parent) = args.parent {
+        if parent.trim().is_empty() || parent.eq_ignore_ascii_case("none") {
+            println!("  Parent: None");
+        } else {
+            println!("  Parent: {parent}");
+        }
+    }
+    if let Some(ref due_date) = args.due {
+        if due_date.is_empty() {
+            println!("  Due: None");
+        } else {
+            println!("  Due: {due_date}");
+        }
+    }
+    println!();
 }
 
 async fn handle_update_command(
@@ -742,12 +1207,56 @@ async fn handle_update_command(
         && args.cycle.is_none()
         && args.project.is_none()
         && args.project_id.is_none()
+        && args.milestone.is_none()
+        && args.parent.is_none()
+        && args.due.is_none()
     {
         cli_output.error("At least one field must be specified for update");
-        eprintln!("Use --title, --description, --assignee, --status, --priority, --estimate, --label, --cycle, --project, or --project-id");
+        eprintln!("Use --title, --description, --assignee, --status, --priority, --estimate, --label, --cycle, --project, --project-id, --milestone, --parent, or --due");
         std::process::exit(1);
     }
 
+    let targets = resolve_targets(
+        client,
+        args.ids.clone(),
+        args.filter.clone(),
+        use_color,
+        is_interactive,
+    )
+    .await;
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    // A milestone belongs to a single project, so it can't be looked up in each issue's own
+    // project when several issues are updated at once
+    let clears_milestone = args
+        .milestone
+        .as_deref()
+        .is_some_and(|m| m.trim().is_empty() || m.eq_ignore_ascii_case("none"));
+    if let Some(milestone) = &args.milestone {
+        let has_project = args.project.is_some() || args.project_id.is_some();
+        if targets.len() > 1 && !has_project && !clears_milestone {
+            cli_output.error(&format!(
+                "Milestone '{milestone}' needs --project or --project-id when updating several issues"
+            ));
+            std::process::exit(1);
+        }
+    }
+
+    // Show preview unless --force is used; --dry-run stops after it
+    if args.dry_run || (!args.force && is_interactive) {
+        print_update_preview(&targets, &args);
+        if args.dry_run {
+            return Ok(());
+        }
+
+        if !confirm_action("update", false, true) {
+            cli_output.info("Update cancelled");
+            return Ok(());
+        }
+    }
+
     // Resolve assignee if provided (empty string for update means "unassign")
     let assignee_id = match &args.assignee {
         Some(assignee)
@@ -765,42 +1274,6 @@ async fn handle_update_command(
         None => None,
     };
 
-    // Fetch issue to get team_id if any field needs it
-    let needs_team = args.status.is_some() || !args.labels.is_empty() || args.cycle.is_some();
-    let issue = if needs_team {
-        Some(client.get_issue(args.id.clone()).await?)
-    } else {
-        None
-    };
-    let team_id = issue
-        .as_ref()
-        .and_then(|i| i.team.as_ref())
-        .map(|t| t.id.clone());
-
-    // Resolve status to state_id if provided
-    let state_id = if let Some(ref status_name) = args.status {
-        let tid = team_id.as_ref().unwrap();
-        Some(client.resolve_status_to_state_id(tid, status_name).await?)
-    } else {
-        None
-    };
-
-    // Resolve labels if provided
-    let label_ids = if !args.labels.is_empty() {
-        let tid = team_id.as_ref().unwrap();
-        Some(client.resolve_label_names_to_ids(tid, &args.labels).await?)
-    } else {
-        None
-    };
-
-    // Resolve cycle if provided
-    let cycle_id = if let Some(ref cycle_str) = args.cycle {
-        let tid = team_id.as_ref().unwrap();
-        Some(client.resolve_cycle_to_id(tid, cycle_str).await?)
-    } else {
-        None
-    };
-
     // Resolve project if provided
     let project_id = resolve_project_to_id(
         client,
@@ -810,61 +1283,72 @@ async fn handle_update_command(
     )
     .await?;
 
-    let input = linear_sdk::UpdateIssueInput {
+    // A milestone is looked up in the new project, or else the single issue's current one
+    let milestone_project_id = match (&project_id, &args.milestone) {
+        (Some(project_id), _) => Some(project_id.clone()),
+        (None, Some(_)) if !clears_milestone => match client.get_issue(targets[0].clone()).await {
+            Ok(issue) => issue.project.map(|project| project.id),
+            Err(e) => {
+                display_error(&e, use_color);
+                std::process::exit(1);
+            }
+        },
+        (None, _) => None,
+    };
+    let project_milestone_id = resolve_milestone_to_id(
+        client,
+        &cli_output,
+        milestone_project_id.as_deref(),
+        args.milestone.as_deref(),
+    )
+    .await;
+
+    let parent_id = resolve_parent_to_id(client, &cli_output, args.parent.as_deref()).await;
+
+    let mut input = linear_sdk::UpdateIssueInput {
         title: args.title,
         description: args.description,
         assignee_id,
-        state_id,
+        state_id: None,
         priority: args.priority,
         project_id,
-        label_ids,
+        project_milestone_id,
+        label_ids: None,
         estimate: args.estimate,
-        cycle_id,
+        cycle_id: None,
+        parent_id,
+        due_date: args.due,
+    };
+    let team_fields = TeamScopedFields {
+        status: args.status,
+        labels: args.labels,
+        cycle: args.cycle,
     };
 
-    // Show preview unless --force is used
-    if !args.force && is_interactive {
-        println!("Would update issue {}:", args.id);
-        if let Some(ref title) = input.title {
-            println!("  Title: {title}");
-        }
-        if let Some(ref description) = input.description {
-            println!("  Description: {description}");
-        }
-        if let Some(ref assignee_id) = input.assignee_id {
-            if assignee_id.is_empty() {
-                println!("  Assignee: Unassigned");
-            } else {
-                println!("  Assignee: {assignee_id}");
-            }
-        }
-        if let Some(ref _state_id) = input.state_id {
-            if let Some(ref status_name) = args.status {
-                println!("  Status: {status_name}");
-            }
-        }
-        if let Some(priority) = input.priority {
-            println!("  Priority: {priority}");
-        }
-        if let Some(estimate) = input.estimate {
-            println!("  Estimate: {estimate}");
-        }
-        if let Some(ref label_ids) = input.label_ids {
-            println!("  Labels: {} label(s)", label_ids.len());
-        }
-        if let Some(ref cycle_id) = input.cycle_id {
-            println!("  Cycle: {cycle_id}");
-        }
-        println!();
+    let [id] = targets.as_slice() else {
+        let wording = ("update", "Updated", "Updating");
+        bulk_update_issues(
+            client,
+            targets,
+            input,
+            &team_fields,
+            wording,
+            use_color,
+            is_interactive,
+        )
+        .await;
+        return Ok(());
+    };
 
-        if !confirm_action("update", false, true) {
-            cli_output.info("Update cancelled");
-            return Ok(());
-        }
+    // Fetch issue to get team_id if any field needs it
+    if !team_fields.is_empty() {
+        let issue = client.get_issue(id.clone()).await?;
+        let team_id = issue.team.as_ref().unwrap().id.clone();
+        team_fields.resolve(client, &team_id, &mut input).await?;
     }
 
     let spinner = SpinnerGuard::new("Updating issue...", is_interactive);
-    match client.update_issue(args.id.clone(), input).await {
+    match client.update_issue(id.clone(), input).await {
         Ok(updated_issue) => {
             drop(spinner);
 
@@ -878,6 +1362,9 @@ async fn handle_update_command(
                 if let Some(assignee) = &updated_issue.assignee {
                     println!("Assignee: {}", assignee.name);
                 }
+                if let Some(due_date) = &updated_issue.due_date {
+                    println!("Due: {due_date}");
+                }
                 if let Some(team) = &updated_issue.team {
                     println!("Team: {} ({})", team.name, team.key);
                 }
@@ -897,179 +1384,227 @@ async fn handle_update_command(
     Ok(())
 }
 
-async fn handle_status_change(
-    client: &LinearClient,
-    id: String,
-    action: &str,
-    status_name: &str,
-    force: bool,
-    use_color: bool,
-    is_interactive: bool,
-) -> Result<()> {
-    let cli_output = CliOutput::with_color(use_color);
-
-    // Show preview unless --force is used
-    if !force && is_interactive {
-        println!("Would {action} issue: {id}");
-        println!();
+/// Open `text` in $EDITOR until it parses as issue markdown
+///
+/// Returns `None` if the editor is closed without saving or the user gives up after an error.
+fn edit_issue_markdown(
+    cli_output: &CliOutput,
+    mut text: String,
+) -> Option<crate::frontmatter::MarkdownFile> {
+    loop {
+        let edited = match dialoguer::Editor::new()
+            .extension(".md")
+            .require_save(true)
+            .edit(&text)
+        {
+            Ok(Some(edited)) => edited,
+            Ok(None) => return None,
+            Err(e) => {
+                cli_output.error(&format!("Failed to open editor: {e}"));
+                std::process::exit(1);
+            }
+        };
 
-        if !confirm_action(action, false, true) {
-            let cancel_msg = if action == "close" {
-                "Close cancelled"
-            } else {
-                "Reopen cancelled"
-            };
-            cli_output.info(cancel_msg);
-            return Ok(());
+        match crate::frontmatter::parse_markdown_content(&edited) {
+            Ok(file) => return Some(file),
+            Err(e) => {
+                cli_output.error(&format!("{e}"));
+                if !confirm_action("editing", false, true) {
+                    return None;
+                }
+                text = edited;
+            }
         }
     }
+}
 
-    // Get the issue first to determine its team, then resolve the target state ID
-    let issue = client.get_issue(id.clone()).await?;
-    let team_id = issue.team.as_ref().unwrap().id.clone();
-
-    let target_state_id = client
-        .resolve_status_to_state_id(&team_id, status_name)
-        .await?;
-
-    let input = linear_sdk::UpdateIssueInput {
-        title: None,
-        description: None,
-        assignee_id: None,
-        state_id: Some(target_state_id),
-        priority: None,
-        project_id: None,
-        label_ids: None,
-        estimate: None,
-        cycle_id: None,
-    };
-
-    let (success_msg, spinner_msg) = if action == "close" {
-        ("Closed", "Closing issue...")
-    } else {
-        ("Reopened", "Reopening issue...")
-    };
-
-    let spinner = SpinnerGuard::new(spinner_msg, is_interactive);
-    match client.update_issue(id.clone(), input).await {
-        Ok(updated_issue) => {
-            drop(spinner);
+/// Print one line per changed field, e.g. "  state: Todo → In Progress"
+fn print_field_changes(changes: &[crate::frontmatter::FieldChange], use_color: bool) {
+    for change in changes {
+        let old = change.old.as_deref().unwrap_or("(none)");
+        let new = change.new.as_deref().unwrap_or("(none)");
+        if change.field == "description" {
+            let lines = |text: &str| text.lines().count();
+            let summary = format!("{} → {} lines", lines(old), lines(new));
+            println!("  description: {summary}");
+        } else if use_color {
+            println!("  {}: {} → {}", change.field, old.red(), new.green());
+        } else {
+            println!("  {}: {old} → {new}", change.field);
+        }
+    }
+}
 
-            if is_interactive {
-                cli_output.success(&format!(
-                    "{success_msg} issue: {}",
-                    updated_issue.identifier
-                ));
-                println!("Status: {}", updated_issue.state.name);
-                println!("URL: {}", updated_issue.url);
-            } else {
-                println!("{}", updated_issue.identifier);
+/// Turn edited frontmatter fields into an update for an issue on `team_id`
+///
+/// Fields the API can't clear are reported and left alone.
+async fn edit_changes_to_input(
+    client: &LinearClient,
+    cli_output: &CliOutput,
+    changes: &[crate::frontmatter::FieldChange],
+    edited: &crate::frontmatter::MarkdownFile,
+    team_id: &str,
+) -> Result<linear_sdk::UpdateIssueInput> {
+    use crate::interactive::InteractivePrompter;
+
+    let mut input = linear_sdk::UpdateIssueInput::default();
+    let mut team_fields = TeamScopedFields::default();
+    let frontmatter = &edited.frontmatter;
+
+    for change in changes {
+        let value = change.new.clone();
+        match (change.field, value) {
+            ("title", title) => input.title = title,
+            ("description", _) => input.description = Some(edited.content.trim().to_string()),
+            ("state", Some(state)) => team_fields.status = Some(state),
+            ("assignee", Some(assignee)) => {
+                let prompter = InteractivePrompter::new(client)?;
+                input.assignee_id = Some(
+                    prompter
+                        .resolve_assignee(&assignee)
+                        .await?
+                        .unwrap_or_default(),
+                );
             }
-        }
-        Err(e) => {
-            drop(spinner);
-            display_error(&e, use_color);
-            std::process::exit(1);
+            ("assignee", None) => input.assignee_id = Some(String::new()),
+            ("priority", _) => input.priority = Some(frontmatter.priority.unwrap_or(0)),
+            ("estimate", Some(_)) => input.estimate = frontmatter.estimate,
+            ("labels", Some(_)) => {
+                team_fields.labels = frontmatter.labels.clone().unwrap_or_default()
+            }
+            ("labels", None) => input.label_ids = Some(Vec::new()),
+            ("cycle", Some(cycle)) => team_fields.cycle = Some(cycle),
+            ("project", Some(project)) => {
+                input.project_id =
+                    resolve_project_to_id(client, cli_output, Some(&project), None).await?;
+            }
+            ("milestone", Some(milestone)) => {
+                // "project" sorts first, so a project changed in the same edit is already resolved
+                let project_id = match &input.project_id {
+                    Some(project_id) => Some(project_id.clone()),
+                    None => {
+                        resolve_project_to_id(
+                            client,
+                            cli_output,
+                            frontmatter.project.as_deref(),
+                            None,
+                        )
+                        .await?
+                    }
+                };
+                input.project_milestone_id = resolve_milestone_to_id(
+                    client,
+                    cli_output,
+                    project_id.as_deref(),
+                    Some(&milestone),
+                )
+                .await;
+            }
+            ("milestone", None) => input.project_milestone_id = Some(String::new()),
+            ("parent", parent) => {
+                let parent = parent.unwrap_or_default();
+                input.parent_id = resolve_parent_to_id(client, cli_output, Some(&parent)).await;
+            }
+            ("due", Some(due)) => {
+                input.due_date = Some(
+                    crate::dates::parse_due_date(&due)
+                        .map_err(|message| LinearError::InvalidInput { message })?,
+                );
+            }
+            ("due", None) => input.due_date = Some(String::new()),
+            ("team", _) => cli_output
+                .warning("Moving an issue to another team isn't supported; team left unchanged"),
+            (field, _) => cli_output.warning(&format!(
+                "Clearing {field} isn't supported; {field} left unchanged"
+            )),
         }
     }
 
-    Ok(())
+    team_fields.resolve(client, team_id, &mut input).await?;
+    Ok(input)
 }
 
-async fn handle_close_command(
+async fn handle_edit_command(
     client: &LinearClient,
-    id: String,
+    id: Option<String>,
     force: bool,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    handle_status_change(
-        client,
-        id,
-        "close",
-        DEFAULT_DONE_STATE,
-        force,
-        use_color,
-        is_interactive,
-    )
-    .await
-}
+    use crate::frontmatter::{diff_markdown, MarkdownFile};
 
-async fn handle_reopen_command(
-    client: &LinearClient,
-    id: String,
-    force: bool,
-    use_color: bool,
-    is_interactive: bool,
-) -> Result<()> {
-    handle_status_change(
-        client,
-        id,
-        "reopen",
-        DEFAULT_TODO_STATE,
-        force,
-        use_color,
-        is_interactive,
-    )
-    .await
-}
-async fn handle_comment_command(
-    client: &LinearClient,
-    id: String,
-    message: Option<String>,
-    use_color: bool,
-    is_interactive: bool,
-) -> Result<()> {
     let cli_output = CliOutput::with_color(use_color);
+    if !is_interactive || !std::io::stdin().is_terminal() {
+        cli_output.error("linear edit needs an interactive terminal");
+        eprintln!("Use linear update to change issues from scripts");
+        std::process::exit(1);
+    }
 
-    // Get comment body from argument or stdin
-    let body = if let Some(msg) = message {
-        msg
-    } else {
-        // Read from stdin
-        use std::io::IsTerminal as _;
-        if std::io::stdin().is_terminal() {
-            cli_output.error("No message provided. Use --message TEXT or pipe content to stdin.");
-            std::process::exit(1);
+    let id = match id {
+        Some(id) => id,
+        None => pick_issue(client, use_color).await,
+    };
+
+    let spinner = SpinnerGuard::new(&format!("Fetching issue {id}..."), is_interactive);
+    let issue = match client.get_issue(id).await {
+        Ok(issue) => {
+            drop(spinner);
+            issue
         }
-        use std::io::Read;
-        let mut buffer = String::new();
-        if let Err(e) = std::io::stdin().read_to_string(&mut buffer) {
-            cli_output.error(&format!("Failed to read from stdin: {e}"));
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
             std::process::exit(1);
         }
+    };
 
-        if buffer.trim().is_empty() {
-            cli_output.error("Comment body cannot be empty");
-            cli_output.error("Provide a message argument or pipe content to stdin");
+    let original = MarkdownFile::from_issue(&issue);
+    let text = match original.to_markdown() {
+        Ok(text) => text,
+        Err(e) => {
+            cli_output.error(&format!("{e}"));
             std::process::exit(1);
         }
-
-        buffer.trim().to_string()
+    };
+    let Some(edited) = edit_issue_markdown(&cli_output, text) else {
+        cli_output.info("Edit cancelled");
+        return Ok(());
     };
 
-    let input = linear_sdk::CreateCommentInput { body, issue_id: id };
+    let changes = diff_markdown(&original, &edited);
+    if changes.is_empty() {
+        cli_output.info(&format!("No changes to {}", issue.identifier));
+        return Ok(());
+    }
 
-    let spinner = SpinnerGuard::new("Adding comment...", is_interactive);
-    match client.create_comment(input).await {
-        Ok(created_comment) => {
-            drop(spinner);
+    println!("Changes to {}:", issue.identifier);
+    print_field_changes(&changes, use_color);
+    println!();
+    if !confirm_action("update", force, is_interactive) {
+        cli_output.info("Update cancelled");
+        return Ok(());
+    }
 
-            if is_interactive {
-                cli_output.success(&format!(
-                    "Added comment to issue: {}",
-                    created_comment.issue.identifier
-                ));
-                println!("Comment: {}", created_comment.body);
-                println!("Author: {}", created_comment.user.name);
-                println!(
-                    "Issue: {} - {}",
-                    created_comment.issue.identifier, created_comment.issue.title
-                );
-            } else {
-                // Non-interactive: just print the comment ID for scripting
-                println!("{}", created_comment.id);
-            }
+    let team_id = issue
+        .team
+        .as_ref()
+        .map(|team| team.id.clone())
+        .unwrap_or_default();
+    let input = match edit_changes_to_input(client, &cli_output, &changes, &edited, &team_id).await
+    {
+        Ok(input) => input,
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    let spinner = SpinnerGuard::new("Updating issue...", is_interactive);
+    match client.update_issue(issue.id.clone(), input).await {
+        Ok(updated_issue) => {
+            drop(spinner);
+            cli_output.success(&format!("Updated issue: {}", updated_issue.identifier));
+            println!("URL: {}", updated_issue.url);
         }
         Err(e) => {
             drop(spinner);
@@ -1081,124 +1616,608 @@ async fn handle_comment_command(
     Ok(())
 }
 
-async fn handle_attach_command(
+/// Build a create input from an issue file, resolving names against `team`
+async fn create_input_from_markdown(
     client: &LinearClient,
-    id: String,
-    url: String,
-    title: Option<String>,
-    use_color: bool,
-    is_interactive: bool,
-) -> Result<()> {
-    let cli_output = CliOutput::with_color(use_color);
+    file: &crate::frontmatter::MarkdownFile,
+    team: &str,
+) -> Result<linear_sdk::CreateIssueInput> {
+    use crate::interactive::InteractivePrompter;
 
-    // Resolve issue identifier to UUID
-    let issue = client.get_issue(id.clone()).await?;
+    let frontmatter = &file.frontmatter;
+    let team_id = resolve_team_to_id(client, team).await?;
+    let prompter = InteractivePrompter::new(client)?;
 
-    let input = linear_sdk::CreateAttachmentInput {
-        issue_id: issue.id.clone(),
-        url: url.clone(),
-        title,
+    let assignee_id = match &frontmatter.assignee {
+        Some(assignee) => prompter.resolve_assignee(assignee).await?,
+        None => None,
     };
-
-    let spinner = SpinnerGuard::new("Attaching URL...", is_interactive);
-    match client.create_attachment(input).await {
-        Ok(attachment) => {
-            drop(spinner);
-
-            if is_interactive {
-                cli_output.success(&format!("Attached URL to issue {}", issue.identifier));
-                println!("URL: {}", attachment.url);
-                println!("Title: {}", attachment.title);
-            } else {
-                println!("{}", attachment.id);
-            }
+    let state_id = match &frontmatter.state {
+        Some(state) => Some(client.resolve_status_to_state_id(&team_id, state).await?),
+        None => None,
+    };
+    let label_ids = match frontmatter.labels.as_deref() {
+        Some(labels) if !labels.is_empty() => {
+            Some(client.resolve_label_names_to_ids(&team_id, labels).await?)
         }
-        Err(e) => {
-            drop(spinner);
-            display_error(&e, use_color);
-            std::process::exit(1);
+        _ => None,
+    };
+    let cycle_id = match &frontmatter.cycle {
+        Some(cycle) => Some(client.resolve_cycle_to_id(&team_id, cycle).await?),
+        None => None,
+    };
+    let project_id = match &frontmatter.project {
+        Some(project) => prompter.resolve_project(project).await?,
+        None => None,
+    };
+    let project_milestone_id = match (&frontmatter.milestone, &project_id) {
+        (Some(milestone), Some(project_id)) => Some(
+            client
+                .resolve_milestone_to_id(project_id, milestone)
+                .await?,
+        ),
+        (Some(milestone), None) => {
+            return Err(LinearError::InvalidInput {
+                message: format!("Milestone '{milestone}' needs a project in the frontmatter"),
+            });
         }
-    }
+        (None, _) => None,
+    };
+    let parent_id = match &frontmatter.parent {
+        Some(parent) => Some(client.resolve_issue_id(parent).await?),
+        None => None,
+    };
+    let due_date = match &frontmatter.due {
+        Some(due) => Some(
+            crate::dates::parse_due_date(due)
+                .map_err(|message| LinearError::InvalidInput { message })?,
+        ),
+        None => None,
+    };
 
-    Ok(())
+    let description = file.content.trim();
+    Ok(linear_sdk::CreateIssueInput {
+        title: frontmatter.title.clone(),
+        description: Some(description.to_string()).filter(|d| !d.is_empty()),
+        team_id: Some(team_id),
+        state_id,
+        assignee_id,
+        priority: frontmatter.priority,
+        label_ids,
+        project_id,
+        project_milestone_id,
+        estimate: frontmatter.estimate,
+        cycle_id,
+        parent_id,
+        due_date: due_date.filter(|date| !date.is_empty()),
+    })
 }
 
-async fn handle_relate_command(
+/// Exit with an error if `result` failed
+fn sync_or_exit<T>(result: anyhow::Result<T>, cli_output: &CliOutput) -> T {
+    result.unwrap_or_else(|e| {
+        cli_output.error(&e.to_string());
+        std::process::exit(1);
+    })
+}
+
+async fn handle_sync_pull(
     client: &LinearClient,
-    id: String,
-    kind: String,
-    targets: Vec<String>,
+    dir: &Path,
+    filters: IssueFilters,
+    force: bool,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    use linear_sdk::{CreateIssueRelationInput, IssueRelationKind};
+    use crate::sync::{file_name_for, read_issue_files, write_issue_file, SyncState};
 
     let cli_output = CliOutput::with_color(use_color);
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        cli_output.error(&format!("Failed to create '{}': {e}", dir.display()));
+        std::process::exit(1);
+    }
+    let mut state = sync_or_exit(SyncState::load(dir), &cli_output);
+    let local = sync_or_exit(read_issue_files(dir), &cli_output);
 
-    // Parse the relation kind. `blocked-by` and `duplicate-of` swap the issue pair.
-    let (relation_kind, swap) = match kind.as_str() {
-        "blocks" => (IssueRelationKind::Blocks, false),
-        "blocked-by" => (IssueRelationKind::Blocks, true),
-        "related" => (IssueRelationKind::Related, false),
-        "duplicate" => (IssueRelationKind::Duplicate, false),
-        "duplicate-of" => (IssueRelationKind::Duplicate, true),
-        "similar" => (IssueRelationKind::Similar, false),
-        _ => unreachable!("clap restricts the set of allowed values"),
+    let spinner = SpinnerGuard::new("Finding matching issues...", is_interactive);
+    let issues = match client
+        .list_issues_filtered(PageLimit::All, Some(filters))
+        .await
+    {
+        Ok(issues) => {
+            drop(spinner);
+            issues
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
     };
 
-    let source = client.get_issue(id.clone()).await?;
+    let ids = issues.into_iter().map(|issue| issue.identifier).collect();
+    let results = bulk::run(ids, "Pulling issues", is_interactive, |id| {
+        client.get_issue(id)
+    })
+    .await;
 
-    let mut had_error = false;
-    for target_ref in targets {
-        let target = match client.get_issue(target_ref.clone()).await {
-            Ok(t) => t,
+    let mut report = BulkReport::default();
+    let mut unchanged = 0;
+    let mut kept = Vec::new();
+    for (identifier, result) in results {
+        let issue = match result {
+            Ok(issue) => issue,
             Err(e) => {
-                had_error = true;
-                if is_interactive {
-                    cli_output.error(&format!("Could not resolve {target_ref}: {e}"));
-                } else {
-                    eprintln!("{target_ref}: {e}");
-                }
+                report.record::<()>(identifier, Err(e));
                 continue;
             }
         };
 
-        let (issue_id, related_issue_id) = if swap {
-            (target.id.clone(), source.id.clone())
-        } else {
-            (source.id.clone(), target.id.clone())
+        let file = synced_issue_file(&issue);
+
+        // Reuse the file that already holds this issue, whatever it's called
+        let name = local
+            .iter()
+            .find(|existing| existing.issue_id() == Some(issue.id.as_str()))
+            .map(|existing| existing.name.clone())
+            .unwrap_or_else(|| file_name_for(&issue.identifier));
+        let existing = local.iter().find(|existing| existing.name == name);
+
+        let text = sync_or_exit(file.to_markdown(), &cli_output);
+        if existing.is_some_and(|existing| existing.text == text) {
+            state.record(&name, &text);
+            unchanged += 1;
+            continue;
+        }
+        if let Some(existing) = existing {
+            if !force && state.is_modified(&name, &existing.text) {
+                kept.push(name);
+                continue;
+            }
+        }
+
+        let result = write_issue_file(&dir.join(&name), &file)
+            .map_err(|e| LinearError::Configuration(e.to_string()));
+        if result.is_ok() {
+            state.record(&name, &text);
+        }
+        report.record(issue.identifier, result);
+    }
+    sync_or_exit(state.save(dir), &cli_output);
+
+    report.print("pull", "Pulled", use_color, is_interactive);
+    if unchanged > 0 && is_interactive {
+        let noun = if unchanged == 1 { "issue" } else { "issues" };
+        cli_output.info(&format!("{unchanged} {noun} already up to date"));
+    }
+    if !kept.is_empty() {
+        cli_output.warning(&format!(
+            "Kept local changes in {}; push them, or pull with --force to overwrite them",
+            kept.join(", ")
+        ));
+    }
+    if !report.failed.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// The file a pull writes for `issue`: its fields plus the ID and updatedAt that sync tracks
+fn synced_issue_file(issue: &linear_sdk::DetailedIssue) -> crate::frontmatter::MarkdownFile {
+    let mut file = crate::frontmatter::MarkdownFile::from_issue(issue);
+    file.frontmatter.id = Some(issue.id.clone());
+    file.frontmatter.updated_at = Some(issue.updated_at.clone());
+    file
+}
+
+/// Re-read a pushed issue so its file keeps the resolved values (`due: friday` becomes a
+/// date, `assignee: me` an email) rather than resolving them again on the next push
+async fn refresh_pushed_file(
+    client: &LinearClient,
+    id: &str,
+    mut pushed: crate::frontmatter::MarkdownFile,
+    updated_at: String,
+) -> crate::frontmatter::MarkdownFile {
+    match client.get_issue(id.to_string()).await {
+        Ok(issue) => synced_issue_file(&issue),
+        Err(_) => {
+            pushed.frontmatter.updated_at = Some(updated_at);
+            pushed
+        }
+    }
+}
+
+/// What `sync push` did to the files in a directory
+#[derive(Default)]
+struct SyncPushOutcome {
+    report: BulkReport,
+    /// File name, issue identifier, and Linear's updatedAt for files that changed on both sides
+    conflicts: Vec<(String, String, String)>,
+}
+
+/// Create an issue for every new file and push the edits in every changed one
+async fn push_issue_files(
+    client: &LinearClient,
+    dir: &Path,
+    team: Option<String>,
+    dry_run: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> anyhow::Result<SyncPushOutcome> {
+    use crate::frontmatter::{diff_markdown, MarkdownFile};
+    use crate::sync::{file_name_for, read_issue_files, write_issue_file, SyncState};
+
+    let cli_output = CliOutput::with_color(use_color);
+    let mut state = SyncState::load(dir)?;
+    let files = read_issue_files(dir)?;
+
+    let mut outcome = SyncPushOutcome::default();
+    let report = &mut outcome.report;
+    let write_error = |e: anyhow::Error| LinearError::Configuration(e.to_string());
+
+    for local in files {
+        let mut file = match local.parsed {
+            Ok(file) => file,
+            Err(message) => {
+                report.record::<()>(local.name, Err(LinearError::InvalidInput { message }));
+                continue;
+            }
+        };
+
+        let Some(id) = file.frontmatter.id.clone() else {
+            // A new file: create its issue, then record the ID so the next push updates it
+            let Some(team) = file.frontmatter.team.clone().or_else(|| team.clone()) else {
+                let message = "No team in the frontmatter; pass --team".to_string();
+                report.record::<()>(local.name, Err(LinearError::InvalidInput { message }));
+                continue;
+            };
+            if dry_run {
+                println!(
+                    "Would create '{}' from {}",
+                    file.frontmatter.title, local.name
+                );
+                continue;
+            }
+
+            let spinner = SpinnerGuard::new(&format!("Creating {}...", local.name), is_interactive);
+            let created = match create_input_from_markdown(client, &file, &team).await {
+                Ok(input) => client.create_issue(input).await,
+                Err(e) => Err(e),
+            };
+            drop(spinner);
+            let created = match created {
+                Ok(created) => created,
+                Err(e) => {
+                    report.record::<()>(local.name, Err(e));
+                    continue;
+                }
+            };
+
+            file.frontmatter.id = Some(created.id.clone());
+            file.frontmatter.team = created.team.as_ref().map(|t| t.key.clone()).or(Some(team));
+            let file = refresh_pushed_file(client, &created.id, file, created.updated_at).await;
+
+            // Name the file after its new identifier, as a pull would, unless that name is taken
+            let mut name = local.name.clone();
+            let renamed = file_name_for(&created.identifier);
+            let renamed_path = dir.join(&renamed);
+            if !renamed_path.exists() && std::fs::rename(&local.path, &renamed_path).is_ok() {
+                state.forget(&name);
+                name = renamed;
+            }
+            let result = write_issue_file(&dir.join(&name), &file).map_err(write_error);
+            if let Ok(text) = &result {
+                state.record(&name, text);
+            }
+            report.record(created.identifier, result);
+            continue;
+        };
+
+        if !state.is_modified(&local.name, &local.text) {
+            continue;
+        }
+
+        let spinner = SpinnerGuard::new(&format!("Checking {}...", local.name), is_interactive);
+        let remote = client.get_issue(id).await;
+        drop(spinner);
+        let remote = match remote {
+            Ok(remote) => remote,
+            Err(e) => {
+                report.record::<()>(local.name, Err(e));
+                continue;
+            }
         };
 
+        let changes = diff_markdown(&MarkdownFile::from_issue(&remote), &file);
+        if changes.is_empty() {
+            if file.frontmatter.updated_at.as_deref() == Some(remote.updated_at.as_str()) {
+                state.record(&local.name, &local.text);
+            }
+            continue;
+        }
+        if file.frontmatter.updated_at.as_deref() != Some(remote.updated_at.as_str()) {
+            outcome
+                .conflicts
+                .push((local.name, remote.identifier, remote.updated_at));
+            continue;
+        }
+
+        if dry_run {
+            println!("Would update {} from {}:", remote.identifier, local.name);
+            print_field_changes(&changes, use_color);
+            continue;
+        }
+
         let spinner = SpinnerGuard::new(
-            &format!(
-                "Linking {} {} {}...",
-                source.identifier, kind, target.identifier
-            ),
+            &format!("Updating {}...", remote.identifier),
             is_interactive,
         );
-        let input = CreateIssueRelationInput {
-            issue_id,
-            related_issue_id,
-            kind: relation_kind,
+        let team_id = remote
+            .team
+            .as_ref()
+            .map(|t| t.id.clone())
+            .unwrap_or_default();
+        let updated =
+            match edit_changes_to_input(client, &cli_output, &changes, &file, &team_id).await {
+                Ok(input) => client.update_issue(remote.id.clone(), input).await,
+                Err(e) => Err(e),
+            };
+        drop(spinner);
+        let updated = match updated {
+            Ok(updated) => updated,
+            Err(e) => {
+                report.record::<()>(remote.identifier, Err(e));
+                continue;
+            }
         };
-        match client.create_issue_relation(input).await {
-            Ok(relation) => {
-                drop(spinner);
-                if is_interactive {
-                    cli_output.success(&format!(
-                        "{} {} {}",
-                        relation.issue_identifier,
-                        relation.kind.as_str(),
-                        relation.related_issue_identifier
-                    ));
-                } else {
-                    println!("{}", relation.id);
-                }
+
+        let file = refresh_pushed_file(client, &remote.id, file, updated.updated_at).await;
+        let result = write_issue_file(&local.path, &file).map_err(write_error);
+        if let Ok(text) = &result {
+            state.record(&local.name, text);
+        }
+        report.record(remote.identifier, result);
+    }
+
+    if !dry_run {
+        state.save(dir)?;
+    }
+    Ok(outcome)
+}
+
+async fn handle_sync_push(
+    client: &LinearClient,
+    dir: &Path,
+    team: Option<String>,
+    dry_run: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let SyncPushOutcome { report, conflicts } = sync_or_exit(
+        push_issue_files(client, dir, team, dry_run, use_color, is_interactive).await,
+        &cli_output,
+    );
+
+    if !dry_run || !report.failed.is_empty() {
+        report.print("push", "Pushed", use_color, is_interactive);
+    }
+
+    if !conflicts.is_empty() {
+        cli_output.error(&format!(
+            "{} changed in Linear since they were last synced; nothing was pushed for them:",
+            if conflicts.len() == 1 {
+                "1 issue"
+            } else {
+                "Several issues"
+            }
+        ));
+        for (name, identifier, updated_at) in &conflicts {
+            eprintln!("  {name}: {identifier} was updated at {updated_at}");
+        }
+        eprintln!("Merge the changes from Linear, set updatedAt to the time shown, and push again");
+    }
+    if !report.failed.is_empty() || !conflicts.is_empty() {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Which convenience status change `close` or `reopen` makes
+#[derive(Debug, Clone, Copy)]
+enum StatusChange {
+    Close,
+    Reopen,
+}
+
+impl StatusChange {
+    /// Verb for prompts ("close"), past tense for results ("Closed"), and progress text
+    fn wording(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            StatusChange::Close => ("close", "Closed", "Closing"),
+            StatusChange::Reopen => ("reopen", "Reopened", "Reopening"),
+        }
+    }
+
+    fn status_name(self) -> &'static str {
+        match self {
+            StatusChange::Close => DEFAULT_DONE_STATE,
+            StatusChange::Reopen => DEFAULT_TODO_STATE,
+        }
+    }
+}
+
+/// Issues and flags shared by `close` and `reopen`
+struct StatusChangeArgs {
+    ids: Vec<String>,
+    filter: Option<IssueFilters>,
+    force: bool,
+    dry_run: bool,
+}
+
+async fn handle_status_change(
+    client: &LinearClient,
+    change: StatusChange,
+    args: StatusChangeArgs,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let (action, done, progress) = change.wording();
+
+    let targets = resolve_targets(client, args.ids, args.filter, use_color, is_interactive).await;
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    // Show preview unless --force is used; --dry-run stops after it
+    if args.dry_run || (!args.force && is_interactive) {
+        println!("Would {action} {}", describe_targets(&targets));
+        println!();
+        if args.dry_run {
+            return Ok(());
+        }
+
+        if !confirm_action(action, false, true) {
+            let cancelled = match change {
+                StatusChange::Close => "Close cancelled",
+                StatusChange::Reopen => "Reopen cancelled",
+            };
+            cli_output.info(cancelled);
+            return Ok(());
+        }
+    }
+
+    let team_fields = TeamScopedFields {
+        status: Some(change.status_name().to_string()),
+        ..Default::default()
+    };
+    let mut input = linear_sdk::UpdateIssueInput {
+        title: None,
+        description: None,
+        assignee_id: None,
+        state_id: None,
+        priority: None,
+        project_id: None,
+        project_milestone_id: None,
+        label_ids: None,
+        estimate: None,
+        cycle_id: None,
+        parent_id: None,
+        due_date: None,
+    };
+
+    let [id] = targets.as_slice() else {
+        bulk_update_issues(
+            client,
+            targets,
+            input,
+            &team_fields,
+            change.wording(),
+            use_color,
+            is_interactive,
+        )
+        .await;
+        return Ok(());
+    };
+
+    // Get the issue first to determine its team, then resolve the target state ID
+    let issue = client.get_issue(id.clone()).await?;
+    let team_id = issue.team.as_ref().unwrap().id.clone();
+    team_fields.resolve(client, &team_id, &mut input).await?;
+
+    let spinner = SpinnerGuard::new(&format!("{progress} issue..."), is_interactive);
+    match client.update_issue(id.clone(), input).await {
+        Ok(updated_issue) => {
+            drop(spinner);
+
+            if is_interactive {
+                cli_output.success(&format!("{done} issue: {}", updated_issue.identifier));
+                println!("Status: {}", updated_issue.state.name);
+                println!("URL: {}", updated_issue.url);
+            } else {
+                println!("{}", updated_issue.identifier);
+            }
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Archive, restore, or delete issues
+#[derive(Debug, Clone, Copy)]
+enum IssueRemoval {
+    Archive,
+    Unarchive,
+    Delete { permanent: bool },
+}
+
+impl IssueRemoval {
+    /// Verb for prompts ("archive"), past tense for results ("Archived"), and spinner text
+    fn wording(self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            IssueRemoval::Archive => ("archive", "Archived", "Archiving"),
+            IssueRemoval::Unarchive => ("restore", "Restored", "Restoring"),
+            IssueRemoval::Delete { permanent: false } => ("delete", "Deleted", "Deleting"),
+            IssueRemoval::Delete { permanent: true } => {
+                ("permanently delete", "Permanently deleted", "Deleting")
             }
+        }
+    }
+}
+
+async fn handle_issue_removal(
+    client: &LinearClient,
+    removal: IssueRemoval,
+    ids: Vec<String>,
+    force: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let (verb, done, progress) = removal.wording();
+
+    // Restoring is harmless; archiving and deleting ask first unless --force is used
+    let needs_confirmation = !matches!(removal, IssueRemoval::Unarchive);
+    if needs_confirmation && !force && is_interactive {
+        let noun = if ids.len() == 1 { "issue" } else { "issues" };
+        println!("Would {verb} {} {noun}: {}", ids.len(), ids.join(", "));
+        if matches!(removal, IssueRemoval::Delete { permanent: true }) {
+            cli_output.warning("Permanently deleted issues cannot be restored");
+        }
+        println!();
+
+        if !confirm_action(verb, false, true) {
+            cli_output.info("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let mut had_error = false;
+    for id in ids {
+        let spinner = SpinnerGuard::new(&format!("{progress} {id}..."), is_interactive);
+        let result = match removal {
+            IssueRemoval::Archive => client.archive_issue(&id).await,
+            IssueRemoval::Unarchive => client.unarchive_issue(&id).await,
+            IssueRemoval::Delete { permanent } => client.delete_issue(&id, permanent).await,
+        };
+        drop(spinner);
+
+        match result {
+            Ok(()) if is_interactive => cli_output.success(&format!("{done} issue: {id}")),
+            Ok(()) => println!("{id}"),
             Err(e) => {
-                drop(spinner);
                 had_error = true;
-                display_error(&e, use_color);
+                if is_interactive {
+                    display_error(&e, use_color);
+                } else {
+                    eprintln!("{id}: {e}");
+                }
             }
         }
     }
@@ -1210,251 +2229,1523 @@ async fn handle_relate_command(
     Ok(())
 }
 
-#[allow(clippy::too_many_arguments)]
-async fn handle_issues_command(
+async fn handle_api_command(
     client: &LinearClient,
-    limit: i32,
+    query: Option<String>,
+    fields: Vec<ApiField>,
+    paginate: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let document = match api::read_query(query.as_deref()) {
+        Ok(document) => document,
+        Err(message) => {
+            CliOutput::with_color(use_color).error(&message);
+            std::process::exit(1);
+        }
+    };
+
+    let query = fields
+        .into_iter()
+        .fold(QueryBuilder::new(document), |query, field| {
+            query.variable(field.name, field.value)
+        });
+
+    let spinner = SpinnerGuard::new("Sending request...", is_interactive);
+    let result = if paginate {
+        client.execute_raw_paginated(query).await
+    } else {
+        client.execute_raw(&query).await
+    };
+
+    match result {
+        Ok(data) => {
+            drop(spinner);
+            print_formatted(
+                serde_json::to_string_pretty(&data).map_err(LinearError::from),
+                use_color,
+            );
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// `linear comment ENG-1 "text"` passes the comment as a trailing positional argument; take
+/// it back off the list of issues when no --message was given
+fn split_comment_args(
+    mut ids: Vec<String>,
+    message: Option<String>,
+) -> (Vec<String>, Option<String>) {
+    if message.is_none()
+        && ids.len() > 1
+        && ids
+            .last()
+            .is_some_and(|last| !bulk::looks_like_issue_id(last))
+    {
+        let message = ids.pop();
+        return (ids, message);
+    }
+    (ids, message)
+}
+
+struct CommentCommandArgs {
+    ids: Vec<String>,
+    message: Option<String>,
+    filter: Option<IssueFilters>,
+    force: bool,
+    dry_run: bool,
+}
+
+async fn handle_comment_command(
+    client: &LinearClient,
+    args: CommentCommandArgs,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let (ids, message) = split_comment_args(args.ids, args.message);
+
+    // Get comment body from argument or stdin
+    let body = if let Some(msg) = message {
+        msg
+    } else {
+        if ids.iter().any(|id| id == "-") {
+            cli_output
+                .error("Issue IDs are being read from stdin, so pass the comment with --message");
+            std::process::exit(1);
+        }
+
+        // Read from stdin
+        use std::io::IsTerminal as _;
+        if std::io::stdin().is_terminal() {
+            cli_output.error("No message provided. Use --message TEXT or pipe content to stdin.");
+            std::process::exit(1);
+        }
+        use std::io::Read;
+        let mut buffer = String::new();
+        if let Err(e) = std::io::stdin().read_to_string(&mut buffer) {
+            cli_output.error(&format!("Failed to read from stdin: {e}"));
+            std::process::exit(1);
+        }
+
+        if buffer.trim().is_empty() {
+            cli_output.error("Comment body cannot be empty");
+            cli_output.error("Provide a message argument or pipe content to stdin");
+            std::process::exit(1);
+        }
+
+        buffer.trim().to_string()
+    };
+
+    let targets = resolve_targets(client, ids, args.filter, use_color, is_interactive).await;
+    if targets.is_empty() {
+        return Ok(());
+    }
+
+    // Commenting on several issues asks first unless --force is used; --dry-run only previews
+    let bulk = targets.len() > 1;
+    if args.dry_run || (bulk && !args.force && is_interactive) {
+        println!("Would comment on {}:", describe_targets(&targets));
+        println!("  Comment: {body}");
+        println!();
+        if args.dry_run {
+            return Ok(());
+        }
+
+        if !confirm_action("comment", false, true) {
+            cli_output.info("Comment cancelled");
+            return Ok(());
+        }
+    }
+
+    if bulk {
+        let results = bulk::run(targets, "Commenting", is_interactive, |issue_id| {
+            let input = linear_sdk::CreateCommentInput {
+                body: body.clone(),
+                issue_id,
+            };
+            async move { client.create_comment(input).await }
+        })
+        .await;
+
+        let mut report = BulkReport::default();
+        for (id, result) in results {
+            report.record(id, result);
+        }
+        report.print("comment on", "Commented on", use_color, is_interactive);
+        if !report.failed.is_empty() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let input = linear_sdk::CreateCommentInput {
+        body,
+        issue_id: targets[0].clone(),
+    };
+
+    let spinner = SpinnerGuard::new("Adding comment...", is_interactive);
+    match client.create_comment(input).await {
+        Ok(created_comment) => {
+            drop(spinner);
+
+            if is_interactive {
+                cli_output.success(&format!(
+                    "Added comment to issue: {}",
+                    created_comment.issue.identifier
+                ));
+                println!("Comment: {}", created_comment.body);
+                println!("Author: {}", created_comment.user.name);
+                println!(
+                    "Issue: {} - {}",
+                    created_comment.issue.identifier, created_comment.issue.title
+                );
+            } else {
+                // Non-interactive: just print the comment ID for scripting
+                println!("{}", created_comment.id);
+            }
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_attach_command(
+    client: &LinearClient,
+    id: String,
+    url: String,
+    title: Option<String>,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+
+    // Resolve issue identifier to UUID
+    let issue = client.get_issue(id.clone()).await?;
+
+    let input = linear_sdk::CreateAttachmentInput {
+        issue_id: issue.id.clone(),
+        url: url.clone(),
+        title,
+    };
+
+    let spinner = SpinnerGuard::new("Attaching URL...", is_interactive);
+    match client.create_attachment(input).await {
+        Ok(attachment) => {
+            drop(spinner);
+
+            if is_interactive {
+                cli_output.success(&format!("Attached URL to issue {}", issue.identifier));
+                println!("URL: {}", attachment.url);
+                println!("Title: {}", attachment.title);
+            } else {
+                println!("{}", attachment.id);
+            }
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_relate_command(
+    client: &LinearClient,
+    id: String,
+    kind: String,
+    targets: Vec<String>,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    use linear_sdk::{CreateIssueRelationInput, IssueRelationKind};
+
+    let cli_output = CliOutput::with_color(use_color);
+
+    // Parse the relation kind. `blocked-by` and `duplicate-of` swap the issue pair.
+    let (relation_kind, swap) = match kind.as_str() {
+        "blocks" => (IssueRelationKind::Blocks, false),
+        "blocked-by" => (IssueRelationKind::Blocks, true),
+        "related" => (IssueRelationKind::Related, false),
+        "duplicate" => (IssueRelationKind::Duplicate, false),
+        "duplicate-of" => (IssueRelationKind::Duplicate, true),
+        "similar" => (IssueRelationKind::Similar, false),
+        _ => unreachable!("clap restricts the set of allowed values"),
+    };
+
+    let source = client.get_issue(id.clone()).await?;
+
+    let mut had_error = false;
+    for target_ref in targets {
+        let target = match client.get_issue(target_ref.clone()).await {
+            Ok(t) => t,
+            Err(e) => {
+                had_error = true;
+                if is_interactive {
+                    cli_output.error(&format!("Could not resolve {target_ref}: {e}"));
+                } else {
+                    eprintln!("{target_ref}: {e}");
+                }
+                continue;
+            }
+        };
+
+        let (issue_id, related_issue_id) = if swap {
+            (target.id.clone(), source.id.clone())
+        } else {
+            (source.id.clone(), target.id.clone())
+        };
+
+        let spinner = SpinnerGuard::new(
+            &format!(
+                "Linking {} {} {}...",
+                source.identifier, kind, target.identifier
+            ),
+            is_interactive,
+        );
+        let input = CreateIssueRelationInput {
+            issue_id,
+            related_issue_id,
+            kind: relation_kind,
+        };
+        match client.create_issue_relation(input).await {
+            Ok(relation) => {
+                drop(spinner);
+                if is_interactive {
+                    cli_output.success(&format!(
+                        "{} {} {}",
+                        relation.issue_identifier,
+                        relation.kind.as_str(),
+                        relation.related_issue_identifier
+                    ));
+                } else {
+                    println!("{}", relation.id);
+                }
+            }
+            Err(e) => {
+                drop(spinner);
+                had_error = true;
+                display_error(&e, use_color);
+            }
+        }
+    }
+
+    if had_error {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+async fn handle_issues_command(
+    client: &LinearClient,
+    limit: PageLimit,
+    filters: IssueFilters,
+    output: IssueListOutput,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    // Piped table output for every issue is printed page by page as results arrive,
+    // unless it has to be sorted first
+    if limit == PageLimit::All
+        && output.format == Format::Table
+        && !is_interactive
+        && output.sort.is_empty()
+    {
+        return stream_issues_table(client, filters, output.columns, use_color).await;
+    }
+
+    let spinner = SpinnerGuard::new("Fetching issues...", is_interactive);
+    let issues = match fetch_sorted_issues(client, limit, filters, &output.sort).await {
+        Ok(issues) => {
+            drop(spinner);
+            issues
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    if issues.is_empty() && output.format == Format::Table && is_interactive {
+        println!("No issues found.");
+    } else if !issues.is_empty() {
+        let formatter = formatter_or_exit(
+            output.format,
+            FormatterOptions {
+                pretty: output.pretty,
+                use_color,
+                is_interactive,
+                columns: output.columns,
+            },
+        );
+        print_formatted(formatter.format_issues(&issues), use_color);
+    }
+
+    Ok(())
+}
+
+/// Fetch issues for `linear issues`; a sort fetches every match first so that `limit`
+/// keeps the top of the sorted list rather than sorting an arbitrary page
+async fn fetch_sorted_issues(
+    client: &LinearClient,
+    limit: PageLimit,
+    filters: IssueFilters,
+    sort: &[SortKey],
+) -> Result<Vec<linear_sdk::Issue>> {
+    if sort.is_empty() {
+        return client.list_issues_filtered(limit, Some(filters)).await;
+    }
+
+    let mut issues = client
+        .list_issues_filtered(PageLimit::All, Some(filters))
+        .await?;
+    sort_issues(&mut issues, sort);
+    if let PageLimit::Count(count) = limit {
+        issues.truncate(count);
+    }
+    Ok(issues)
+}
+
+async fn stream_issues_table(
+    client: &LinearClient,
+    filters: IssueFilters,
+    columns: Vec<IssueColumn>,
+    use_color: bool,
+) -> Result<()> {
+    let filter = match client.build_issue_filter(&filters).await {
+        Ok(filter) => filter,
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    let formatter = TableFormatter::new_with_interactive(use_color, false).with_columns(columns);
+    let mut pages = client
+        .issues_stream_with_options(filter, filters.include_archived)
+        .ready_chunks(MAX_PAGE_SIZE);
+    // Column widths come from the first page so later pages line up under its header
+    let mut widths: Option<Vec<usize>> = None;
+
+    while let Some(page) = pages.next().await {
+        let issues = match page.into_iter().collect::<Result<Vec<_>>>() {
+            Ok(issues) => issues,
+            Err(e) => {
+                display_error(&e, use_color);
+                std::process::exit(1);
+            }
+        };
+
+        let output = match &widths {
+            Some(widths) => formatter.format_issues_continuation(&issues, widths),
+            None => {
+                widths = Some(formatter.issue_column_widths(&issues));
+                formatter.format_issues(&issues)
+            }
+        };
+        match output {
+            Ok(output) => println!("{output}"),
+            Err(e) => {
+                display_error(&e, use_color);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_issue_detail_command(
+    client: &LinearClient,
+    id: String,
+    json: bool,
+    raw: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let spinner = SpinnerGuard::new(&format!("Fetching issue {id}..."), is_interactive);
+    match client.get_issue(id).await {
+        Ok(issue) => {
+            drop(spinner);
+            // Remember the issue for the picker; failing to save it shouldn't fail the command
+            if let Ok(preferences) = PreferencesManager::new() {
+                let _ = preferences.record_recent_issue(&issue.identifier, &issue.title);
+            }
+            let output = if json {
+                let formatter = JsonFormatter::new(false);
+                match formatter.format_detailed_issue(&issue) {
+                    Ok(output) => output,
+                    Err(e) => {
+                        display_error(&e, use_color);
+                        std::process::exit(1);
+                    }
+                }
+            } else {
+                let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
+                let use_rich_formatting = is_interactive && !raw;
+
+                match formatter.format_detailed_issue_rich(&issue, use_rich_formatting) {
+                    Ok(output) => output,
+                    Err(e) => {
+                        display_error(&e, use_color);
+                        std::process::exit(1);
+                    }
+                }
+            };
+            println!("{output}");
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_issue_tree_command(
+    client: &LinearClient,
+    id: String,
+    json: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let spinner = SpinnerGuard::new(&format!("Fetching sub-issues of {id}..."), is_interactive);
+    match client.get_issue_tree(id).await {
+        Ok(tree) => {
+            drop(spinner);
+            if json {
+                println!("{}", serde_json::to_string(&tree)?);
+            } else {
+                let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
+                println!("{}", formatter.format_issue_tree(&tree));
+            }
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_status_command(
+    client: &LinearClient,
+    verbose: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let spinner = SpinnerGuard::new("Checking Linear connection...", is_interactive);
+    match client.execute_viewer_query().await {
+        Ok(viewer_data) => {
+            drop(spinner);
+            if is_interactive {
+                if use_color {
+                    println!("{} Connected to Linear", "✓".green());
+                } else {
+                    println!("✓ Connected to Linear");
+                }
+
+                if verbose {
+                    println!();
+                    println!(
+                        "User: {} ({})",
+                        viewer_data.viewer.name, viewer_data.viewer.email
+                    );
+                    println!("User ID: {}", viewer_data.viewer.id);
+
+                    if let Some(budget) = client.rate_limit() {
+                        if let (Some(remaining), Some(limit)) =
+                            (budget.requests_remaining, budget.requests_limit)
+                        {
+                            println!("API requests remaining: {remaining}/{limit}");
+                        }
+                        if let (Some(remaining), Some(limit)) =
+                            (budget.complexity_remaining, budget.complexity_limit)
+                        {
+                            println!("API complexity remaining: {remaining}/{limit}");
+                        }
+                    }
+                }
+            }
+        }
+        Err(e) => {
+            drop(spinner);
+            if is_interactive {
+                if use_color {
+                    println!("{} Failed to connect to Linear", "✗".red());
+                } else {
+                    println!("✗ Failed to connect to Linear");
+                }
+                println!();
+            }
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_projects_command(
+    client: &LinearClient,
+    limit: PageLimit,
+    filters: linear_sdk::ProjectFilters,
+    format: Format,
+    pretty: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let spinner = SpinnerGuard::new("Fetching projects...", is_interactive);
+    let projects = match client.list_projects_filtered(limit, &filters).await {
+        Ok(projects) => {
+            drop(spinner);
+            projects
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    if projects.is_empty() && format == Format::Table && is_interactive {
+        println!("No projects found.");
+    } else if !projects.is_empty() {
+        let formatter = list_formatter(format, pretty, use_color, is_interactive);
+        print_formatted(formatter.format_projects(&projects), use_color);
+    }
+
+    Ok(())
+}
+
+const PROJECT_TEAM_REQUIRED: &str =
+    "Team is required (use --team or set default_team in the config)";
+
+/// Project fields as given on the command line, before names are resolved to IDs
+struct ProjectFieldArgs {
+    name: Option<String>,
+    description: Option<String>,
+    team: Option<String>,
+    lead: Option<String>,
+    target_date: Option<String>,
+    state: Option<String>,
+}
+
+/// Resolve a project name or ID, exiting with an error message on failure.
+async fn resolve_project_or_exit(
+    client: &LinearClient,
+    cli_output: &CliOutput,
+    project: &str,
+) -> String {
+    use crate::interactive::InteractivePrompter;
+
+    let resolved = match InteractivePrompter::new(client) {
+        Ok(prompter) => prompter.resolve_project(project).await,
+        Err(e) => Err(e),
+    };
+    match resolved {
+        Ok(Some(id)) => id,
+        Ok(None) => {
+            cli_output.error(&format!("'{project}' does not name a project"));
+            std::process::exit(1);
+        }
+        Err(e) => {
+            cli_output.error(&format!("Failed to resolve project '{project}': {e}"));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Resolve the lead, team, status, and target date of a project create or update.
+/// A lead or target date of "none" becomes an empty string, which clears it on update.
+async fn project_update_input(
+    client: &LinearClient,
+    args: ProjectFieldArgs,
+) -> Result<linear_sdk::UpdateProjectInput> {
+    use crate::interactive::InteractivePrompter;
+
+    let prompter = InteractivePrompter::new(client)?;
+    let lead_id = match &args.lead {
+        Some(lead) => Some(prompter.resolve_assignee(lead).await?.unwrap_or_default()),
+        None => None,
+    };
+    let team_ids = match &args.team {
+        Some(team) => Some(vec![resolve_team_to_id(client, team).await?]),
+        None => None,
+    };
+    let status_id = match &args.state {
+        Some(state) => Some(client.resolve_project_status_to_id(state).await?),
+        None => None,
+    };
+    let target_date = args
+        .target_date
+        .as_deref()
+        .map(crate::dates::parse_due_date)
+        .transpose()
+        .map_err(|message| LinearError::InvalidInput { message })?;
+
+    Ok(linear_sdk::UpdateProjectInput {
+        name: args.name,
+        description: None,
+        content: args.description,
+        lead_id,
+        team_ids,
+        status_id,
+        target_date,
+    })
+}
+
+/// Display a created or updated project
+fn display_saved_project(
+    cli_output: &CliOutput,
+    verb: &str,
+    project: &linear_sdk::SavedProject,
+    is_interactive: bool,
+) {
+    if is_interactive {
+        cli_output.success(&format!("{verb} project: {}", project.name));
+        println!("URL: {}", project.url);
+    } else {
+        println!("{}", project.id);
+    }
+}
+
+async fn handle_project_show_command(
+    client: &LinearClient,
+    project: &str,
+    json: bool,
+    raw: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    let spinner = SpinnerGuard::new(&format!("Fetching project {project}..."), is_interactive);
+    let project = match client.get_project(&project_id).await {
+        Ok(project) => {
+            drop(spinner);
+            project
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string(&project)?);
+    } else {
+        let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
+        println!(
+            "{}",
+            formatter.format_project_detail(&project, is_interactive && !raw)
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_project_create_command(
+    client: &LinearClient,
+    args: ProjectFieldArgs,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let Some(team) = args.team.clone() else {
+        cli_output.error(PROJECT_TEAM_REQUIRED);
+        std::process::exit(1);
+    };
+    let name = args.name.clone().unwrap_or_default();
+
+    let spinner = SpinnerGuard::new("Creating project...", is_interactive);
+    let created = async {
+        let fields = project_update_input(client, args).await?;
+        let input = linear_sdk::CreateProjectInput {
+            name,
+            team_ids: fields.team_ids.unwrap_or_else(|| vec![team]),
+            description: None,
+            content: fields.content,
+            lead_id: fields.lead_id.filter(|id| !id.is_empty()),
+            status_id: fields.status_id,
+            target_date: fields.target_date.filter(|date| !date.is_empty()),
+        };
+        client.create_project(input).await
+    }
+    .await;
+    drop(spinner);
+
+    match created {
+        Ok(project) => display_saved_project(&cli_output, "Created", &project, is_interactive),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_project_update_command(
+    client: &LinearClient,
+    project: &str,
+    args: ProjectFieldArgs,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    if args.name.is_none()
+        && args.description.is_none()
+        && args.team.is_none()
+        && args.lead.is_none()
+        && args.target_date.is_none()
+        && args.state.is_none()
+    {
+        cli_output.error(
+            "Nothing to update. Pass --name, --description, --lead, --team, --target-date, or --state",
+        );
+        std::process::exit(1);
+    }
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    let spinner = SpinnerGuard::new("Updating project...", is_interactive);
+    let updated = async {
+        let mut input = project_update_input(client, args).await?;
+        // teamIds replaces the project's teams, so add --team to the ones it already has
+        if let Some(added) = input.team_ids.take() {
+            let mut team_ids: Vec<String> = client
+                .get_project(&project_id)
+                .await?
+                .teams
+                .into_iter()
+                .map(|team| team.id)
+                .collect();
+            for id in added {
+                if !team_ids.contains(&id) {
+                    team_ids.push(id);
+                }
+            }
+            input.team_ids = Some(team_ids);
+        }
+        client.update_project(&project_id, input).await
+    }
+    .await;
+    drop(spinner);
+
+    match updated {
+        Ok(project) => display_saved_project(&cli_output, "Updated", &project, is_interactive),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Read a project update body from piped stdin, or from $EDITOR in a terminal
+///
+/// Returns `None` if the editor is closed without saving.
+fn read_project_update_body(cli_output: &CliOutput, is_interactive: bool) -> Option<String> {
+    let body = if !std::io::stdin().is_terminal() {
+        use std::io::Read;
+        let mut buffer = String::new();
+        if let Err(e) = std::io::stdin().read_to_string(&mut buffer) {
+            cli_output.error(&format!("Failed to read from stdin: {e}"));
+            std::process::exit(1);
+        }
+        buffer
+    } else if is_interactive {
+        match dialoguer::Editor::new()
+            .extension(".md")
+            .require_save(true)
+            .edit("")
+        {
+            Ok(Some(edited)) => edited,
+            Ok(None) => return None,
+            Err(e) => {
+                cli_output.error(&format!("Failed to open editor: {e}"));
+                std::process::exit(1);
+            }
+        }
+    } else {
+        cli_output.error("No update provided. Pipe markdown to stdin or run in a terminal.");
+        std::process::exit(1);
+    };
+
+    if body.trim().is_empty() {
+        cli_output.error("Project update body cannot be empty");
+        std::process::exit(1);
+    }
+    Some(body.trim().to_string())
+}
+
+async fn handle_project_update_post_command(
+    client: &LinearClient,
+    project: &str,
+    health: Option<HealthArg>,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    let Some(body) = read_project_update_body(&cli_output, is_interactive) else {
+        cli_output.info("Project update cancelled");
+        return Ok(());
+    };
+
+    let spinner = SpinnerGuard::new("Posting project update...", is_interactive);
+    let input = linear_sdk::CreateProjectUpdateInput {
+        project_id,
+        body,
+        health: health.map(linear_sdk::ProjectHealth::from),
+    };
+    let posted = client.create_project_update(input).await;
+    drop(spinner);
+
+    match posted {
+        Ok(update) if is_interactive => {
+            cli_output.success(&format!("Posted update on project {project}"));
+            println!("URL: {}", update.url);
+        }
+        Ok(update) => println!("{}", update.id),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_project_updates_command(
+    client: &LinearClient,
+    project: &str,
+    limit: i64,
     json: bool,
+    raw: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    let spinner = SpinnerGuard::new("Fetching project updates...", is_interactive);
+    let updates = match client.list_project_updates(&project_id, limit).await {
+        Ok(updates) => {
+            drop(spinner);
+            updates
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string(&updates)?);
+    } else {
+        let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
+        println!(
+            "{}",
+            formatter.format_project_updates(&updates, is_interactive && !raw)
+        );
+    }
+
+    Ok(())
+}
+
+async fn handle_project_archive_command(
+    client: &LinearClient,
+    project: &str,
+    force: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    if !confirm_action(
+        &format!("archiving project {project}"),
+        force,
+        is_interactive,
+    ) {
+        cli_output.info("Archive cancelled");
+        return Ok(());
+    }
+
+    let spinner = SpinnerGuard::new("Archiving project...", is_interactive);
+    let archived = client.archive_project(&project_id).await;
+    drop(spinner);
+
+    match archived {
+        Ok(()) => cli_output.success(&format!("Archived project {project}")),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Name, description, and target date flags shared by `milestones create` and `update`
+struct MilestoneFieldArgs {
+    name: Option<String>,
+    description: Option<String>,
+    target_date: Option<String>,
+}
+
+/// Parse a milestone target date; "none" becomes an empty string, which clears it on update
+fn parse_milestone_target_date(target_date: Option<&str>) -> Result<Option<String>> {
+    target_date
+        .map(crate::dates::parse_due_date)
+        .transpose()
+        .map_err(|message| LinearError::InvalidInput { message })
+}
+
+/// Resolve a milestone name or ID within a project, or report the error and exit
+async fn resolve_milestone_or_exit(
+    client: &LinearClient,
+    cli_output: &CliOutput,
+    project_id: &str,
+    milestone: &str,
+) -> String {
+    match client.resolve_milestone_to_id(project_id, milestone).await {
+        Ok(id) => id,
+        Err(e) => {
+            cli_output.error(&format!("Failed to resolve milestone '{milestone}': {e}"));
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Display a created or updated milestone
+fn display_saved_milestone(
+    cli_output: &CliOutput,
+    verb: &str,
+    milestone: &linear_sdk::ProjectMilestone,
+    is_interactive: bool,
+) {
+    if is_interactive {
+        cli_output.success(&format!("{verb} milestone: {}", milestone.name));
+        if let Some(target_date) = &milestone.target_date {
+            println!("Target: {target_date}");
+        }
+    } else {
+        println!("{}", milestone.id);
+    }
+}
+
+async fn handle_milestones_list_command(
+    client: &LinearClient,
+    project: &str,
+    format: Format,
     pretty: bool,
-    assignee: Option<String>,
-    status: Option<String>,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    let spinner = SpinnerGuard::new("Fetching milestones...", is_interactive);
+    let milestones = match client.list_project_milestones(&project_id).await {
+        Ok(milestones) => {
+            drop(spinner);
+            milestones
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    if milestones.is_empty() && format == Format::Table && is_interactive {
+        println!("No milestones found in this project.");
+    } else if !milestones.is_empty() {
+        let formatter = list_formatter(format, pretty, use_color, is_interactive);
+        print_formatted(formatter.format_milestones(&milestones), use_color);
+    }
+
+    Ok(())
+}
+
+async fn handle_milestone_create_command(
+    client: &LinearClient,
+    project: &str,
+    args: MilestoneFieldArgs,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+
+    let target_date = match parse_milestone_target_date(args.target_date.as_deref()) {
+        Ok(target_date) => target_date.filter(|date| !date.is_empty()),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+    let input = linear_sdk::CreateProjectMilestoneInput {
+        project_id,
+        name: args.name.unwrap_or_default(),
+        description: args.description,
+        target_date,
+    };
+
+    let spinner = SpinnerGuard::new("Creating milestone...", is_interactive);
+    let created = client.create_project_milestone(input).await;
+    drop(spinner);
+
+    match created {
+        Ok(milestone) => {
+            display_saved_milestone(&cli_output, "Created", &milestone, is_interactive)
+        }
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_milestone_update_command(
+    client: &LinearClient,
+    project: &str,
+    milestone: &str,
+    args: MilestoneFieldArgs,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+
+    if args.name.is_none() && args.description.is_none() && args.target_date.is_none() {
+        cli_output.error("Nothing to update");
+        eprintln!("Use --name, --description, or --target-date");
+        std::process::exit(1);
+    }
+
+    let target_date = match parse_milestone_target_date(args.target_date.as_deref()) {
+        Ok(target_date) => target_date,
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+    let milestone_id = resolve_milestone_or_exit(client, &cli_output, &project_id, milestone).await;
+
+    let input = linear_sdk::UpdateProjectMilestoneInput {
+        name: args.name,
+        description: args.description,
+        target_date,
+    };
+
+    let spinner = SpinnerGuard::new("Updating milestone...", is_interactive);
+    let updated = client.update_project_milestone(&milestone_id, input).await;
+    drop(spinner);
+
+    match updated {
+        Ok(milestone) => {
+            display_saved_milestone(&cli_output, "Updated", &milestone, is_interactive)
+        }
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_milestone_delete_command(
+    client: &LinearClient,
+    project: &str,
+    milestone: &str,
+    force: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let project_id = resolve_project_or_exit(client, &cli_output, project).await;
+    let milestone_id = resolve_milestone_or_exit(client, &cli_output, &project_id, milestone).await;
+
+    if !confirm_action(
+        &format!("deleting milestone {milestone}"),
+        force,
+        is_interactive,
+    ) {
+        cli_output.info("Delete cancelled");
+        return Ok(());
+    }
+
+    let spinner = SpinnerGuard::new("Deleting milestone...", is_interactive);
+    let deleted = client.delete_project_milestone(&milestone_id).await;
+    drop(spinner);
+
+    match deleted {
+        Ok(()) => cli_output.success(&format!("Deleted milestone {milestone}")),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+
+    Ok(())
+}
+
+async fn handle_teams_command(
+    client: &LinearClient,
+    format: Format,
+    pretty: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let spinner = SpinnerGuard::new("Fetching teams...", is_interactive);
+    let teams = match client.list_teams().await {
+        Ok(teams) => {
+            drop(spinner);
+            teams
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    };
+
+    if teams.is_empty() && format == Format::Table && is_interactive {
+        println!("No teams found.");
+    } else if !teams.is_empty() {
+        let formatter = list_formatter(format, pretty, use_color, is_interactive);
+        print_formatted(formatter.format_teams(&teams), use_color);
+    }
+
+    Ok(())
+}
+
+const CYCLE_TEAM_REQUIRED: &str = "Team is required (use --team or set default_team in the config)";
+
+async fn handle_cycles_list_command(
+    client: &LinearClient,
     team: Option<String>,
+    format: Format,
+    pretty: bool,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    let filters = if assignee.is_some() || status.is_some() || team.is_some() {
-        Some(IssueFilters {
-            assignee,
-            status,
-            team,
-        })
-    } else {
-        None
+    let cli_output = CliOutput::with_color(use_color);
+    let team_id = resolve_team_or_exit(client, &cli_output, team, CYCLE_TEAM_REQUIRED).await;
+
+    let spinner = SpinnerGuard::new("Fetching cycles...", is_interactive);
+    let cycles = match client.list_cycles(&team_id).await {
+        Ok(cycles) => {
+            drop(spinner);
+            cycles
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
     };
 
-    let spinner = SpinnerGuard::new("Fetching issues...", is_interactive);
-    let issues = match client.list_issues_filtered(limit, filters).await {
-        Ok(issues) => {
-            drop(spinner);
-            issues
-        }
+    if cycles.is_empty() && format == Format::Table && is_interactive {
+        println!("No cycles found. Cycles may be turned off for this team.");
+    } else if !cycles.is_empty() {
+        let formatter = list_formatter(format, pretty, use_color, is_interactive);
+        print_formatted(formatter.format_cycles(&cycles), use_color);
+    }
+
+    Ok(())
+}
+
+async fn handle_cycle_show_command(
+    client: &LinearClient,
+    team: Option<String>,
+    cycle: &str,
+    format: Format,
+    pretty: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    let cli_output = CliOutput::with_color(use_color);
+    let team_id = resolve_team_or_exit(client, &cli_output, team, CYCLE_TEAM_REQUIRED).await;
+
+    let spinner = SpinnerGuard::new("Fetching cycle...", is_interactive);
+    let result = match client.resolve_cycle_to_id(&team_id, cycle).await {
+        Ok(cycle_id) => client.get_cycle(&cycle_id).await,
+        Err(e) => Err(e),
+    };
+    drop(spinner);
+    let detail = match result {
+        Ok(detail) => detail,
         Err(e) => {
-            drop(spinner);
             display_error(&e, use_color);
             std::process::exit(1);
         }
     };
 
-    if issues.is_empty() && !json && is_interactive {
-        println!("No issues found.");
-    } else if !issues.is_empty() {
-        let output = if json {
-            let formatter = JsonFormatter::new(pretty);
-            match formatter.format_issues(&issues) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&e, use_color);
-                    std::process::exit(1);
-                }
-            }
-        } else {
-            let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
-            match formatter.format_issues(&issues) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&e, use_color);
-                    std::process::exit(1);
-                }
-            }
-        };
-        println!("{output}");
-    }
-
+    let formatter = list_formatter(format, pretty, use_color, is_interactive);
+    print_formatted(formatter.format_cycle(&detail), use_color);
     Ok(())
 }
 
-async fn handle_issue_detail_command(
+async fn handle_cycles_add_command(
     client: &LinearClient,
-    id: String,
-    json: bool,
-    raw: bool,
+    args: StatusChangeArgs,
+    cycle: String,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    let spinner = SpinnerGuard::new(&format!("Fetching issue {id}..."), is_interactive);
-    match client.get_issue(id).await {
-        Ok(issue) => {
-            drop(spinner);
-            let output = if json {
-                let formatter = JsonFormatter::new(false);
-                match formatter.format_detailed_issue(&issue) {
-                    Ok(output) => output,
-                    Err(e) => {
-                        display_error(&e, use_color);
-                        std::process::exit(1);
-                    }
-                }
-            } else {
-                let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
-                let use_rich_formatting = is_interactive && !raw;
+    let cli_output = CliOutput::with_color(use_color);
+    let targets = resolve_targets(client, args.ids, args.filter, use_color, is_interactive).await;
+    if targets.is_empty() {
+        return Ok(());
+    }
 
-                match formatter.format_detailed_issue_rich(&issue, use_rich_formatting) {
-                    Ok(output) => output,
-                    Err(e) => {
-                        display_error(&e, use_color);
-                        std::process::exit(1);
-                    }
-                }
-            };
-            println!("{output}");
+    if args.dry_run || (!args.force && is_interactive) {
+        println!(
+            "Would move {} to cycle '{cycle}'",
+            describe_targets(&targets)
+        );
+        println!();
+        if args.dry_run {
+            return Ok(());
         }
-        Err(e) => {
-            drop(spinner);
-            display_error(&e, use_color);
-            std::process::exit(1);
+
+        if !confirm_action("move", false, true) {
+            cli_output.info("Move cancelled");
+            return Ok(());
         }
     }
 
+    // Cycles belong to teams, so "next" or a cycle number resolves against each issue's team
+    let team_fields = TeamScopedFields {
+        cycle: Some(cycle),
+        ..Default::default()
+    };
+    bulk_update_issues(
+        client,
+        targets,
+        linear_sdk::UpdateIssueInput::default(),
+        &team_fields,
+        ("move", "Moved", "Moving"),
+        use_color,
+        is_interactive,
+    )
+    .await;
     Ok(())
 }
 
-async fn handle_status_command(
+async fn handle_cycles_rollover_command(
     client: &LinearClient,
-    verbose: bool,
+    team: Option<String>,
+    force: bool,
+    dry_run: bool,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    let spinner = SpinnerGuard::new("Checking Linear connection...", is_interactive);
-    match client.execute_viewer_query().await {
-        Ok(viewer_data) => {
-            drop(spinner);
-            if is_interactive {
-                if use_color {
-                    println!("{} Connected to Linear", "✓".green());
-                } else {
-                    println!("✓ Connected to Linear");
-                }
+    let cli_output = CliOutput::with_color(use_color);
+    let team_id = resolve_team_or_exit(client, &cli_output, team, CYCLE_TEAM_REQUIRED).await;
 
-                if verbose {
-                    println!();
-                    println!(
-                        "User: {} ({})",
-                        viewer_data.viewer.name, viewer_data.viewer.email
-                    );
-                    println!("User ID: {}", viewer_data.viewer.id);
-                }
-            }
-        }
+    let spinner = SpinnerGuard::new("Fetching cycles...", is_interactive);
+    let cycles = async {
+        let current_id = client.resolve_cycle_to_id(&team_id, "current").await?;
+        let next_id = client.resolve_cycle_to_id(&team_id, "next").await?;
+        Ok::<_, LinearError>((client.get_cycle(&current_id).await?, next_id))
+    }
+    .await;
+    drop(spinner);
+    let (current, next_id) = match cycles {
+        Ok(cycles) => cycles,
         Err(e) => {
-            drop(spinner);
-            if is_interactive {
-                if use_color {
-                    println!("{} Failed to connect to Linear", "✗".red());
-                } else {
-                    println!("✗ Failed to connect to Linear");
-                }
-                println!();
-            }
             display_error(&e, use_color);
             std::process::exit(1);
         }
+    };
+
+    let targets: Vec<String> = current
+        .issues
+        .iter()
+        .filter(|issue| !matches!(issue.state.type_.as_str(), "completed" | "canceled"))
+        .map(|issue| issue.identifier.clone())
+        .collect();
+    if targets.is_empty() {
+        cli_output.info(&format!(
+            "Every issue in cycle {} is finished; nothing to roll over",
+            current.cycle.number
+        ));
+        return Ok(());
+    }
+
+    if dry_run || (!force && is_interactive) {
+        println!(
+            "Would move {} from cycle {} to the next cycle",
+            describe_targets(&targets),
+            current.cycle.number
+        );
+        println!();
+        if dry_run {
+            return Ok(());
+        }
+
+        if !confirm_action("rollover", false, true) {
+            cli_output.info("Rollover cancelled");
+            return Ok(());
+        }
     }
 
+    let input = linear_sdk::UpdateIssueInput {
+        cycle_id: Some(next_id),
+        ..Default::default()
+    };
+    bulk_update_issues(
+        client,
+        targets,
+        input,
+        &TeamScopedFields::default(),
+        ("move", "Moved", "Moving"),
+        use_color,
+        is_interactive,
+    )
+    .await;
     Ok(())
 }
 
-async fn handle_projects_command(
+/// Print a report as a chart, JSON, YAML, CSV, or TSV
+fn print_report<T: serde::Serialize>(
+    format: &Format,
+    report: &T,
+    chart: impl Fn(&T) -> String,
+    csv: impl Fn(&T, u8) -> anyhow::Result<String>,
+    use_color: bool,
+) {
+    let output = match format {
+        Format::Json => serde_json::to_string(report).map_err(anyhow::Error::from),
+        Format::Yaml => serde_yaml::to_string(report)
+            .map(|yaml| yaml.trim_end().to_string())
+            .map_err(anyhow::Error::from),
+        Format::Csv => csv(report, b','),
+        Format::Tsv => csv(report, b'\t'),
+        _ => Ok(chart(report)),
+    };
+    match output {
+        Ok(output) => println!("{output}"),
+        Err(e) => {
+            CliOutput::with_color(use_color).error(&format!("Failed to format report: {e}"));
+            std::process::exit(1);
+        }
+    }
+}
+
+async fn handle_burndown_report(
     client: &LinearClient,
-    limit: i32,
-    json: bool,
+    team: Option<String>,
+    cycle: &str,
+    format: &Format,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    let spinner = SpinnerGuard::new("Fetching projects...", is_interactive);
-    let projects = match client.list_projects(limit).await {
-        Ok(projects) => {
-            drop(spinner);
-            projects
-        }
+    let cli_output = CliOutput::with_color(use_color);
+    let team_id = resolve_team_or_exit(client, &cli_output, team, CYCLE_TEAM_REQUIRED).await;
+
+    let spinner = SpinnerGuard::new("Fetching cycle history...", is_interactive);
+    let result = match client.resolve_cycle_to_id(&team_id, cycle).await {
+        Ok(cycle_id) => client.get_cycle_history(&cycle_id).await,
+        Err(e) => Err(e),
+    };
+    drop(spinner);
+    let history = match result {
+        Ok(history) => history,
         Err(e) => {
-            drop(spinner);
             display_error(&e, use_color);
             std::process::exit(1);
         }
     };
 
-    if projects.is_empty() && !json && is_interactive {
-        println!("No projects found.");
-    } else if !projects.is_empty() {
-        let output = if json {
-            match serde_json::to_string_pretty(&projects) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&LinearError::from(e), use_color);
-                    std::process::exit(1);
-                }
-            }
-        } else {
-            projects
-                .iter()
-                .map(|p| format!("{}: {} ({})", p.id, p.name, p.state))
-                .collect::<Vec<_>>()
-                .join("\n")
-        };
-        println!("{output}");
-    }
-
+    let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
+    print_report(
+        format,
+        &report::burndown(&history),
+        |burndown| report::render_burndown(burndown, &formatter),
+        report::burndown_csv,
+        use_color,
+    );
     Ok(())
 }
 
-async fn handle_teams_command(
+async fn handle_velocity_report(
     client: &LinearClient,
-    json: bool,
+    team: Option<String>,
+    last: usize,
+    format: &Format,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
-    let spinner = SpinnerGuard::new("Fetching teams...", is_interactive);
-    let teams = match client.list_teams().await {
-        Ok(teams) => {
-            drop(spinner);
-            teams
-        }
+    let cli_output = CliOutput::with_color(use_color);
+    let team_id = resolve_team_or_exit(client, &cli_output, team, CYCLE_TEAM_REQUIRED).await;
+
+    let spinner = SpinnerGuard::new("Fetching cycles...", is_interactive);
+    let result = match client.list_cycles(&team_id).await {
+        Ok(cycles) => client
+            .get_team_states(team_id.clone())
+            .await
+            .map(|team| (cycles, team.name)),
+        Err(e) => Err(e),
+    };
+    drop(spinner);
+    let (cycles, team_name) = match result {
+        Ok(result) => result,
         Err(e) => {
-            drop(spinner);
             display_error(&e, use_color);
             std::process::exit(1);
         }
     };
 
-    if teams.is_empty() && !json && is_interactive {
-        println!("No teams found.");
-    } else if !teams.is_empty() {
-        let output = if json {
-            match serde_json::to_string_pretty(&teams) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&LinearError::from(e), use_color);
-                    std::process::exit(1);
-                }
-            }
-        } else {
-            teams
-                .iter()
-                .map(|t| format!("{}: {} ({} members)", t.key, t.name, t.members.len()))
-                .collect::<Vec<_>>()
-                .join("\n")
-        };
-        println!("{output}");
-    }
-
+    let formatter = TableFormatter::new_with_interactive(use_color, is_interactive);
+    print_report(
+        format,
+        &report::velocity(&cycles, &team_name, last),
+        |velocity| report::render_velocity(velocity, &formatter),
+        report::velocity_csv,
+        use_color,
+    );
     Ok(())
 }
 
 async fn handle_comments_command(
     client: &LinearClient,
     id: String,
-    limit: i32,
-    json: bool,
+    limit: PageLimit,
+    format: Format,
+    pretty: bool,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
@@ -1471,31 +3762,11 @@ async fn handle_comments_command(
         }
     };
 
-    if issue_with_comments.comments.is_empty() && !json && is_interactive {
+    if issue_with_comments.comments.is_empty() && format == Format::Table && is_interactive {
         println!("No comments found for issue {id}.");
     } else {
-        let output = if json {
-            match serde_json::to_string_pretty(&issue_with_comments) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&LinearError::from(e), use_color);
-                    std::process::exit(1);
-                }
-            }
-        } else {
-            format!(
-                "Issue: {} - {}\n\nComments:\n{}",
-                issue_with_comments.identifier,
-                issue_with_comments.title,
-                issue_with_comments
-                    .comments
-                    .iter()
-                    .map(|c| format!("{}: {}", c.user.name, c.body))
-                    .collect::<Vec<_>>()
-                    .join("\n")
-            )
-        };
-        println!("{output}");
+        let formatter = list_formatter(format, pretty, use_color, is_interactive);
+        print_formatted(formatter.format_comments(&issue_with_comments), use_color);
     }
 
     Ok(())
@@ -1503,13 +3774,15 @@ async fn handle_comments_command(
 
 async fn handle_mywork_command(
     client: &LinearClient,
-    limit: i32,
-    json: bool,
+    limit: PageLimit,
+    filters: linear_sdk::MyWorkFilters,
+    format: Format,
+    pretty: bool,
     use_color: bool,
     is_interactive: bool,
 ) -> Result<()> {
     let spinner = SpinnerGuard::new("Fetching your work...", is_interactive);
-    let my_work = match client.get_my_work(limit).await {
+    let my_work = match client.get_my_work_filtered(limit, &filters).await {
         Ok(work) => {
             drop(spinner);
             work
@@ -1521,33 +3794,50 @@ async fn handle_mywork_command(
         }
     };
 
-    let output = if json {
-        match serde_json::to_string_pretty(&my_work) {
-            Ok(output) => output,
-            Err(e) => {
-                display_error(&LinearError::from(e), use_color);
-                std::process::exit(1);
-            }
+    let formatter = list_formatter(format, pretty, use_color, is_interactive);
+    print_formatted(formatter.format_my_work(&my_work), use_color);
+
+    Ok(())
+}
+
+async fn handle_pick_command(client: &LinearClient, use_color: bool) -> Result<()> {
+    println!("{}", pick_issue(client, use_color).await);
+    Ok(())
+}
+
+async fn handle_tui_command(
+    client: &LinearClient,
+    limit: PageLimit,
+    filters: IssueFilters,
+    use_color: bool,
+    is_interactive: bool,
+) -> Result<()> {
+    if !is_interactive {
+        let cli_output = CliOutput::with_color(use_color);
+        cli_output.error("linear tui needs an interactive terminal");
+        std::process::exit(1);
+    }
+
+    let spinner = SpinnerGuard::new("Fetching issues...", is_interactive);
+    let issues = match client
+        .list_issues_filtered(limit, Some(filters.clone()))
+        .await
+    {
+        Ok(issues) => {
+            drop(spinner);
+            issues
+        }
+        Err(e) => {
+            drop(spinner);
+            display_error(&e, use_color);
+            std::process::exit(1);
         }
-    } else {
-        format!(
-            "Assigned to you:\n{}\n\nCreated by you:\n{}",
-            my_work
-                .assigned_issues
-                .iter()
-                .map(|i| format!("{}: {}", i.identifier, i.title))
-                .collect::<Vec<_>>()
-                .join("\n"),
-            my_work
-                .created_issues
-                .iter()
-                .map(|i| format!("{}: {}", i.identifier, i.title))
-                .collect::<Vec<_>>()
-                .join("\n")
-        )
     };
-    println!("{output}");
 
+    if let Err(e) = tui::run(client, issues, filters, limit, use_color).await {
+        display_error(&e, use_color);
+        std::process::exit(1);
+    }
     Ok(())
 }
 
@@ -1559,7 +3849,7 @@ async fn handle_search_command(
     docs_only: bool,
     projects_only: bool,
     limit: i32,
-    json: bool,
+    format: Format,
     pretty: bool,
     include_archived: bool,
     use_color: bool,
@@ -1590,96 +3880,181 @@ async fn handle_search_command(
         }
     };
 
+    let has_results =
+        !result.issues.is_empty() || !result.documents.is_empty() || !result.projects.is_empty();
+
+    if format == Format::Table && !has_results {
+        if is_interactive {
+            println!("No results found.");
+        }
+    } else {
+        let formatter = list_formatter(format, pretty, use_color, is_interactive);
+        print_formatted(formatter.format_search_results(&result), use_color);
+    }
+
+    Ok(())
+}
+
+/// Resolve `--json` and `--format` into the output format, defaulting to a table
+fn output_format(json: bool, format: Option<Format>) -> Format {
     if json {
-        let output = if pretty {
-            match serde_json::to_string_pretty(&result) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&LinearError::from(e), use_color);
-                    std::process::exit(1);
-                }
-            }
-        } else {
-            match serde_json::to_string(&result) {
-                Ok(output) => output,
-                Err(e) => {
-                    display_error(&LinearError::from(e), use_color);
-                    std::process::exit(1);
-                }
-            }
-        };
-        println!("{output}");
+        Format::Json
     } else {
-        let has_results = !result.issues.is_empty()
-            || !result.documents.is_empty()
-            || !result.projects.is_empty();
+        format.unwrap_or(Format::Table)
+    }
+}
 
-        if !has_results {
-            if is_interactive {
-                println!("No results found.");
-            }
-        } else {
-            if !result.issues.is_empty() {
-                println!("Issues:");
-                for issue in &result.issues {
-                    println!("  {}: {}", issue.identifier, issue.title);
-                }
-                if !result.documents.is_empty() || !result.projects.is_empty() {
-                    println!();
-                }
-            }
+/// Formatter for list commands that don't choose issue columns
+fn list_formatter(
+    format: Format,
+    pretty: bool,
+    use_color: bool,
+    is_interactive: bool,
+) -> Box<dyn OutputFormat> {
+    formatter_or_exit(
+        format,
+        FormatterOptions {
+            pretty,
+            use_color,
+            is_interactive,
+            columns: DEFAULT_ISSUE_COLUMNS.to_vec(),
+        },
+    )
+}
 
-            if !result.documents.is_empty() {
-                println!("Documents:");
-                for doc in &result.documents {
-                    println!("  {}: {}", doc.title, doc.url);
-                }
-                if !result.projects.is_empty() {
-                    println!();
-                }
-            }
+/// Build the formatter for `format`, or report an invalid template and exit
+fn formatter_or_exit(format: Format, options: FormatterOptions) -> Box<dyn OutputFormat> {
+    let use_color = options.use_color;
+    match create_formatter(format, options) {
+        Ok(formatter) => formatter,
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
+        }
+    }
+}
 
-            if !result.projects.is_empty() {
-                println!("Projects:");
-                for project in &result.projects {
-                    println!("  {}: {}", project.name, project.url);
-                }
-            }
+/// Print formatted output, or report the formatting error and exit
+fn print_formatted(output: Result<String>, use_color: bool) {
+    match output {
+        Ok(output) => println!("{output}"),
+        Err(e) => {
+            display_error(&e, use_color);
+            std::process::exit(1);
         }
     }
+}
 
-    Ok(())
+/// Apply the configured default assignee and team to issue filters that don't set them
+fn apply_filter_defaults(filters: &mut IssueFilterArgs, config: &Config) {
+    if filters.assignee.is_none() {
+        filters.assignee = config.default_assignee.clone();
+    }
+    if filters.team.is_none() {
+        filters.team = config.default_team.clone();
+    }
 }
 
 /// Apply configuration defaults to CLI arguments where not explicitly provided
-fn apply_config_defaults(cli: &mut Cli, config: &Config) {
+fn apply_config_defaults(cli: &mut Cli, config: &Config) -> Result<()> {
     match &mut cli.command {
         Commands::Issues {
-            assignee,
-            team,
+            filters,
             json,
+            format,
+            template,
+            columns,
             ..
         } => {
-            // Apply default assignee if not specified
-            if assignee.is_none() {
-                *assignee = config.default_assignee.clone();
+            // Apply configured table columns if not specified
+            if columns.is_none() {
+                *columns = config.columns_for("issues").map(ColumnSpec);
             }
 
-            // Apply default team if not specified
+            apply_filter_defaults(filters, config);
+            apply_output_config(config, *json, format, template)?;
+        }
+        Commands::Cycles {
+            command:
+                CycleCommand::List { view }
+                | CycleCommand::Show { view, .. }
+                | CycleCommand::Current { view }
+                | CycleCommand::Next { view },
+        } => {
+            if view.team.is_none() {
+                view.team = config.default_team.clone();
+            }
+            apply_output_config(config, view.json, &mut view.format, &mut view.template)?;
+        }
+        Commands::Cycles {
+            command: CycleCommand::Rollover { team, .. },
+        }
+        | Commands::Project {
+            command: ProjectCommand::Create { team, .. },
+        }
+        | Commands::Report {
+            command: ReportCommand::Burndown { team, .. } | ReportCommand::Velocity { team, .. },
+        } => {
             if team.is_none() {
                 *team = config.default_team.clone();
             }
-
-            // Apply preferred format if not specified and format is table->json
-            if !*json {
-                if let Some(ref format) = config.preferred_format {
-                    if format == "json" {
-                        *json = true;
-                    }
-                }
+        }
+        Commands::Tui { filters, .. }
+        | Commands::Sync {
+            command: SyncCommand::Pull { filters, .. },
+        } => apply_filter_defaults(filters, config),
+        Commands::Sync {
+            command: SyncCommand::Push { team, .. },
+        } => {
+            if team.is_none() {
+                *team = config.default_team.clone();
             }
         }
-        Commands::Issue { json, .. } => {
+        Commands::Projects {
+            json,
+            format,
+            template,
+            ..
+        }
+        | Commands::Teams {
+            json,
+            format,
+            template,
+            ..
+        }
+        | Commands::Comments {
+            json,
+            format,
+            template,
+            ..
+        }
+        | Commands::MyWork {
+            json,
+            format,
+            template,
+            ..
+        }
+        | Commands::Search {
+            json,
+            format,
+            template,
+            ..
+        }
+        | Commands::Milestones {
+            command:
+                MilestoneCommand::List {
+                    json,
+                    format,
+                    template,
+                    ..
+                },
+        } => {
+            apply_output_config(config, *json, format, template)?;
+        }
+        Commands::Issue { json, .. }
+        | Commands::Project {
+            command: ProjectCommand::Show { json, .. } | ProjectCommand::Updates { json, .. },
+        } => {
             // Apply preferred format if not specified
             if !*json {
                 if let Some(ref format) = config.preferred_format {
@@ -1700,16 +4075,47 @@ fn apply_config_defaults(cli: &mut Cli, config: &Config) {
                 *assignee = config.default_assignee.clone();
             }
         }
-        Commands::Update { assignee, .. } => {
-            // Apply default assignee if not specified and not setting to unassigned
-            if assignee.is_none() {
-                *assignee = config.default_assignee.clone();
-            }
-        }
         _ => {
-            // Other commands don't use configurable defaults
+            // Other commands don't use configurable defaults; `update` in particular leaves
+            // assignees alone unless --assignee is given, since it may touch many issues
+        }
+    }
+    Ok(())
+}
+
+/// Fold `--template` into `--format`, expand named templates from the config file, and use
+/// the configured preferred format when no output flag was given. A bare word that names no
+/// configured template is rejected rather than printed literally for every row
+fn apply_output_config(
+    config: &Config,
+    json: bool,
+    format: &mut Option<Format>,
+    template: &mut Option<Format>,
+) -> Result<()> {
+    if let Some(template) = template.take() {
+        *format = Some(template);
+    }
+
+    if let Some(Format::Template(source)) = format {
+        if let Some(named) = config.output_template(source) {
+            *source = named.to_string();
+        } else if !source.contains("{{") {
+            let names = config.template_names();
+            let available = if names.is_empty() {
+                "no named templates are configured under [templates]".to_string()
+            } else {
+                format!("configured templates: {}", names.join(", "))
+            };
+            return Err(LinearError::InvalidInput {
+                message: format!("Unknown template '{source}'; {available}"),
+            });
         }
     }
+
+    if !json && format.is_none() {
+        *format = config.preferred_output_format();
+    }
+    Ok(())
 }
 
 async fn run_async_commands(
@@ -1719,7 +4125,7 @@ async fn run_async_commands(
     is_interactive: bool,
 ) -> Result<()> {
     // Apply config defaults to CLI arguments
-    apply_config_defaults(&mut cli, &config);
+    apply_config_defaults(&mut cli, &config)?;
 
     // Authentication priority:
     // 1. LINEAR_API_KEY env var
@@ -1762,6 +4168,9 @@ async fn run_async_commands(
             match LinearClient::builder()
                 .auth_token(SecretString::new(bearer_token.into_boxed_str()))
                 .base_url(config.api_url.clone())
+                .max_concurrent_requests(Some(config.max_concurrent_requests()))
+                .requests_per_hour(Some(config.requests_per_hour()))
+                .max_rate_limit_wait(config.max_rate_limit_wait())
                 .verbose(cli.verbose)
                 .build()
             {
@@ -1785,6 +4194,9 @@ async fn run_async_commands(
         match LinearClient::builder()
             .auth_token(SecretString::new(auth_token.into_boxed_str()))
             .base_url(config.api_url.clone())
+            .max_concurrent_requests(Some(config.max_concurrent_requests()))
+            .requests_per_hour(Some(config.requests_per_hour()))
+            .max_rate_limit_wait(config.max_rate_limit_wait())
             .verbose(cli.verbose)
             .build()
         {
@@ -1803,27 +4215,46 @@ async fn run_async_commands(
     match cli.command {
         Commands::Issues {
             limit,
+            all,
             json,
+            format,
+            template: _,
             pretty,
-            assignee,
-            status,
-            team,
+            filters,
+            sort,
+            columns,
         } => {
+            let filters = IssueFilters::from(filters);
+            let output = IssueListOutput {
+                format: output_format(json, format),
+                pretty,
+                sort: sort.map(|spec| spec.0).unwrap_or_default(),
+                columns: columns
+                    .map(|spec| spec.0)
+                    .unwrap_or_else(|| DEFAULT_ISSUE_COLUMNS.to_vec()),
+            };
             handle_issues_command(
                 &client,
-                limit,
-                json,
-                pretty,
-                assignee,
-                status,
-                team,
+                page_limit(limit, all),
+                filters,
+                output,
                 use_color,
                 is_interactive,
             )
             .await?;
         }
-        Commands::Issue { id, json, raw } => {
-            handle_issue_detail_command(&client, id, json, raw, use_color, is_interactive).await?;
+        Commands::Issue {
+            id,
+            json,
+            raw,
+            tree,
+        } => {
+            if tree {
+                handle_issue_tree_command(&client, id, json, use_color, is_interactive).await?;
+            } else {
+                handle_issue_detail_command(&client, id, json, raw, use_color, is_interactive)
+                    .await?;
+            }
         }
         Commands::Create {
             title,
@@ -1836,6 +4267,9 @@ async fn run_async_commands(
             cycle,
             project,
             project_id,
+            milestone,
+            parent,
+            due,
             from_file,
             open,
             dry_run,
@@ -1851,6 +4285,9 @@ async fn run_async_commands(
                 cycle,
                 project,
                 project_id,
+                milestone,
+                parent,
+                due,
                 from_file,
                 open,
                 dry_run,
@@ -1866,7 +4303,8 @@ async fn run_async_commands(
             unreachable!()
         }
         Commands::Update {
-            id,
+            ids,
+            filter,
             title,
             description,
             assignee,
@@ -1877,12 +4315,17 @@ async fn run_async_commands(
             cycle,
             project,
             project_id,
+            milestone,
+            parent,
+            due,
             force,
+            dry_run,
         } => {
             handle_update_command(
                 &client,
                 UpdateCommandArgs {
-                    id,
+                    ids,
+                    filter,
                     title,
                     description,
                     assignee,
@@ -1893,21 +4336,114 @@ async fn run_async_commands(
                     cycle,
                     project,
                     project_id,
+                    milestone,
+                    parent,
+                    due,
                     force,
+                    dry_run,
                 },
                 use_color,
                 is_interactive,
             )
             .await?;
         }
-        Commands::Close { id, force } => {
-            handle_close_command(&client, id, force, use_color, is_interactive).await?;
+        Commands::Edit { id, force } => {
+            handle_edit_command(&client, id, force, use_color, is_interactive).await?;
+        }
+        Commands::Close {
+            ids,
+            filter,
+            force,
+            dry_run,
+        } => {
+            let args = StatusChangeArgs {
+                ids,
+                filter,
+                force,
+                dry_run,
+            };
+            handle_status_change(
+                &client,
+                StatusChange::Close,
+                args,
+                use_color,
+                is_interactive,
+            )
+            .await?;
+        }
+        Commands::Reopen {
+            ids,
+            filter,
+            force,
+            dry_run,
+        } => {
+            let args = StatusChangeArgs {
+                ids,
+                filter,
+                force,
+                dry_run,
+            };
+            handle_status_change(
+                &client,
+                StatusChange::Reopen,
+                args,
+                use_color,
+                is_interactive,
+            )
+            .await?;
+        }
+        Commands::Archive { ids, force } => {
+            handle_issue_removal(
+                &client,
+                IssueRemoval::Archive,
+                ids,
+                force,
+                use_color,
+                is_interactive,
+            )
+            .await?;
+        }
+        Commands::Unarchive { ids } => {
+            handle_issue_removal(
+                &client,
+                IssueRemoval::Unarchive,
+                ids,
+                false,
+                use_color,
+                is_interactive,
+            )
+            .await?;
         }
-        Commands::Reopen { id, force } => {
-            handle_reopen_command(&client, id, force, use_color, is_interactive).await?;
+        Commands::Delete {
+            ids,
+            permanent,
+            force,
+        } => {
+            handle_issue_removal(
+                &client,
+                IssueRemoval::Delete { permanent },
+                ids,
+                force,
+                use_color,
+                is_interactive,
+            )
+            .await?;
         }
-        Commands::Comment { id, message } => {
-            handle_comment_command(&client, id, message, use_color, is_interactive).await?;
+        Commands::Comment {
+            ids,
+            message,
+            filter,
+            force,
+            dry_run,
+        } => {
+            let args = CommentCommandArgs {
+                ids,
+                message,
+                filter,
+                force,
+                dry_run,
+            };
+            handle_comment_command(&client, args, use_color, is_interactive).await?;
         }
         Commands::Attach { id, url, title } => {
             handle_attach_command(&client, id, url, title, use_color, is_interactive).await?;
@@ -1917,28 +4453,387 @@ async fn run_async_commands(
         }
         Commands::Projects {
             limit,
+            all,
+            team,
+            state,
+            lead,
             json,
-            pretty: _,
+            format,
+            template: _,
+            pretty,
         } => {
-            handle_projects_command(&client, limit, json, use_color, is_interactive).await?;
+            let limit = page_limit(limit, all);
+            let filters = linear_sdk::ProjectFilters { team, state, lead };
+            let format = output_format(json, format);
+            handle_projects_command(
+                &client,
+                limit,
+                filters,
+                format,
+                pretty,
+                use_color,
+                is_interactive,
+            )
+            .await?;
         }
-        Commands::Teams { json, pretty: _ } => {
-            handle_teams_command(&client, json, use_color, is_interactive).await?;
+        Commands::Project { command } => match command {
+            ProjectCommand::Show { project, json, raw } => {
+                handle_project_show_command(
+                    &client,
+                    &project,
+                    json,
+                    raw,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            ProjectCommand::Create {
+                name,
+                team,
+                description,
+                lead,
+                target_date,
+                state,
+            } => {
+                let args = ProjectFieldArgs {
+                    name: Some(name),
+                    description,
+                    team,
+                    lead,
+                    target_date,
+                    state,
+                };
+                handle_project_create_command(&client, args, use_color, is_interactive).await?;
+            }
+            ProjectCommand::Update {
+                project,
+                name,
+                description,
+                lead,
+                team,
+                target_date,
+                state,
+            } => {
+                let args = ProjectFieldArgs {
+                    name,
+                    description,
+                    team,
+                    lead,
+                    target_date,
+                    state,
+                };
+                handle_project_update_command(&client, &project, args, use_color, is_interactive)
+                    .await?;
+            }
+            ProjectCommand::UpdatePost { project, health } => {
+                handle_project_update_post_command(
+                    &client,
+                    &project,
+                    health,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            ProjectCommand::Updates {
+                project,
+                limit,
+                json,
+                raw,
+            } => {
+                handle_project_updates_command(
+                    &client,
+                    &project,
+                    limit,
+                    json,
+                    raw,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            ProjectCommand::Archive { project, force } => {
+                handle_project_archive_command(&client, &project, force, use_color, is_interactive)
+                    .await?;
+            }
+        },
+        Commands::Milestones { command } => match command {
+            MilestoneCommand::List {
+                project,
+                json,
+                format,
+                template: _,
+                pretty,
+            } => {
+                let format = output_format(json, format);
+                handle_milestones_list_command(
+                    &client,
+                    &project,
+                    format,
+                    pretty,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            MilestoneCommand::Create {
+                name,
+                project,
+                description,
+                target_date,
+            } => {
+                let args = MilestoneFieldArgs {
+                    name: Some(name),
+                    description,
+                    target_date,
+                };
+                handle_milestone_create_command(&client, &project, args, use_color, is_interactive)
+                    .await?;
+            }
+            MilestoneCommand::Update {
+                milestone,
+                project,
+                name,
+                description,
+                target_date,
+            } => {
+                let args = MilestoneFieldArgs {
+                    name,
+                    description,
+                    target_date,
+                };
+                handle_milestone_update_command(
+                    &client,
+                    &project,
+                    &milestone,
+                    args,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            MilestoneCommand::Delete {
+                milestone,
+                project,
+                force,
+            } => {
+                handle_milestone_delete_command(
+                    &client,
+                    &project,
+                    &milestone,
+                    force,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+        },
+        Commands::Teams {
+            json,
+            format,
+            template: _,
+            pretty,
+        } => {
+            let format = output_format(json, format);
+            handle_teams_command(&client, format, pretty, use_color, is_interactive).await?;
         }
         Commands::Comments {
             id,
             limit,
+            all,
             json,
-            pretty: _,
+            format,
+            template: _,
+            pretty,
         } => {
-            handle_comments_command(&client, id, limit, json, use_color, is_interactive).await?;
+            let limit = page_limit(limit, all);
+            let format = output_format(json, format);
+            handle_comments_command(
+                &client,
+                id,
+                limit,
+                format,
+                pretty,
+                use_color,
+                is_interactive,
+            )
+            .await?;
         }
         Commands::MyWork {
             limit,
+            all,
+            due_before,
+            overdue,
             json,
-            pretty: _,
+            format,
+            template: _,
+            pretty,
+        } => {
+            let limit = page_limit(limit, all);
+            let filters = linear_sdk::MyWorkFilters {
+                due_before,
+                overdue,
+            };
+            let format = output_format(json, format);
+            handle_mywork_command(
+                &client,
+                limit,
+                filters,
+                format,
+                pretty,
+                use_color,
+                is_interactive,
+            )
+            .await?;
+        }
+        Commands::Cycles { command } => match command {
+            CycleCommand::List { view } => {
+                let format = output_format(view.json, view.format);
+                handle_cycles_list_command(
+                    &client,
+                    view.team,
+                    format,
+                    view.pretty,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            CycleCommand::Show { cycle, view } => {
+                let format = output_format(view.json, view.format);
+                handle_cycle_show_command(
+                    &client,
+                    view.team,
+                    &cycle,
+                    format,
+                    view.pretty,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            CycleCommand::Current { view } => {
+                let format = output_format(view.json, view.format);
+                handle_cycle_show_command(
+                    &client,
+                    view.team,
+                    "current",
+                    format,
+                    view.pretty,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            CycleCommand::Next { view } => {
+                let format = output_format(view.json, view.format);
+                handle_cycle_show_command(
+                    &client,
+                    view.team,
+                    "next",
+                    format,
+                    view.pretty,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            CycleCommand::Add {
+                ids,
+                cycle,
+                filter,
+                force,
+                dry_run,
+            } => {
+                let args = StatusChangeArgs {
+                    ids,
+                    filter,
+                    force,
+                    dry_run,
+                };
+                handle_cycles_add_command(&client, args, cycle, use_color, is_interactive).await?;
+            }
+            CycleCommand::Rollover {
+                team,
+                force,
+                dry_run,
+            } => {
+                handle_cycles_rollover_command(
+                    &client,
+                    team,
+                    force,
+                    dry_run,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+        },
+        Commands::Report { command } => match command {
+            ReportCommand::Burndown {
+                team,
+                cycle,
+                format,
+            } => {
+                handle_burndown_report(&client, team, &cycle, &format, use_color, is_interactive)
+                    .await?;
+            }
+            ReportCommand::Velocity { team, last, format } => {
+                handle_velocity_report(
+                    &client,
+                    team,
+                    last as usize,
+                    &format,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+        },
+        Commands::Sync { command } => match command {
+            SyncCommand::Pull {
+                dir,
+                filters,
+                force,
+            } => {
+                let filters = IssueFilters::from(filters);
+                handle_sync_pull(
+                    &client,
+                    Path::new(&dir),
+                    filters,
+                    force,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+            SyncCommand::Push { dir, team, dry_run } => {
+                handle_sync_push(
+                    &client,
+                    Path::new(&dir),
+                    team,
+                    dry_run,
+                    use_color,
+                    is_interactive,
+                )
+                .await?;
+            }
+        },
+        Commands::Pick => handle_pick_command(&client, use_color).await?,
+        Commands::Tui {
+            limit,
+            all,
+            filters,
         } => {
-            handle_mywork_command(&client, limit, json, use_color, is_interactive).await?;
+            handle_tui_command(
+                &client,
+                page_limit(limit, all),
+                IssueFilters::from(filters),
+                use_color,
+                is_interactive,
+            )
+            .await?;
         }
         Commands::Search {
             query,
@@ -1947,6 +4842,8 @@ async fn run_async_commands(
             projects_only,
             limit,
             json,
+            format,
+            template: _,
             pretty,
             include_archived,
         } => {
@@ -1957,7 +4854,7 @@ async fn run_async_commands(
                 docs_only,
                 projects_only,
                 limit,
-                json,
+                output_format(json, format),
                 pretty,
                 include_archived,
                 use_color,
@@ -1965,6 +4862,13 @@ async fn run_async_commands(
             )
             .await?;
         }
+        Commands::Api {
+            query,
+            fields,
+            paginate,
+        } => {
+            handle_api_command(&client, query, fields, paginate, use_color, is_interactive).await?;
+        }
         Commands::Completions { .. } => {
             // This should never be reached because completions are handled synchronously above
             unreachable!("Completions command should be handled synchronously")
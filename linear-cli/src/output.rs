@@ -1,19 +1,30 @@
 // ABOUTME: This module handles output formatting for the Linear CLI
 // ABOUTME: It provides different formatters like table formatting with color support
 
-use linear_sdk::{DetailedIssue, Issue, Result};
+use linear_sdk::{
+    Cycle, CycleDetail, CycleIssue, DetailedIssue, Issue, IssueNode, IssueState, IssueWithComments,
+    LinearError, MyWork, Project, ProjectDetail, ProjectMilestone, ProjectUpdate, Result,
+    SearchResult, Team,
+};
 use owo_colors::OwoColorize;
 use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde_json::{json, Value};
 use std::io::Write;
 use std::sync::OnceLock;
 use syntect::easy::HighlightLines;
 use syntect::highlighting::ThemeSet;
 use syntect::parsing::SyntaxSet;
 use syntect::util::as_24_bit_terminal_escaped;
-use tabled::settings::Style;
-use tabled::{Table, Tabled};
+use tabled::builder::Builder;
+use tabled::grid::util::string::get_text_width;
+use tabled::settings::object::{Columns, Rows};
+use tabled::settings::{Remove, Style, Width};
+use tabled::Table;
 
+use crate::columns::{IssueColumn, DEFAULT_ISSUE_COLUMNS};
 use crate::constants;
+use crate::formats::tagged;
+use crate::output_template::OutputTemplate;
 use crate::types::IssueStatus;
 
 static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
@@ -35,11 +46,71 @@ pub trait OutputFormat {
         issue: &DetailedIssue,
         is_interactive: bool,
     ) -> Result<String>;
+    fn format_projects(&self, projects: &[Project]) -> Result<String>;
+    fn format_teams(&self, teams: &[Team]) -> Result<String>;
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String>;
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String>;
+    fn format_search_results(&self, results: &SearchResult) -> Result<String>;
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String>;
+    /// A cycle with its issues; line-oriented formats print one line per issue
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String>;
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String>;
+}
+
+/// "active", "completed", or "upcoming"
+pub fn cycle_status(cycle: &Cycle) -> &'static str {
+    if cycle.is_active {
+        "active"
+    } else if cycle.completed_at.is_some() {
+        "completed"
+    } else {
+        "upcoming"
+    }
+}
+
+/// Group issues by state, in workflow order: started work first, finished work last
+pub fn group_by_state<'a, T>(
+    issues: &'a [T],
+    state: impl Fn(&'a T) -> &'a IssueState,
+) -> Vec<(&'a str, Vec<&'a T>)> {
+    let rank = |state_type: &str| match state_type {
+        "started" => 0,
+        "unstarted" => 1,
+        "backlog" => 2,
+        "triage" => 3,
+        "completed" => 4,
+        "canceled" => 5,
+        _ => 6,
+    };
+
+    let mut groups: Vec<(&str, &str, Vec<&T>)> = Vec::new();
+    for issue in issues {
+        let state = state(issue);
+        match groups.iter_mut().find(|(name, _, _)| *name == state.name) {
+            Some((_, _, group)) => group.push(issue),
+            None => groups.push((&state.name, &state.type_, vec![issue])),
+        }
+    }
+    groups.sort_by_key(|(_, state_type, _)| rank(state_type));
+    groups
+        .into_iter()
+        .map(|(name, _, issues)| (name, issues))
+        .collect()
+}
+
+/// What a run of glyphs in a `linear report` chart measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartSegment {
+    Remaining,
+    Completed,
+    /// The even burndown line, or the unfinished part of a cycle's scope
+    Ideal,
 }
 
 pub struct TableFormatter {
     use_color: bool,
     is_interactive: bool,
+    columns: Vec<IssueColumn>,
 }
 
 impl TableFormatter {
@@ -48,6 +119,7 @@ impl TableFormatter {
         Self {
             use_color,
             is_interactive: use_color,
+            columns: DEFAULT_ISSUE_COLUMNS.to_vec(),
         }
     }
 
@@ -55,9 +127,16 @@ impl TableFormatter {
         Self {
             use_color,
             is_interactive,
+            columns: DEFAULT_ISSUE_COLUMNS.to_vec(),
         }
     }
 
+    /// Choose which columns issue tables show, in order
+    pub fn with_columns(mut self, columns: Vec<IssueColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
     fn truncate_title(title: &str, max_len: usize) -> String {
         if title.chars().count() <= max_len {
             title.to_string()
@@ -468,21 +547,23 @@ impl JsonFormatter {
     }
 }
 
-impl OutputFormat for JsonFormatter {
-    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+impl JsonFormatter {
+    fn to_json<T: serde::Serialize + ?Sized>(&self, value: &T) -> Result<String> {
         if self.pretty {
-            Ok(serde_json::to_string_pretty(issues)?)
+            Ok(serde_json::to_string_pretty(value)?)
         } else {
-            Ok(serde_json::to_string(issues)?)
+            Ok(serde_json::to_string(value)?)
         }
     }
+}
+
+impl OutputFormat for JsonFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        self.to_json(issues)
+    }
 
     fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
-        if self.pretty {
-            Ok(serde_json::to_string_pretty(issue)?)
-        } else {
-            Ok(serde_json::to_string(issue)?)
-        }
+        self.to_json(issue)
     }
 
     fn format_detailed_issue_rich(
@@ -493,21 +574,394 @@ impl OutputFormat for JsonFormatter {
         // JSON format doesn't change based on interactivity
         self.format_detailed_issue(issue)
     }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        self.to_json(projects)
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        self.to_json(teams)
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        self.to_json(issue)
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        self.to_json(my_work)
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        self.to_json(results)
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        self.to_json(cycles)
+    }
+
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String> {
+        self.to_json(cycle)
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        self.to_json(milestones)
+    }
+}
+
+/// Renders a user-defined template once per item, one item per line
+pub struct TemplateFormatter {
+    template: OutputTemplate,
+    use_color: bool,
 }
 
-#[derive(Tabled)]
-struct TableRow {
-    #[tabled(rename = "Issue")]
-    issue: String,
-    #[tabled(rename = "Title")]
-    title: String,
-    #[tabled(rename = "Status")]
-    status: String,
-    #[tabled(rename = "Assignee")]
-    assignee: String,
+impl TemplateFormatter {
+    pub fn new(source: &str, use_color: bool) -> Result<Self> {
+        let template =
+            OutputTemplate::parse(source).map_err(|message| LinearError::InvalidInput {
+                message: format!("Invalid output template: {message}"),
+            })?;
+        Ok(Self {
+            template,
+            use_color,
+        })
+    }
+
+    fn render_all(&self, items: impl IntoIterator<Item = Value>) -> Result<String> {
+        Ok(items
+            .into_iter()
+            .map(|item| self.template.render(&item, self.use_color))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    fn render_serialized<T: serde::Serialize>(&self, items: &[T]) -> Result<String> {
+        let values = items
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        self.render_all(values)
+    }
+
+    /// Template fields for an issue; related records become objects so both `{{assignee}}`
+    /// and `{{assignee.name}}` work
+    fn issue_context(issue: &Issue) -> Result<Value> {
+        let mut value = serde_json::to_value(issue)?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert(
+                "state".to_string(),
+                json!({ "id": issue.state_id, "name": issue.status }),
+            );
+            object.insert(
+                "assignee".to_string(),
+                match &issue.assignee {
+                    Some(name) => json!({ "id": issue.assignee_id, "name": name }),
+                    None => Value::Null,
+                },
+            );
+            object.insert(
+                "team".to_string(),
+                json!({ "id": issue.team_id, "key": issue.team }),
+            );
+            object.insert(
+                "project".to_string(),
+                match &issue.project {
+                    Some(name) => json!({ "name": name }),
+                    None => Value::Null,
+                },
+            );
+        }
+        Ok(value)
+    }
+
+    fn tagged_issue_context(issue: &Issue, key: &str, tag: &str) -> Result<Value> {
+        let mut value = Self::issue_context(issue)?;
+        if let Some(object) = value.as_object_mut() {
+            object.insert(key.to_string(), tag.into());
+        }
+        Ok(value)
+    }
+}
+
+impl OutputFormat for TemplateFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        let contexts = issues
+            .iter()
+            .map(Self::issue_context)
+            .collect::<Result<Vec<_>>>()?;
+        self.render_all(contexts)
+    }
+
+    fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
+        self.render_serialized(std::slice::from_ref(issue))
+    }
+
+    fn format_detailed_issue_rich(
+        &self,
+        issue: &DetailedIssue,
+        _is_interactive: bool,
+    ) -> Result<String> {
+        self.format_detailed_issue(issue)
+    }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        self.render_serialized(projects)
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        self.render_serialized(teams)
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        let comments = issue
+            .comments
+            .iter()
+            .map(|comment| tagged(comment, "issue", &issue.identifier))
+            .collect::<Result<Vec<_>>>()?;
+        self.render_all(comments)
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        let assigned = my_work
+            .assigned_issues
+            .iter()
+            .map(|issue| Self::tagged_issue_context(issue, "category", "assigned"));
+        let created = my_work
+            .created_issues
+            .iter()
+            .map(|issue| Self::tagged_issue_context(issue, "category", "created"));
+        self.render_all(assigned.chain(created).collect::<Result<Vec<_>>>()?)
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        let issues = results
+            .issues
+            .iter()
+            .map(|issue| Self::tagged_issue_context(issue, "type", "issue"));
+        let documents = results
+            .documents
+            .iter()
+            .map(|doc| tagged(doc, "type", "document"));
+        let projects = results
+            .projects
+            .iter()
+            .map(|project| tagged(project, "type", "project"));
+        self.render_all(
+            issues
+                .chain(documents)
+                .chain(projects)
+                .collect::<Result<Vec<_>>>()?,
+        )
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        self.render_serialized(cycles)
+    }
+
+    fn format_cycle(&self, cycle: &CycleDetail) -> Result<String> {
+        self.render_serialized(&cycle.issues)
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        self.render_serialized(milestones)
+    }
 }
 
 impl TableFormatter {
+    fn issue_cell(&self, issue: &Issue, column: IssueColumn) -> String {
+        match column {
+            IssueColumn::Identifier => issue.identifier.clone(),
+            IssueColumn::Title => Self::truncate_title(&issue.title, 40),
+            IssueColumn::State => self.format_status(&issue.status),
+            IssueColumn::Assignee => self.format_assignee(&issue.assignee),
+            IssueColumn::Priority => match issue.priority {
+                Some(0) | None => self.format_missing(),
+                priority => self.format_priority(priority, &issue.priority_label),
+            },
+            IssueColumn::Estimate => match issue.estimate {
+                Some(estimate) => estimate.to_string(),
+                None => self.format_missing(),
+            },
+            IssueColumn::Labels if issue.labels.is_empty() => self.format_missing(),
+            IssueColumn::Labels => issue
+                .labels
+                .iter()
+                .map(|label| label.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            IssueColumn::Cycle => match &issue.cycle {
+                Some(cycle) => cycle
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("Cycle {}", cycle.number)),
+                None => self.format_missing(),
+            },
+            IssueColumn::Project => issue
+                .project
+                .clone()
+                .unwrap_or_else(|| self.format_missing()),
+            IssueColumn::Team => issue.team.clone().unwrap_or_else(|| self.format_missing()),
+            IssueColumn::Due => match &issue.due_date {
+                Some(due_date) => self.format_due_date(due_date, &issue.status),
+                None => self.format_missing(),
+            },
+            IssueColumn::Created => match &issue.created_at {
+                Some(created_at) => self.format_datetime(created_at),
+                None => self.format_missing(),
+            },
+            IssueColumn::Updated => match &issue.updated_at {
+                Some(updated_at) => self.format_datetime(updated_at),
+                None => self.format_missing(),
+            },
+        }
+    }
+
+    /// Show a due date, in red with an "overdue" marker when it has passed on unfinished work
+    fn format_due_date(&self, due_date: &str, status: &str) -> String {
+        let finished = matches!(
+            IssueStatus::from(status),
+            IssueStatus::Done | IssueStatus::Canceled
+        );
+        if finished || !crate::dates::is_overdue(due_date) {
+            return due_date.to_string();
+        }
+
+        let text = format!("{due_date} (overdue)");
+        if self.use_color {
+            text.red().bold().to_string()
+        } else {
+            text
+        }
+    }
+
+    fn format_missing(&self) -> String {
+        if self.use_color {
+            "-".dimmed().to_string()
+        } else {
+            "-".to_string()
+        }
+    }
+
+    /// Style a report title the way detail views style theirs
+    pub fn format_heading(&self, text: &str) -> String {
+        if self.use_color {
+            text.bold().blue().to_string()
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Color chart glyphs by what they measure, matching in-progress and done states
+    pub fn format_chart_segment(&self, text: &str, segment: ChartSegment) -> String {
+        if !self.use_color {
+            return text.to_string();
+        }
+        match segment {
+            ChartSegment::Remaining => text.yellow().to_string(),
+            ChartSegment::Completed => text.green().to_string(),
+            ChartSegment::Ideal => text.dimmed().to_string(),
+        }
+    }
+
+    fn issues_table(&self, issues: &[Issue]) -> Table {
+        let mut builder = Builder::default();
+        builder.push_record(self.columns.iter().map(|column| column.header()));
+        for issue in issues {
+            builder.push_record(
+                self.columns
+                    .iter()
+                    .map(|column| self.issue_cell(issue, *column)),
+            );
+        }
+
+        let mut table = builder.build();
+        if self.is_interactive {
+            table.with(Style::psql());
+        } else {
+            // Use minimal style for piped output
+            table.with(Style::blank());
+        }
+        table
+    }
+
+    /// Width of each column's widest cell, header included, so later pages can line up
+    pub fn issue_column_widths(&self, issues: &[Issue]) -> Vec<usize> {
+        self.columns
+            .iter()
+            .map(|column| {
+                issues
+                    .iter()
+                    .map(|issue| get_text_width(&self.issue_cell(issue, *column)))
+                    .fold(column.header().len(), usize::max)
+            })
+            .collect()
+    }
+
+    /// Format a later page of streamed issues without repeating the header row, padding each
+    /// column to at least the width it had on the first page
+    pub fn format_issues_continuation(&self, issues: &[Issue], widths: &[usize]) -> Result<String> {
+        let mut table = self.issues_table(issues);
+        table.with(Remove::row(Rows::first()));
+        for (index, &width) in widths.iter().enumerate() {
+            table.modify(Columns::one(index), Width::increase(width));
+        }
+        Ok(table.to_string())
+    }
+
+    fn format_cycle_status(&self, cycle: &Cycle) -> String {
+        let status = cycle_status(cycle);
+        if !self.use_color {
+            return status.to_string();
+        }
+        match status {
+            "active" => status.green().bold().to_string(),
+            "completed" => status.dimmed().to_string(),
+            _ => status.blue().to_string(),
+        }
+    }
+
+    fn format_milestone_status(&self, status: &str) -> String {
+        if !self.use_color {
+            return status.to_string();
+        }
+        match status {
+            "done" => status.green().to_string(),
+            "overdue" => status.red().bold().to_string(),
+            "next" => status.blue().to_string(),
+            _ => status.dimmed().to_string(),
+        }
+    }
+
+    fn format_cycle_dates(&self, cycle: &Cycle) -> String {
+        let date = |timestamp: &str| match chrono::DateTime::parse_from_rfc3339(timestamp) {
+            Ok(parsed) => parsed.format("%Y-%m-%d").to_string(),
+            Err(_) => timestamp.to_string(),
+        };
+        format!("{} → {}", date(&cycle.starts_at), date(&cycle.ends_at))
+    }
+
+    fn format_cycle_title(&self, cycle: &Cycle) -> String {
+        let number = format!("Cycle {}", cycle.number);
+        match (&cycle.name, self.use_color) {
+            (Some(name), true) => format!("{}: {}", number.bold().blue(), name.bold()),
+            (Some(name), false) => format!("{number}: {name}"),
+            (None, true) => number.bold().blue().to_string(),
+            (None, false) => number,
+        }
+    }
+
+    fn format_cycle_issue(&self, issue: &CycleIssue) -> String {
+        let identifier = if self.use_color {
+            issue.identifier.bold().to_string()
+        } else {
+            issue.identifier.clone()
+        };
+        let assignee = self.format_assignee(&issue.assignee);
+        match issue.estimate {
+            Some(points) => format!("{identifier} {} ({points} pts, {assignee})", issue.title),
+            None => format!("{identifier} {} ({assignee})", issue.title),
+        }
+    }
+
     /// Core implementation for formatting detailed issues.
     /// When `render_markdown` is true, descriptions are rendered as terminal markdown.
     fn format_detailed_issue_impl(
@@ -537,12 +991,26 @@ impl TableFormatter {
         if let Some(project) = &issue.project {
             output.push(format!("Project:    {}", project.name));
         }
+        if let Some(milestone) = &issue.milestone {
+            output.push(format!("Milestone:  {milestone}"));
+        }
+
+        if let Some(parent) = &issue.parent {
+            output.push(format!("Parent:     {}", self.format_issue_node(parent)));
+        }
 
         output.push(format!(
             "Priority:   {}",
             self.format_priority(issue.priority, &issue.priority_label)
         ));
 
+        if let Some(due_date) = &issue.due_date {
+            output.push(format!(
+                "Due:        {}",
+                self.format_due_date(due_date, &issue.state.name)
+            ));
+        }
+
         if let Some(description) = &issue.description {
             output.push(String::new());
             if self.use_color {
@@ -562,6 +1030,19 @@ impl TableFormatter {
             }
         }
 
+        if !issue.children.is_empty() {
+            output.push(String::new());
+            let heading = format!("Sub-issues ({}):", issue.children.len());
+            if self.use_color {
+                output.push(format!("{}{}", "🌳 ".white(), heading.cyan()));
+            } else {
+                output.push(format!("🌳 {heading}"));
+            }
+            for child in &issue.children {
+                output.push(format!("   {}", self.format_issue_node(child)));
+            }
+        }
+
         // Metadata section
         output.push(String::new());
         if self.use_color {
@@ -608,28 +1089,266 @@ impl TableFormatter {
 
         Ok(output.join("\n"))
     }
-}
 
-impl OutputFormat for TableFormatter {
-    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
-        let rows: Vec<TableRow> = issues
+    /// One-line summary of an issue in a hierarchy: identifier, title, and status
+    fn format_issue_node(&self, node: &IssueNode) -> String {
+        let identifier = if self.use_color {
+            node.identifier.bold().to_string()
+        } else {
+            node.identifier.clone()
+        };
+        format!(
+            "{identifier} {} [{}]",
+            node.title,
+            self.format_status(&node.state.name)
+        )
+    }
+
+    /// Format an issue and its sub-issues as an indented tree
+    pub fn format_issue_tree(&self, root: &IssueNode) -> String {
+        let mut lines = vec![self.format_issue_node(root)];
+        self.push_tree_children(&root.children, "", &mut lines);
+        lines.join("\n")
+    }
+
+    fn push_tree_children(&self, children: &[IssueNode], prefix: &str, lines: &mut Vec<String>) {
+        for (index, child) in children.iter().enumerate() {
+            let is_last = index == children.len() - 1;
+            let (branch, indent) = if is_last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            lines.push(format!("{prefix}{branch}{}", self.format_issue_node(child)));
+            self.push_tree_children(&child.children, &format!("{prefix}{indent}"), lines);
+        }
+    }
+
+    fn format_project_health(&self, health: &str) -> String {
+        let text = match health {
+            "onTrack" => "on track",
+            "atRisk" => "at risk",
+            "offTrack" => "off track",
+            other => other,
+        };
+        if !self.use_color {
+            return text.to_string();
+        }
+        match health {
+            "onTrack" => text.green().to_string(),
+            "atRisk" => text.yellow().to_string(),
+            "offTrack" => text.red().to_string(),
+            _ => text.to_string(),
+        }
+    }
+
+    /// Format project updates with their date, author, and health above each markdown body.
+    /// When `render_markdown` is true, bodies are rendered as terminal markdown.
+    pub fn format_project_updates(
+        &self,
+        updates: &[ProjectUpdate],
+        render_markdown: bool,
+    ) -> String {
+        if updates.is_empty() {
+            return "No updates posted on this project.".to_string();
+        }
+
+        let border_line = "─".repeat(constants::ui::BORDER_LINE_LENGTH);
+        let mut output = Vec::new();
+        for (index, update) in updates.iter().enumerate() {
+            if index > 0 {
+                output.push(String::new());
+                output.push(border_line.clone());
+            }
+            let date = self.format_datetime(&update.created_at);
+            let header = if self.use_color {
+                format!(
+                    "{} · {} · {}",
+                    date.bold(),
+                    update.author.cyan(),
+                    self.format_project_health(&update.health)
+                )
+            } else {
+                format!(
+                    "{date} · {} · {}",
+                    update.author,
+                    self.format_project_health(&update.health)
+                )
+            };
+            output.push(header);
+            output.push(String::new());
+
+            if render_markdown {
+                // Render markdown to terminal, falling back to raw on error
+                match self.render_markdown_to_terminal(&update.body) {
+                    Ok(rendered) => output.push(rendered.trim_end().to_string()),
+                    Err(_) => output.push(update.body.clone()),
+                }
+            } else {
+                output.push(update.body.trim_end().to_string());
+            }
+        }
+
+        output.join("\n")
+    }
+
+    /// Format a project's details, description, milestones, and issues grouped by state.
+    /// When `render_markdown` is true, the description is rendered as terminal markdown.
+    pub fn format_project_detail(&self, project: &ProjectDetail, render_markdown: bool) -> String {
+        let border_line = "─".repeat(constants::ui::BORDER_LINE_LENGTH);
+        let title_line = if self.use_color {
+            project.name.bold().blue().to_string()
+        } else {
+            project.name.clone()
+        };
+
+        let status = match &project.health {
+            Some(health) => format!(
+                "{} ({})",
+                project.status.name,
+                self.format_project_health(health)
+            ),
+            None => project.status.name.clone(),
+        };
+        let lead = match &project.lead {
+            Some(lead) => lead.name.clone(),
+            None => self.format_missing(),
+        };
+        let members = if project.members.is_empty() {
+            self.format_missing()
+        } else {
+            project
+                .members
+                .iter()
+                .map(|member| member.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let teams = project
+            .teams
             .iter()
-            .map(|issue| TableRow {
-                issue: issue.identifier.clone(),
-                title: Self::truncate_title(&issue.title, 40),
-                status: self.format_status(&issue.status),
-                assignee: self.format_assignee(&issue.assignee),
+            .map(|team| {
+                if self.use_color {
+                    format!("{} ({})", team.name.cyan(), team.key.dimmed())
+                } else {
+                    format!("{} ({})", team.name, team.key)
+                }
             })
-            .collect();
+            .collect::<Vec<_>>()
+            .join(", ");
+        let completed = project
+            .issues
+            .iter()
+            .filter(|issue| issue.state.type_ == "completed")
+            .count();
 
-        let mut table = Table::new(rows);
-        if self.is_interactive {
-            table.with(Style::psql());
+        let mut output = vec![
+            border_line.clone(),
+            title_line,
+            border_line,
+            format!("Status:     {status}"),
+            format!("Lead:       {lead}"),
+            format!("Members:    {members}"),
+            format!("Teams:      {teams}"),
+        ];
+        if let Some(start_date) = &project.start_date {
+            output.push(format!("Start:      {start_date}"));
+        }
+        output.push(format!(
+            "Target:     {}",
+            project
+                .target_date
+                .clone()
+                .unwrap_or_else(|| self.format_missing())
+        ));
+        output.push(format!(
+            "Progress:   {:.0}% ({completed} of {} issues done)",
+            project.progress * 100.0,
+            project.issues.len()
+        ));
+
+        if let Some(description) = project.content.as_ref().or(project.description.as_ref()) {
+            output.push(String::new());
+            if self.use_color {
+                output.push(format!("{}{}", "📝 ".white(), "Description:".cyan()));
+            } else {
+                output.push("📝 Description:".to_string());
+            }
+
+            if render_markdown {
+                // Render markdown to terminal, falling back to raw on error
+                match self.render_markdown_to_terminal(description) {
+                    Ok(rendered) => output.push(rendered),
+                    Err(_) => output.push(description.clone()),
+                }
+            } else {
+                output.push(description.clone());
+            }
+        }
+
+        if !project.milestones.is_empty() {
+            output.push(String::new());
+            let heading = format!("Milestones ({}):", project.milestones.len());
+            if self.use_color {
+                output.push(format!("{}{}", "🎯 ".white(), heading.cyan()));
+            } else {
+                output.push(format!("🎯 {heading}"));
+            }
+            for milestone in &project.milestones {
+                let mut line = format!(
+                    "   {} {:.0}% {}",
+                    milestone.name,
+                    milestone.progress * 100.0,
+                    self.format_milestone_status(&milestone.status)
+                );
+                if let Some(target_date) = &milestone.target_date {
+                    line.push_str(&format!(" (target {target_date})"));
+                }
+                output.push(line);
+            }
+        }
+
+        if project.issues.is_empty() {
+            output.push(String::new());
+            output.push("No issues in this project.".to_string());
+        }
+        for (state, issues) in group_by_state(&project.issues, |issue| &issue.state) {
+            output.push(String::new());
+            output.push(format!("{} ({}):", self.format_status(state), issues.len()));
+            for issue in issues {
+                let identifier = if self.use_color {
+                    issue.identifier.bold().to_string()
+                } else {
+                    issue.identifier.clone()
+                };
+                output.push(format!(
+                    "   {identifier} {} ({})",
+                    issue.title,
+                    self.format_assignee(&issue.assignee)
+                ));
+            }
+        }
+
+        output.push(String::new());
+        let supports_osc8 = Self::supports_osc8();
+        if self.use_color {
+            output.push(format!(
+                "{}{}\n   {}",
+                "🔗 ".white(),
+                "View in Linear:".cyan(),
+                self.format_hyperlink(&project.url, &project.url, supports_osc8)
+            ));
         } else {
-            // Use minimal style for piped output
-            table.with(Style::blank());
+            output.push(format!("🔗 View in Linear:\n   {}", project.url));
         }
-        Ok(table.to_string())
+
+        output.join("\n")
+    }
+}
+
+impl OutputFormat for TableFormatter {
+    fn format_issues(&self, issues: &[Issue]) -> Result<String> {
+        Ok(self.issues_table(issues).to_string())
     }
 
     fn format_detailed_issue(&self, issue: &DetailedIssue) -> Result<String> {
@@ -644,6 +1363,195 @@ impl OutputFormat for TableFormatter {
         // Only render markdown in interactive mode
         self.format_detailed_issue_impl(issue, is_interactive)
     }
+
+    fn format_projects(&self, projects: &[Project]) -> Result<String> {
+        Ok(projects
+            .iter()
+            .map(|p| format!("{}: {} ({})", p.id, p.name, p.state))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    fn format_teams(&self, teams: &[Team]) -> Result<String> {
+        Ok(teams
+            .iter()
+            .map(|t| format!("{}: {} ({} members)", t.key, t.name, t.members.len()))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    fn format_comments(&self, issue: &IssueWithComments) -> Result<String> {
+        Ok(format!(
+            "Issue: {} - {}\n\nComments:\n{}",
+            issue.identifier,
+            issue.title,
+            issue
+                .comments
+                .iter()
+                .map(|c| format!("{}: {}", c.user.name, c.body))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ))
+    }
+
+    fn format_my_work(&self, my_work: &MyWork) -> Result<String> {
+        let summary = |issues: &[Issue]| {
+            issues
+                .iter()
+                .map(|i| match &i.due_date {
+                    Some(due_date) => format!(
+                        "{}: {} [due {}]",
+                        i.identifier,
+                        i.title,
+                        self.format_due_date(due_date, &i.status)
+                    ),
+                    None => format!("{}: {}", i.identifier, i.title),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        Ok(format!(
+            "Assigned to you:\n{}\n\nCreated by you:\n{}",
+            summary(&my_work.assigned_issues),
+            summary(&my_work.created_issues)
+        ))
+    }
+
+    fn format_search_results(&self, results: &SearchResult) -> Result<String> {
+        let mut sections = Vec::new();
+
+        if !results.issues.is_empty() {
+            let lines: Vec<String> = results
+                .issues
+                .iter()
+                .map(|issue| format!("  {}: {}", issue.identifier, issue.title))
+                .collect();
+            sections.push(format!("Issues:\n{}", lines.join("\n")));
+        }
+
+        if !results.documents.is_empty() {
+            let lines: Vec<String> = results
+                .documents
+                .iter()
+                .map(|doc| format!("  {}: {}", doc.title, doc.url))
+                .collect();
+            sections.push(format!("Documents:\n{}", lines.join("\n")));
+        }
+
+        if !results.projects.is_empty() {
+            let lines: Vec<String> = results
+                .projects
+                .iter()
+                .map(|project| format!("  {}: {}", project.name, project.url))
+                .collect();
+            sections.push(format!("Projects:\n{}", lines.join("\n")));
+        }
+
+        Ok(sections.join("\n\n"))
+    }
+
+    fn format_cycles(&self, cycles: &[Cycle]) -> Result<String> {
+        let mut builder = Builder::default();
+        builder.push_record([
+            "Cycle",
+            "Name",
+            "Dates",
+            "Status",
+            "Scope",
+            "Started",
+            "Completed",
+            "Progress",
+        ]);
+        for cycle in cycles {
+            builder.push_record([
+                format!("#{}", cycle.number),
+                cycle.name.clone().unwrap_or_else(|| self.format_missing()),
+                self.format_cycle_dates(cycle),
+                self.format_cycle_status(cycle),
+                cycle.scope.to_string(),
+                cycle.started_scope.to_string(),
+                cycle.completed_scope.to_string(),
+                format!("{:.0}%", cycle.progress * 100.0),
+            ]);
+        }
+
+        let mut table = builder.build();
+        if self.is_interactive {
+            table.with(Style::psql());
+        } else {
+            table.with(Style::blank());
+        }
+        Ok(table.to_string())
+    }
+
+    fn format_cycle(&self, detail: &CycleDetail) -> Result<String> {
+        let cycle = &detail.cycle;
+        let border_line = "─".repeat(constants::ui::BORDER_LINE_LENGTH);
+        let mut output = vec![
+            border_line.clone(),
+            self.format_cycle_title(cycle),
+            border_line,
+            format!(
+                "Team:       {}",
+                self.format_team(&Some(detail.team.clone()))
+            ),
+            format!(
+                "Dates:      {} ({})",
+                self.format_cycle_dates(cycle),
+                self.format_cycle_status(cycle)
+            ),
+            format!(
+                "Scope:      {} points, {} issues",
+                cycle.scope, cycle.issue_count
+            ),
+            format!("Started:    {} points", cycle.started_scope),
+            format!(
+                "Completed:  {} points, {} issues ({:.0}%)",
+                cycle.completed_scope,
+                cycle.completed_issue_count,
+                cycle.progress * 100.0
+            ),
+        ];
+
+        if detail.issues.is_empty() {
+            output.push(String::new());
+            output.push("No issues in this cycle.".to_string());
+        }
+        for (state, issues) in group_by_state(&detail.issues, |issue| &issue.state) {
+            output.push(String::new());
+            output.push(format!("{} ({}):", self.format_status(state), issues.len()));
+            for issue in issues {
+                output.push(format!("   {}", self.format_cycle_issue(issue)));
+            }
+        }
+
+        Ok(output.join("\n"))
+    }
+
+    fn format_milestones(&self, milestones: &[ProjectMilestone]) -> Result<String> {
+        let mut builder = Builder::default();
+        builder.push_record(["Milestone", "Target", "Status", "Progress"]);
+        for milestone in milestones {
+            builder.push_record([
+                milestone.name.clone(),
+                milestone
+                    .target_date
+                    .clone()
+                    .unwrap_or_else(|| self.format_missing()),
+                self.format_milestone_status(&milestone.status),
+                format!("{:.0}%", milestone.progress * 100.0),
+            ]);
+        }
+
+        let mut table = builder.build();
+        if self.is_interactive {
+            table.with(Style::psql());
+        } else {
+            table.with(Style::blank());
+        }
+        Ok(table.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -667,6 +1575,7 @@ mod tests {
             assignee_id: assignee.map(|_| format!("user-{identifier}")),
             team: Some("TEST".to_string()),
             team_id: format!("team-{identifier}"),
+            ..Default::default()
         }
     }
 
@@ -725,6 +1634,129 @@ mod tests {
         assert!(result.contains("Assignee"));
     }
 
+    #[test]
+    fn test_format_issues_continuation_omits_header() {
+        let formatter = TableFormatter::new_with_interactive(false, false);
+        let issues = vec![create_test_issue("ENG-126", "Next page", "Todo", None)];
+        let widths = formatter.issue_column_widths(&issues);
+
+        let result = formatter
+            .format_issues_continuation(&issues, &widths)
+            .unwrap();
+
+        assert!(result.contains("ENG-126"));
+        assert!(!result.contains("Assignee"));
+        assert_eq!(result.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_format_issues_continuation_keeps_first_page_widths() {
+        let formatter = TableFormatter::new_with_interactive(false, false);
+        let first = vec![create_test_issue("ENG-1", "First page title", "Todo", None)];
+        let widths = formatter.issue_column_widths(&first);
+        let header = formatter.format_issues(&first).unwrap();
+        let width = header.lines().next().unwrap().len();
+
+        let short = vec![create_test_issue("ENG-2", "Short", "Todo", None)];
+        let result = formatter
+            .format_issues_continuation(&short, &widths)
+            .unwrap();
+        assert_eq!(result.lines().next().unwrap().len(), width);
+
+        // Wider values are never cut to fit the first page
+        let long = vec![create_test_issue(
+            "ENG-1000",
+            "A much longer title on a later page",
+            "In Progress",
+            Some("Alexandria Longname".to_string()),
+        )];
+        let result = formatter
+            .format_issues_continuation(&long, &widths)
+            .unwrap();
+        assert!(result.contains("ENG-1000"));
+        assert!(result.contains("In Progress"));
+        assert!(result.contains("Alexandria Longname"));
+        assert!(!result.contains("..."));
+    }
+
+    #[test]
+    fn test_table_formatter_custom_columns() {
+        let formatter = TableFormatter::new_with_interactive(false, false).with_columns(vec![
+            IssueColumn::Identifier,
+            IssueColumn::Priority,
+            IssueColumn::Labels,
+            IssueColumn::Cycle,
+            IssueColumn::Due,
+        ]);
+        let mut issue = create_test_issue("ENG-127", "Columns", "Todo", None);
+        issue.priority = Some(2);
+        issue.priority_label = Some("High".to_string());
+        issue.labels = vec![linear_sdk::IssueLabel {
+            name: "bug".to_string(),
+            color: "#ff0000".to_string(),
+        }];
+        issue.cycle = Some(linear_sdk::IssueCycle {
+            number: 12,
+            name: None,
+        });
+
+        let result = formatter.format_issues(&[issue]).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(
+            lines[0].split_whitespace().collect::<Vec<_>>(),
+            vec!["Issue", "Priority", "Labels", "Cycle", "Due"]
+        );
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<_>>(),
+            vec!["ENG-127", "High", "bug", "Cycle", "12", "-"]
+        );
+        assert!(!result.contains("Title"));
+    }
+
+    #[test]
+    fn test_due_column_marks_overdue_open_issues() {
+        let formatter = TableFormatter::new_with_interactive(false, false)
+            .with_columns(vec![IssueColumn::Identifier, IssueColumn::Due]);
+        let mut late = create_test_issue("ENG-1", "Late", "Todo", None);
+        late.due_date = Some("2020-01-01".to_string());
+        let mut finished = create_test_issue("ENG-2", "Finished", "Done", None);
+        finished.due_date = Some("2020-01-01".to_string());
+        let mut upcoming = create_test_issue("ENG-3", "Upcoming", "Todo", None);
+        upcoming.due_date = Some("2999-01-01".to_string());
+
+        let result = formatter
+            .format_issues(&[late, finished, upcoming])
+            .unwrap();
+        let lines: Vec<&str> = result.lines().map(str::trim).collect();
+
+        assert!(lines[1].ends_with("2020-01-01 (overdue)"));
+        assert!(lines[2].ends_with("2020-01-01"));
+        assert!(lines[3].ends_with("2999-01-01"));
+    }
+
+    #[test]
+    fn test_template_formatter_issue_fields() {
+        let formatter = TemplateFormatter::new(
+            r"{{identifier}}\t{{state.name}}\t{{assignee.name | default -}}\t{{team}}",
+            false,
+        )
+        .unwrap();
+        let issues = vec![
+            create_test_issue("ENG-1", "First", "Todo", Some("Alice".to_string())),
+            create_test_issue("ENG-2", "Second", "Done", None),
+        ];
+
+        let result = formatter.format_issues(&issues).unwrap();
+
+        assert_eq!(result, "ENG-1\tTodo\tAlice\tTEST\nENG-2\tDone\t-\tTEST");
+    }
+
+    #[test]
+    fn test_template_formatter_rejects_invalid_template() {
+        assert!(TemplateFormatter::new("{{title | wobble}}", false).is_err());
+    }
+
     #[test]
     fn test_snapshot_colored_output() {
         let formatter = TableFormatter::new(true);
@@ -841,6 +1873,225 @@ mod tests {
         assert_eq!(first_issue["assignee"], "John Doe");
     }
 
+    fn create_test_cycle_detail() -> CycleDetail {
+        let issue =
+            |identifier: &str, state: &str, state_type: &str, estimate: Option<f64>| CycleIssue {
+                id: format!("id-{identifier}"),
+                identifier: identifier.to_string(),
+                title: format!("Work on {identifier}"),
+                estimate,
+                state: linear_sdk::IssueState {
+                    id: format!("state-{state}"),
+                    name: state.to_string(),
+                    type_: state_type.to_string(),
+                },
+                assignee: Some("Alice".to_string()),
+            };
+
+        CycleDetail {
+            cycle: Cycle {
+                id: "cycle-42".to_string(),
+                number: 42,
+                name: Some("Launch prep".to_string()),
+                starts_at: "2026-10-05T00:00:00.000Z".to_string(),
+                ends_at: "2026-10-19T00:00:00.000Z".to_string(),
+                completed_at: None,
+                is_active: true,
+                progress: 0.25,
+                scope: 20.0,
+                started_scope: 6.0,
+                completed_scope: 5.0,
+                issue_count: 3,
+                completed_issue_count: 1,
+            },
+            team: linear_sdk::IssueTeam {
+                id: "team-1".to_string(),
+                key: "ENG".to_string(),
+                name: "Engineering".to_string(),
+            },
+            issues: vec![
+                issue("ENG-1", "Done", "completed", Some(5.0)),
+                issue("ENG-2", "Todo", "unstarted", None),
+                issue("ENG-3", "In Progress", "started", Some(3.0)),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_cycle_detail_groups_issues_by_state() {
+        let formatter = TableFormatter::new(false);
+
+        let output = formatter.format_cycle(&create_test_cycle_detail()).unwrap();
+
+        assert!(output.contains("Cycle 42: Launch prep"));
+        assert!(output.contains("Dates:      2026-10-05 → 2026-10-19 (active)"));
+        assert!(output.contains("Scope:      20 points, 3 issues"));
+        assert!(output.contains("Completed:  5 points, 1 issues (25%)"));
+
+        let in_progress = output.find("In Progress (1):").unwrap();
+        let todo = output.find("Todo (1):").unwrap();
+        let done = output.find("Done (1):").unwrap();
+        assert!(in_progress < todo && todo < done);
+        assert!(output.contains("   ENG-3 Work on ENG-3 (3 pts, Alice)"));
+        assert!(output.contains("   ENG-2 Work on ENG-2 (Alice)"));
+    }
+
+    #[test]
+    fn test_cycles_table() {
+        let formatter = TableFormatter::new_with_interactive(false, false);
+        let mut upcoming = create_test_cycle_detail().cycle;
+        upcoming.number = 43;
+        upcoming.name = None;
+        upcoming.is_active = false;
+        let active = create_test_cycle_detail().cycle;
+
+        let output = formatter.format_cycles(&[upcoming, active]).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert!(lines[0].contains("Cycle") && lines[0].contains("Progress"));
+        assert!(lines[1].contains("#43") && lines[1].contains("upcoming"));
+        assert!(lines[2].contains("Launch prep") && lines[2].contains("active"));
+        assert!(lines[2].contains("25%"));
+    }
+
+    fn create_test_project_detail() -> ProjectDetail {
+        use linear_sdk::*;
+
+        let issue = |identifier: &str, state: &str, state_type: &str| IssueNode {
+            id: format!("id-{identifier}"),
+            identifier: identifier.to_string(),
+            title: format!("Work on {identifier}"),
+            state: IssueState {
+                id: format!("state-{state}"),
+                name: state.to_string(),
+                type_: state_type.to_string(),
+            },
+            assignee: None,
+            children: Vec::new(),
+        };
+        let alice = ProjectLead {
+            id: "user-1".to_string(),
+            name: "Alice Smith".to_string(),
+            display_name: "alice".to_string(),
+        };
+
+        ProjectDetail {
+            id: "proj-1".to_string(),
+            name: "Mobile App".to_string(),
+            description: Some("Native apps".to_string()),
+            content: Some("## Goals\n\nShip the beta.".to_string()),
+            status: ProjectStatus {
+                id: "status-started".to_string(),
+                name: "In Progress".to_string(),
+                type_: "started".to_string(),
+            },
+            progress: 0.5,
+            health: Some("atRisk".to_string()),
+            start_date: None,
+            target_date: Some("2026-12-31".to_string()),
+            url: "https://linear.app/project/mobile-app".to_string(),
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-06-01T00:00:00Z".to_string(),
+            lead: Some(alice.clone()),
+            members: vec![alice],
+            teams: vec![ProjectTeam {
+                id: "team-1".to_string(),
+                key: "ENG".to_string(),
+                name: "Engineering".to_string(),
+            }],
+            milestones: vec![
+                ProjectMilestone {
+                    id: "milestone-1".to_string(),
+                    name: "Beta".to_string(),
+                    description: None,
+                    target_date: Some("2026-09-01".to_string()),
+                    progress: 0.5,
+                    status: "next".to_string(),
+                },
+                ProjectMilestone {
+                    id: "milestone-2".to_string(),
+                    name: "GA".to_string(),
+                    description: None,
+                    target_date: None,
+                    progress: 0.0,
+                    status: "unstarted".to_string(),
+                },
+            ],
+            issues: vec![
+                issue("ENG-1", "Done", "completed"),
+                issue("ENG-2", "In Progress", "started"),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_project_detail() {
+        let formatter = TableFormatter::new(false);
+
+        let output = formatter.format_project_detail(&create_test_project_detail(), false);
+
+        assert!(output.contains("Status:     In Progress (at risk)"));
+        assert!(output.contains("Lead:       Alice Smith"));
+        assert!(output.contains("Teams:      Engineering (ENG)"));
+        assert!(output.contains("Target:     2026-12-31"));
+        assert!(output.contains("Progress:   50% (1 of 2 issues done)"));
+        // The markdown body wins over the one-line summary
+        assert!(output.contains("## Goals"));
+        assert!(!output.contains("Native apps"));
+        assert!(output.contains("   Beta 50% next (target 2026-09-01)"));
+        assert!(output.contains("   GA 0% unstarted\n"));
+
+        let in_progress = output.find("In Progress (1):").unwrap();
+        let done = output.find("Done (1):").unwrap();
+        assert!(in_progress < done);
+        assert!(output.contains("   ENG-2 Work on ENG-2 (Unassigned)"));
+    }
+
+    #[test]
+    fn test_format_milestones() {
+        let formatter = TableFormatter::new(false);
+        let detail = create_test_project_detail();
+
+        let output = formatter.format_milestones(&detail.milestones).unwrap();
+
+        assert!(output.contains("Milestone"));
+        assert!(output.contains("Beta"));
+        assert!(output.contains("2026-09-01"));
+        assert!(output.contains("50%"));
+        assert!(output.contains("unstarted"));
+    }
+
+    #[test]
+    fn test_project_updates() {
+        let formatter = TableFormatter::new(false);
+        let update = |id: &str, health: &str, created_at: &str| ProjectUpdate {
+            id: id.to_string(),
+            body: format!("Progress report {id}\n"),
+            health: health.to_string(),
+            url: format!("https://linear.app/updates/{id}"),
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            author: "Alice Smith".to_string(),
+        };
+
+        let output = formatter.format_project_updates(
+            &[
+                update("2", "offTrack", "2026-10-12T09:30:00Z"),
+                update("1", "onTrack", "2026-10-05T09:30:00Z"),
+            ],
+            false,
+        );
+
+        assert!(
+            output.starts_with("2026-10-12 09:30 · Alice Smith · off track\n\nProgress report 2")
+        );
+        assert!(output.contains("2026-10-05 09:30 · Alice Smith · on track"));
+        assert!(output.find("report 2").unwrap() < output.find("report 1").unwrap());
+
+        let empty = formatter.format_project_updates(&[], false);
+        assert_eq!(empty, "No updates posted on this project.");
+    }
+
     #[test]
     fn test_json_formatter_pretty() {
         let formatter = JsonFormatter::new(true);
@@ -900,8 +2151,10 @@ mod tests {
                 name: "Engineering".to_string(),
             }),
             project: Some(IssueProject {
+                id: "project-1".to_string(),
                 name: "Web App".to_string(),
             }),
+            milestone: Some("Beta".to_string()),
             labels: vec![
                 IssueLabel {
                     name: "bug".to_string(),
@@ -914,9 +2167,14 @@ mod tests {
             ],
             priority: Some(2),
             priority_label: Some("High".to_string()),
+            estimate: None,
+            cycle: None,
+            due_date: None,
             created_at: "2024-01-15T10:30:00Z".to_string(),
             updated_at: "2024-01-16T14:45:00Z".to_string(),
             url: "https://linear.app/test/issue/ENG-123".to_string(),
+            parent: None,
+            children: vec![],
         }
     }
 
@@ -933,7 +2191,9 @@ mod tests {
         assert!(result.contains("Assignee:   John Doe (john@example.com)"));
         assert!(result.contains("Team:       Engineering (ENG)"));
         assert!(result.contains("Project:    Web App"));
+        assert!(result.contains("Milestone:  Beta"));
         assert!(result.contains("Priority:   High"));
+        assert!(!result.contains("Due:"));
         assert!(result.contains("Description:"));
         assert!(result.contains("Users are experiencing race conditions"));
         assert!(result.contains("🏷️ Labels: ● bug  ● authentication"));
@@ -957,6 +2217,85 @@ mod tests {
         assert!(result.contains("Engineering"));
     }
 
+    fn create_test_issue_node(
+        identifier: &str,
+        state: &str,
+        children: Vec<IssueNode>,
+    ) -> IssueNode {
+        IssueNode {
+            id: format!("id-{identifier}"),
+            identifier: identifier.to_string(),
+            title: format!("Work for {identifier}"),
+            state: linear_sdk::IssueState {
+                id: format!("state-{state}"),
+                name: state.to_string(),
+                type_: "unstarted".to_string(),
+            },
+            assignee: None,
+            children,
+        }
+    }
+
+    #[test]
+    fn test_detailed_issue_due_date() {
+        let formatter = TableFormatter::new(false);
+        let mut issue = create_test_detailed_issue();
+        issue.due_date = Some("2020-01-01".to_string());
+
+        let result = formatter.format_detailed_issue(&issue).unwrap();
+        assert!(result.contains("Due:        2020-01-01 (overdue)"));
+
+        issue.due_date = Some("2999-01-01".to_string());
+        let result = formatter.format_detailed_issue(&issue).unwrap();
+        assert!(result.contains("Due:        2999-01-01\n"));
+    }
+
+    #[test]
+    fn test_detailed_issue_parent_and_sub_issues() {
+        let formatter = TableFormatter::new(false);
+        let mut issue = create_test_detailed_issue();
+        issue.parent = Some(create_test_issue_node("ENG-100", "In Progress", vec![]));
+        issue.children = vec![
+            create_test_issue_node("ENG-124", "Todo", vec![]),
+            create_test_issue_node("ENG-125", "Done", vec![]),
+        ];
+
+        let result = formatter.format_detailed_issue(&issue).unwrap();
+
+        assert!(result.contains("Parent:     ENG-100 Work for ENG-100 [In Progress]"));
+        assert!(result.contains("🌳 Sub-issues (2):"));
+        assert!(result.contains("   ENG-124 Work for ENG-124 [Todo]"));
+        assert!(result.contains("   ENG-125 Work for ENG-125 [Done]"));
+    }
+
+    #[test]
+    fn test_issue_tree_format() {
+        let formatter = TableFormatter::new(false);
+        let tree = create_test_issue_node(
+            "ENG-1",
+            "In Progress",
+            vec![
+                create_test_issue_node(
+                    "ENG-2",
+                    "Todo",
+                    vec![create_test_issue_node("ENG-4", "Done", vec![])],
+                ),
+                create_test_issue_node("ENG-3", "Todo", vec![]),
+            ],
+        );
+
+        assert_eq!(
+            formatter.format_issue_tree(&tree),
+            [
+                "ENG-1 Work for ENG-1 [In Progress]",
+                "├── ENG-2 Work for ENG-2 [Todo]",
+                "│   └── ENG-4 Work for ENG-4 [Done]",
+                "└── ENG-3 Work for ENG-3 [Todo]",
+            ]
+            .join("\n")
+        );
+    }
+
     #[test]
     fn test_detailed_issue_json_format() {
         let formatter = JsonFormatter::new(false);
@@ -996,12 +2335,18 @@ mod tests {
             assignee: None,
             team: None,
             project: None,
+            milestone: None,
             labels: vec![],
             priority: None,
             priority_label: None,
+            estimate: None,
+            cycle: None,
+            due_date: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             url: "https://linear.app/test/issue/ENG-456".to_string(),
+            parent: None,
+            children: vec![],
         };
 
         let formatter = TableFormatter::new(false);
@@ -1070,17 +2415,24 @@ Final paragraph with normal text."#
                 name: "Engineering".to_string(),
             }),
             project: Some(IssueProject {
+                id: "project-2".to_string(),
                 name: "Markdown Test".to_string(),
             }),
+            milestone: None,
             labels: vec![IssueLabel {
                 name: "documentation".to_string(),
                 color: "#0066CC".to_string(),
             }],
             priority: Some(3),
             priority_label: Some("Normal".to_string()),
+            estimate: None,
+            cycle: None,
+            due_date: None,
             created_at: "2024-01-15T10:30:00Z".to_string(),
             updated_at: "2024-01-16T14:45:00Z".to_string(),
             url: "https://linear.app/test/issue/ENG-456".to_string(),
+            parent: None,
+            children: vec![],
         }
     }
 
@@ -1418,12 +2770,18 @@ Test with:
             assignee: None,
             team: None,
             project: None,
+            milestone: None,
             labels: vec![],
             priority: None,
             priority_label: None,
+            estimate: None,
+            cycle: None,
+            due_date: None,
             created_at: "2024-01-01T00:00:00Z".to_string(),
             updated_at: "2024-01-01T00:00:00Z".to_string(),
             url: "https://linear.app/test/issue/ENG-999".to_string(),
+            parent: None,
+            children: vec![],
         };
 
         let formatter = TableFormatter::new(true);
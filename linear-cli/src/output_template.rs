@@ -0,0 +1,393 @@
+// ABOUTME: Small template language for user-defined output such as "{{identifier}}\t{{title}}"
+// ABOUTME: Resolves dotted paths against JSON values and applies filters like truncate, pad, and date
+
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, NaiveDate, Utc};
+use owo_colors::OwoColorize;
+use serde_json::Value;
+
+const FILTER_NAMES: &str = "truncate, pad, lpad, date, ago, color, upper, lower, default";
+
+/// A parsed output template, rendered once per item
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputTemplate {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Literal(String),
+    Field { path: String, filters: Vec<Filter> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Filter {
+    Truncate(usize),
+    Pad(usize),
+    LeftPad(usize),
+    Date(String),
+    Ago,
+    Color(String),
+    Upper,
+    Lower,
+    Default(String),
+}
+
+impl OutputTemplate {
+    /// Parse a template; `\t`, `\n`, and `\\` in literal text are unescaped so shell-quoted
+    /// templates can contain tabs and newlines
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut rest = source;
+
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                segments.push(Segment::Literal(unescape(&rest[..start])));
+            }
+            let after = &rest[start + 2..];
+            let end = after
+                .find("}}")
+                .ok_or_else(|| format!("Unclosed '{{{{' in template: {source}"))?;
+            segments.push(parse_field(after[..end].trim())?);
+            rest = &after[end + 2..];
+        }
+
+        if !rest.is_empty() {
+            segments.push(Segment::Literal(unescape(rest)));
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// Render the template against one item
+    pub fn render(&self, item: &Value, use_color: bool) -> String {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Literal(text) => text.clone(),
+                Segment::Field { path, filters } => {
+                    let value = lookup(item, path).map(value_to_string).unwrap_or_default();
+                    filters
+                        .iter()
+                        .fold(value, |value, filter| filter.apply(value, use_color))
+                }
+            })
+            .collect()
+    }
+}
+
+fn parse_field(expression: &str) -> Result<Segment, String> {
+    let mut parts = split_outside_quotes(expression, '|').into_iter();
+    let path = parts.next().unwrap_or_default().trim().to_string();
+
+    if path.is_empty() {
+        return Err("Empty field in template, expected something like {{title}}".to_string());
+    }
+
+    let filters = parts
+        .map(|part| parse_filter(part.trim()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Segment::Field { path, filters })
+}
+
+fn parse_filter(spec: &str) -> Result<Filter, String> {
+    let words = split_outside_quotes(spec, ' ')
+        .into_iter()
+        .filter(|word| !word.is_empty())
+        .map(|word| unquote(&word))
+        .collect::<Vec<_>>();
+    let (name, args) = words
+        .split_first()
+        .ok_or_else(|| "Empty filter after '|' in template".to_string())?;
+
+    let width = |filter: &str| -> Result<usize, String> {
+        args.first()
+            .and_then(|arg| arg.parse().ok())
+            .ok_or_else(|| format!("The '{filter}' filter needs a width, e.g. {filter} 20"))
+    };
+
+    match name.as_str() {
+        "truncate" => Ok(Filter::Truncate(width("truncate")?)),
+        "pad" => Ok(Filter::Pad(width("pad")?)),
+        "lpad" => Ok(Filter::LeftPad(width("lpad")?)),
+        "date" => {
+            let format = args.first().cloned().unwrap_or_else(|| "%Y-%m-%d".to_string());
+            // chrono panics when formatting with an invalid spec, so reject it up front
+            if StrftimeItems::new(&format).any(|item| item == Item::Error) {
+                return Err(format!("Invalid date format '{format}' in the 'date' filter"));
+            }
+            Ok(Filter::Date(format))
+        }
+        "ago" => Ok(Filter::Ago),
+        "color" => match args.first().map(String::as_str) {
+            Some(
+                color @ ("red" | "green" | "yellow" | "blue" | "magenta" | "cyan" | "dimmed"
+                | "bold"),
+            ) => Ok(Filter::Color(color.to_string())),
+            _ => Err(
+                "The 'color' filter needs one of: red, green, yellow, blue, magenta, cyan, dimmed, bold"
+                    .to_string(),
+            ),
+        },
+        "upper" => Ok(Filter::Upper),
+        "lower" => Ok(Filter::Lower),
+        "default" => Ok(Filter::Default(args.join(" "))),
+        other => Err(format!(
+            "Unknown template filter '{other}'. Available filters: {FILTER_NAMES}"
+        )),
+    }
+}
+
+impl Filter {
+    fn apply(&self, value: String, use_color: bool) -> String {
+        match self {
+            Filter::Truncate(width) => truncate(&value, *width),
+            Filter::Pad(width) => format!("{value:<width$}"),
+            Filter::LeftPad(width) => format!("{value:>width$}"),
+            Filter::Date(format) => match parse_timestamp(&value) {
+                Some(timestamp) => timestamp.format(format).to_string(),
+                None => value,
+            },
+            Filter::Ago => match parse_timestamp(&value) {
+                Some(timestamp) => relative_time(timestamp, Utc::now()),
+                None => value,
+            },
+            Filter::Color(_) if !use_color || value.is_empty() => value,
+            Filter::Color(color) => match color.as_str() {
+                "red" => value.red().to_string(),
+                "green" => value.green().to_string(),
+                "yellow" => value.yellow().to_string(),
+                "blue" => value.blue().to_string(),
+                "magenta" => value.magenta().to_string(),
+                "cyan" => value.cyan().to_string(),
+                "dimmed" => value.dimmed().to_string(),
+                _ => value.bold().to_string(),
+            },
+            Filter::Upper => value.to_uppercase(),
+            Filter::Lower => value.to_lowercase(),
+            Filter::Default(fallback) if value.is_empty() => fallback.clone(),
+            Filter::Default(_) => value,
+        }
+    }
+}
+
+/// Resolve a dotted path such as `assignee.name`, accepting snake_case for camelCase keys
+fn lookup<'a>(item: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.').try_fold(item, |value, key| match value {
+        Value::Object(map) => map.get(key).or_else(|| map.get(&camel_case(key))),
+        Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
+}
+
+fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        // Objects such as an assignee or label render as their name
+        Value::Object(map) => map
+            .get("name")
+            .or_else(|| map.get("key"))
+            .map(value_to_string)
+            .unwrap_or_else(|| value.to_string()),
+        Value::Array(items) => items
+            .iter()
+            .map(value_to_string)
+            .collect::<Vec<_>>()
+            .join(", "),
+    }
+}
+
+fn camel_case(key: &str) -> String {
+    let mut parts = key.split('_');
+    let mut camel = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() <= width {
+        value.to_string()
+    } else if width <= 3 {
+        value.chars().take(width).collect()
+    } else {
+        let truncated: String = value.chars().take(width - 3).collect();
+        format!("{truncated}...")
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Some(timestamp.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+}
+
+/// Describe how long ago (or how far ahead) `then` is, e.g. "3d ago" or "in 2h"
+fn relative_time(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let seconds = (now - then).num_seconds();
+    let magnitude = seconds.unsigned_abs();
+
+    let amount = match magnitude {
+        0..=59 => return "just now".to_string(),
+        60..=3_599 => format!("{}m", magnitude / 60),
+        3_600..=86_399 => format!("{}h", magnitude / 3_600),
+        86_400..=2_591_999 => format!("{}d", magnitude / 86_400),
+        2_592_000..=31_535_999 => format!("{}mo", magnitude / 2_592_000),
+        _ => format!("{}y", magnitude / 31_536_000),
+    };
+
+    if seconds >= 0 {
+        format!("{amount} ago")
+    } else {
+        format!("in {amount}")
+    }
+}
+
+fn unescape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            output.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => output.push('\t'),
+            Some('n') => output.push('\n'),
+            Some('\\') => output.push('\\'),
+            Some(other) => {
+                output.push('\\');
+                output.push(other);
+            }
+            None => output.push('\\'),
+        }
+    }
+
+    output
+}
+
+fn split_outside_quotes(text: &str, separator: char) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut in_quotes = false;
+
+    for c in text.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        if c == separator && !in_quotes {
+            parts.push(String::new());
+        } else if let Some(part) = parts.last_mut() {
+            part.push(c);
+        }
+    }
+
+    parts
+}
+
+fn unquote(word: &str) -> String {
+    word.strip_prefix('"')
+        .and_then(|w| w.strip_suffix('"'))
+        .unwrap_or(word)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn issue() -> Value {
+        json!({
+            "identifier": "ENG-42",
+            "title": "Fix the login race condition",
+            "assignee": { "id": "user-1", "name": "Alice" },
+            "labels": [{ "name": "bug" }, { "name": "auth" }],
+            "estimate": 3.0,
+            "dueDate": "2026-11-01",
+            "updatedAt": "2026-10-15T09:30:00.000Z",
+            "project": null
+        })
+    }
+
+    fn render(template: &str) -> String {
+        OutputTemplate::parse(template)
+            .unwrap()
+            .render(&issue(), false)
+    }
+
+    #[test]
+    fn test_render_paths_and_escapes() {
+        assert_eq!(
+            render(r"{{identifier}}\t{{title}}\t{{assignee.name}}"),
+            "ENG-42\tFix the login race condition\tAlice"
+        );
+        assert_eq!(render("{{assignee}} / {{labels}}"), "Alice / bug, auth");
+        assert_eq!(
+            render("{{due_date}}|{{project}}|{{missing.path}}"),
+            "2026-11-01||"
+        );
+        assert_eq!(render("{{estimate}}"), "3.0");
+    }
+
+    #[test]
+    fn test_filters() {
+        assert_eq!(render("{{title | truncate 12}}"), "Fix the l...");
+        assert_eq!(render("[{{identifier | pad 8}}]"), "[ENG-42  ]");
+        assert_eq!(render("[{{identifier | lpad 8}}]"), "[  ENG-42]");
+        assert_eq!(render("{{updatedAt | date}}"), "2026-10-15");
+        assert_eq!(render(r#"{{updatedAt | date "%d %b"}}"#), "15 Oct");
+        assert_eq!(render("{{identifier | lower}}"), "eng-42");
+        assert_eq!(
+            render(r#"{{project | default "No project"}}"#),
+            "No project"
+        );
+        assert_eq!(render("{{identifier | color red}}"), "ENG-42");
+    }
+
+    #[test]
+    fn test_color_filter_with_color_enabled() {
+        let template = OutputTemplate::parse("{{identifier | color green}}").unwrap();
+        let output = template.render(&issue(), true);
+        assert!(output.contains("\x1b["));
+        assert!(output.contains("ENG-42"));
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = parse_timestamp("2026-10-17T12:00:00Z").unwrap();
+        let ago = |ts: &str| relative_time(parse_timestamp(ts).unwrap(), now);
+
+        assert_eq!(ago("2026-10-17T11:59:30Z"), "just now");
+        assert_eq!(ago("2026-10-17T09:00:00Z"), "3h ago");
+        assert_eq!(ago("2026-10-14"), "3d ago");
+        assert_eq!(ago("2026-10-19T12:00:00Z"), "in 2d");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(OutputTemplate::parse("{{title").is_err());
+        assert!(OutputTemplate::parse("{{ }}").is_err());
+        assert!(OutputTemplate::parse("{{title | shout}}")
+            .unwrap_err()
+            .contains("Unknown template filter 'shout'"));
+        assert!(OutputTemplate::parse("{{title | truncate}}").is_err());
+        assert!(OutputTemplate::parse("{{title | color pink}}").is_err());
+        assert!(OutputTemplate::parse(r#"{{createdAt | date "%Q"}}"#)
+            .unwrap_err()
+            .contains("Invalid date format '%Q'"));
+    }
+}
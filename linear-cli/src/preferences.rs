@@ -1,6 +1,7 @@
 // ABOUTME: User preferences and smart defaults for the create command
 // ABOUTME: Stores last used settings and provides context-aware defaults
 
+use crate::constants::picker::MAX_RECENT_ISSUES;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
@@ -12,6 +13,15 @@ pub struct UserPreferences {
     pub last_used_assignee: Option<String>,
     pub default_priority: Option<i64>,
     pub preferred_templates: Vec<String>,
+    /// Issues opened with `linear issue`, most recent first
+    #[serde(default)]
+    pub recent_issues: Vec<RecentIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecentIssue {
+    pub identifier: String,
+    pub title: String,
 }
 
 #[derive(Debug, Clone)]
@@ -101,6 +111,24 @@ impl PreferencesManager {
         Ok(())
     }
 
+    /// Move an issue to the front of the recently viewed list, dropping the oldest past the cap
+    pub fn record_recent_issue(&self, identifier: &str, title: &str) -> anyhow::Result<()> {
+        let mut preferences = self.load_preferences()?;
+        preferences
+            .recent_issues
+            .retain(|recent| recent.identifier != identifier);
+        preferences.recent_issues.insert(
+            0,
+            RecentIssue {
+                identifier: identifier.to_string(),
+                title: title.to_string(),
+            },
+        );
+        preferences.recent_issues.truncate(MAX_RECENT_ISSUES);
+        self.save_preferences(&preferences)?;
+        Ok(())
+    }
+
     pub fn get_context_defaults(&self) -> anyhow::Result<ContextDefaults> {
         let mut context = ContextDefaults {
             suggested_team: None,
@@ -239,6 +267,48 @@ mod tests {
         assert_eq!(preferences.default_priority, Some(3));
     }
 
+    #[test]
+    fn test_record_recent_issue() {
+        let (manager, _temp) = create_test_manager();
+
+        manager.record_recent_issue("ENG-1", "First").unwrap();
+        manager.record_recent_issue("ENG-2", "Second").unwrap();
+        manager
+            .record_recent_issue("ENG-1", "First, renamed")
+            .unwrap();
+
+        let recent = manager.load_preferences().unwrap().recent_issues;
+        let identifiers: Vec<_> = recent.iter().map(|r| r.identifier.as_str()).collect();
+        assert_eq!(identifiers, ["ENG-1", "ENG-2"]);
+        assert_eq!(recent[0].title, "First, renamed");
+
+        for n in 0..MAX_RECENT_ISSUES + 5 {
+            manager
+                .record_recent_issue(&format!("ENG-{n}"), "")
+                .unwrap();
+        }
+        let recent = manager.load_preferences().unwrap().recent_issues;
+        assert_eq!(recent.len(), MAX_RECENT_ISSUES);
+        assert_eq!(
+            recent[0].identifier,
+            format!("ENG-{}", MAX_RECENT_ISSUES + 4)
+        );
+    }
+
+    #[test]
+    fn test_preferences_without_recent_issues_still_load() {
+        let (manager, _temp) = create_test_manager();
+        std::fs::write(
+            manager.preferences_file(),
+            r#"{"last_used_team": "ENG", "last_used_assignee": null, "default_priority": null, "preferred_templates": []}"#,
+        )
+        .unwrap();
+
+        let preferences = manager.load_preferences().unwrap();
+        assert_eq!(preferences.last_used_team.as_deref(), Some("ENG"));
+        assert!(preferences.recent_issues.is_empty());
+    }
+
     #[test]
     fn test_extract_title_prefix() {
         let (manager, _temp) = create_test_manager();
@@ -0,0 +1,503 @@
+// ABOUTME: Cycle burndown and velocity reports for `linear report`
+// ABOUTME: Turns Linear's cycle history into day-by-day series and draws them as terminal charts
+
+use anyhow::Result;
+use chrono::{DateTime, Days};
+use linear_sdk::{Cycle, CycleHistory};
+use serde::Serialize;
+
+use crate::constants::report::{CHART_HEIGHT, CHART_WIDTH, VELOCITY_BAR_WIDTH};
+use crate::output::{ChartSegment, TableFormatter};
+
+/// One day of a cycle's burndown
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BurndownDay {
+    pub day: usize,
+    pub date: String,
+    /// Points in the cycle that day; unset for days that haven't happened yet
+    pub scope: Option<f64>,
+    pub completed: Option<f64>,
+    pub remaining: Option<f64>,
+    /// Remaining points if the starting scope burned down evenly
+    pub ideal: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Burndown {
+    pub cycle: Cycle,
+    pub team: String,
+    pub days: Vec<BurndownDay>,
+}
+
+/// Lay a cycle's history out over every day from its start to its end
+pub fn burndown(history: &CycleHistory) -> Burndown {
+    let cycle = &history.cycle;
+    let start = DateTime::parse_from_rfc3339(&cycle.starts_at).ok();
+    let end = DateTime::parse_from_rfc3339(&cycle.ends_at).ok();
+    let length = match (start, end) {
+        (Some(start), Some(end)) => (end - start).num_days().max(1) as usize,
+        _ => 1,
+    }
+    .max(history.scope_history.len().saturating_sub(1));
+
+    let initial = history.scope_history.first().copied().unwrap_or(0.0);
+    let days = (0..=length)
+        .map(|day| {
+            let scope = history.scope_history.get(day).copied();
+            let completed = scope.map(|_| {
+                history
+                    .completed_scope_history
+                    .get(day)
+                    .copied()
+                    .unwrap_or(0.0)
+            });
+            let date = start
+                .and_then(|start| start.date_naive().checked_add_days(Days::new(day as u64)))
+                .map(|date| date.to_string())
+                .unwrap_or_default();
+            BurndownDay {
+                day,
+                date,
+                scope,
+                completed,
+                remaining: scope.zip(completed).map(|(scope, done)| scope - done),
+                ideal: initial * (1.0 - day as f64 / length as f64),
+            }
+        })
+        .collect();
+
+    Burndown {
+        cycle: cycle.clone(),
+        team: history.team.key.clone(),
+        days,
+    }
+}
+
+/// Points without a trailing ".0"
+fn points(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        format!("{value:.1}")
+    }
+}
+
+fn cycle_title(cycle: &Cycle, team: &str, formatter: &TableFormatter) -> String {
+    let title = match &cycle.name {
+        Some(name) => format!("Cycle {}: {name} ({team})", cycle.number),
+        None => format!("Cycle {} ({team})", cycle.number),
+    };
+    formatter.format_heading(&title)
+}
+
+/// Draw a burndown as a bar per day: remaining points below, completed points stacked on
+/// top, and a dotted line where an even burndown would be
+pub fn render_burndown(burndown: &Burndown, formatter: &TableFormatter) -> String {
+    let cycle = &burndown.cycle;
+    let first = burndown.days.first();
+    let last = burndown.days.last();
+    let mut output = vec![
+        cycle_title(cycle, &burndown.team, formatter),
+        format!(
+            "{} → {} · {} points, {} completed, {} remaining",
+            first.map(|day| day.date.as_str()).unwrap_or_default(),
+            last.map(|day| day.date.as_str()).unwrap_or_default(),
+            points(cycle.scope),
+            points(cycle.completed_scope),
+            points(cycle.scope - cycle.completed_scope),
+        ),
+        String::new(),
+    ];
+
+    let max = burndown
+        .days
+        .iter()
+        .map(|day| day.scope.unwrap_or(0.0).max(day.ideal))
+        .fold(0.0, f64::max);
+    if max <= 0.0 {
+        output.push("No points in this cycle yet.".to_string());
+        return output.join("\n");
+    }
+
+    let col_width = (CHART_WIDTH / burndown.days.len()).clamp(1, 3);
+    let row_height = max / CHART_HEIGHT as f64;
+    let label_width = points(max).len();
+
+    for row in (1..=CHART_HEIGHT).rev() {
+        let top = row_height * row as f64;
+        let bottom = top - row_height;
+        // A bar fills a row once it reaches halfway up it
+        let reaches = |value: f64| value >= bottom + row_height / 2.0;
+
+        let mut line = match row {
+            row if row == CHART_HEIGHT => format!("{:>label_width$} ┤", points(max)),
+            row if row == CHART_HEIGHT / 2 => {
+                format!("{:>label_width$} ┤", points(row_height * row as f64))
+            }
+            _ => format!("{:>label_width$} │", ""),
+        };
+        for day in &burndown.days {
+            let cell = match (day.remaining, day.scope) {
+                (Some(remaining), _) if reaches(remaining) => Some(("█", ChartSegment::Remaining)),
+                (_, Some(scope)) if reaches(scope) => Some(("░", ChartSegment::Completed)),
+                _ if day.ideal > bottom && day.ideal <= top => Some(("·", ChartSegment::Ideal)),
+                _ => None,
+            };
+            let cell = match cell {
+                Some((glyph, segment)) => {
+                    formatter.format_chart_segment(&glyph.repeat(col_width), segment)
+                }
+                None => " ".repeat(col_width),
+            };
+            line.push_str(&cell);
+        }
+        output.push(line.trim_end().to_string());
+    }
+
+    let chart_width = col_width * burndown.days.len();
+    output.push(format!(
+        "{:>label_width$} └{}",
+        "0",
+        "─".repeat(chart_width)
+    ));
+
+    let start = first.map(|day| day.date.as_str()).unwrap_or_default();
+    let end = last.map(|day| day.date.as_str()).unwrap_or_default();
+    let gap = chart_width.saturating_sub(start.len() + end.len()).max(1);
+    output.push(format!(
+        "{:>label_width$} {start}{}{end}",
+        "",
+        " ".repeat(gap)
+    ));
+
+    output.push(String::new());
+    output.push(format!(
+        "{} remaining  {} completed  {} ideal",
+        formatter.format_chart_segment("█", ChartSegment::Remaining),
+        formatter.format_chart_segment("░", ChartSegment::Completed),
+        formatter.format_chart_segment("·", ChartSegment::Ideal)
+    ));
+
+    output.join("\n")
+}
+
+/// A burndown as CSV, or TSV with a tab `delimiter`
+pub fn burndown_csv(burndown: &Burndown, delimiter: u8) -> Result<String> {
+    let optional = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    write_csv(
+        delimiter,
+        &["Day", "Date", "Scope", "Completed", "Remaining", "Ideal"],
+        burndown.days.iter().map(|day| {
+            vec![
+                day.day.to_string(),
+                day.date.clone(),
+                optional(day.scope),
+                optional(day.completed),
+                optional(day.remaining),
+                day.ideal.to_string(),
+            ]
+        }),
+    )
+}
+
+/// Points completed in one finished cycle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VelocityCycle {
+    pub number: i64,
+    pub name: Option<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub scope: f64,
+    pub completed: f64,
+    /// Fraction of the scope completed, from 0 to 1
+    pub completion: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Velocity {
+    pub team: String,
+    /// Oldest first
+    pub cycles: Vec<VelocityCycle>,
+    /// Mean points completed per cycle
+    pub average: f64,
+}
+
+/// Summarize the `last` most recently finished cycles
+pub fn velocity(cycles: &[Cycle], team: &str, last: usize) -> Velocity {
+    let mut finished: Vec<&Cycle> = cycles
+        .iter()
+        .filter(|cycle| cycle.completed_at.is_some())
+        .collect();
+    finished.sort_by_key(|cycle| cycle.number);
+    let skip = finished.len().saturating_sub(last);
+
+    let cycles: Vec<VelocityCycle> = finished[skip..]
+        .iter()
+        .map(|cycle| VelocityCycle {
+            number: cycle.number,
+            name: cycle.name.clone(),
+            starts_at: cycle.starts_at.clone(),
+            ends_at: cycle.ends_at.clone(),
+            scope: cycle.scope,
+            completed: cycle.completed_scope,
+            completion: if cycle.scope > 0.0 {
+                cycle.completed_scope / cycle.scope
+            } else {
+                0.0
+            },
+        })
+        .collect();
+
+    let average = if cycles.is_empty() {
+        0.0
+    } else {
+        cycles.iter().map(|cycle| cycle.completed).sum::<f64>() / cycles.len() as f64
+    };
+
+    Velocity {
+        team: team.to_string(),
+        cycles,
+        average,
+    }
+}
+
+/// A bar per cycle: completed points solid, the rest of its scope shaded
+pub fn render_velocity(velocity: &Velocity, formatter: &TableFormatter) -> String {
+    if velocity.cycles.is_empty() {
+        return format!("No completed cycles for {} yet.", velocity.team);
+    }
+
+    let noun = if velocity.cycles.len() == 1 {
+        "cycle"
+    } else {
+        "cycles"
+    };
+    let heading = format!(
+        "Velocity for {} over the last {} completed {noun}",
+        velocity.team,
+        velocity.cycles.len()
+    );
+    let mut output = vec![formatter.format_heading(&heading), String::new()];
+
+    let max = velocity
+        .cycles
+        .iter()
+        .map(|cycle| cycle.scope.max(cycle.completed))
+        .fold(0.0, f64::max);
+    let scale = |value: f64| {
+        if max > 0.0 {
+            (value / max * VELOCITY_BAR_WIDTH as f64).round() as usize
+        } else {
+            0
+        }
+    };
+    let label_width = velocity
+        .cycles
+        .iter()
+        .map(|cycle| cycle.number.to_string().len() + 1)
+        .max()
+        .unwrap_or(0);
+
+    for cycle in &velocity.cycles {
+        let done = scale(cycle.completed);
+        let rest = scale(cycle.scope).saturating_sub(done);
+        let padding = " ".repeat(VELOCITY_BAR_WIDTH - done - rest);
+        let bar = format!(
+            "{}{}",
+            formatter.format_chart_segment(&"█".repeat(done), ChartSegment::Completed),
+            formatter.format_chart_segment(&"░".repeat(rest), ChartSegment::Ideal)
+        );
+        output.push(format!(
+            "{:>label_width$}  {bar}{padding}  {} of {} points ({:.0}%)",
+            format!("#{}", cycle.number),
+            points(cycle.completed),
+            points(cycle.scope),
+            cycle.completion * 100.0
+        ));
+    }
+
+    output.push(String::new());
+    output.push(format!(
+        "Average: {} points per cycle",
+        points((velocity.average * 10.0).round() / 10.0)
+    ));
+    output.join("\n")
+}
+
+/// Velocity as CSV, or TSV with a tab `delimiter`
+pub fn velocity_csv(velocity: &Velocity, delimiter: u8) -> Result<String> {
+    write_csv(
+        delimiter,
+        &[
+            "Number",
+            "Name",
+            "Starts",
+            "Ends",
+            "Scope",
+            "Completed",
+            "Completion",
+        ],
+        velocity.cycles.iter().map(|cycle| {
+            vec![
+                cycle.number.to_string(),
+                cycle.name.clone().unwrap_or_default(),
+                cycle.starts_at.clone(),
+                cycle.ends_at.clone(),
+                cycle.scope.to_string(),
+                cycle.completed.to_string(),
+                cycle.completion.to_string(),
+            ]
+        }),
+    )
+}
+
+fn write_csv(
+    delimiter: u8,
+    headers: &[&str],
+    rows: impl Iterator<Item = Vec<String>>,
+) -> Result<String> {
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(Vec::new());
+    writer.write_record(headers)?;
+    for row in rows {
+        writer.write_record(row)?;
+    }
+    let output = String::from_utf8(writer.into_inner()?)?;
+    Ok(output.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linear_sdk::IssueTeam;
+
+    fn cycle(number: i64, scope: f64, completed: f64, finished: bool) -> Cycle {
+        Cycle {
+            id: format!("cycle-{number}"),
+            number,
+            name: None,
+            starts_at: "2026-10-05T00:00:00.000Z".to_string(),
+            ends_at: "2026-10-09T00:00:00.000Z".to_string(),
+            completed_at: finished.then(|| "2026-10-09T00:00:00.000Z".to_string()),
+            is_active: !finished,
+            progress: 0.0,
+            scope,
+            started_scope: 0.0,
+            completed_scope: completed,
+            issue_count: 0,
+            completed_issue_count: 0,
+        }
+    }
+
+    fn history() -> CycleHistory {
+        CycleHistory {
+            cycle: cycle(42, 12.0, 6.0, false),
+            team: IssueTeam {
+                id: "team-1".to_string(),
+                key: "ENG".to_string(),
+                name: "Engineering".to_string(),
+            },
+            scope_history: vec![10.0, 12.0, 12.0],
+            in_progress_scope_history: vec![0.0, 2.0, 3.0],
+            completed_scope_history: vec![0.0, 2.0, 6.0],
+            issue_count_history: vec![4.0, 5.0, 5.0],
+            completed_issue_count_history: vec![0.0, 1.0, 2.0],
+        }
+    }
+
+    #[test]
+    fn test_burndown_covers_every_day_of_the_cycle() {
+        let burndown = burndown(&history());
+
+        assert_eq!(burndown.days.len(), 5);
+        assert_eq!(burndown.days[0].date, "2026-10-05");
+        assert_eq!(burndown.days[4].date, "2026-10-09");
+        assert_eq!(burndown.days[2].remaining, Some(6.0));
+        assert_eq!(burndown.days[3].scope, None);
+        assert_eq!(burndown.days[0].ideal, 10.0);
+        assert_eq!(burndown.days[2].ideal, 5.0);
+        assert_eq!(burndown.days[4].ideal, 0.0);
+    }
+
+    #[test]
+    fn test_render_burndown() {
+        let output = render_burndown(&burndown(&history()), &TableFormatter::new(false));
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "Cycle 42 (ENG)");
+        assert_eq!(
+            lines[1],
+            "2026-10-05 → 2026-10-09 · 12 points, 6 completed, 6 remaining"
+        );
+        // Only the second and third days reach the full scope
+        assert_eq!(lines[3], "12 ┤   ░░░░░░");
+        assert_eq!(lines[8], " 6 ┤█████████");
+        assert!(lines.contains(&" 0 └───────────────"));
+        assert!(lines.contains(&"   2026-10-05 2026-10-09"));
+        assert_eq!(lines.last(), Some(&"█ remaining  ░ completed  · ideal"));
+    }
+
+    #[test]
+    fn test_render_burndown_without_scope() {
+        let mut history = history();
+        history.scope_history = vec![0.0];
+        history.completed_scope_history = vec![0.0];
+        history.cycle.scope = 0.0;
+        history.cycle.completed_scope = 0.0;
+
+        let output = render_burndown(&burndown(&history), &TableFormatter::new(false));
+
+        assert!(output.ends_with("No points in this cycle yet."));
+    }
+
+    #[test]
+    fn test_velocity_uses_last_finished_cycles() {
+        let cycles = vec![
+            cycle(43, 8.0, 0.0, false),
+            cycle(42, 10.0, 5.0, true),
+            cycle(41, 20.0, 15.0, true),
+            cycle(40, 4.0, 4.0, true),
+        ];
+
+        let velocity = velocity(&cycles, "ENG", 2);
+
+        let numbers: Vec<_> = velocity.cycles.iter().map(|c| c.number).collect();
+        assert_eq!(numbers, [41, 42]);
+        assert_eq!(velocity.average, 10.0);
+        assert_eq!(velocity.cycles[1].completion, 0.5);
+
+        let output = render_velocity(&velocity, &TableFormatter::new(false));
+        assert!(
+            output.contains("#41  ██████████████████████████████░░░░░░░░░░  15 of 20 points (75%)")
+        );
+        assert!(output.ends_with("Average: 10 points per cycle"));
+    }
+
+    #[test]
+    fn test_report_csv() {
+        let velocity = velocity(&[cycle(40, 4.0, 3.0, true)], "ENG", 6);
+
+        assert_eq!(
+            velocity_csv(&velocity, b',').unwrap(),
+            "Number,Name,Starts,Ends,Scope,Completed,Completion\n\
+             40,,2026-10-05T00:00:00.000Z,2026-10-09T00:00:00.000Z,4,3,0.75"
+        );
+
+        let burndown = burndown_csv(&burndown(&history()), b',').unwrap();
+        let lines: Vec<&str> = burndown.lines().collect();
+        assert_eq!(lines[0], "Day,Date,Scope,Completed,Remaining,Ideal");
+        assert_eq!(lines[2], "1,2026-10-06,12,2,10,7.5");
+        assert_eq!(lines[5], "4,2026-10-09,,,,0");
+
+        let tsv = velocity_csv(&velocity, b'\t').unwrap();
+        assert_eq!(
+            tsv.lines().nth(1),
+            Some("40\t\t2026-10-05T00:00:00.000Z\t2026-10-09T00:00:00.000Z\t4\t3\t0.75")
+        );
+    }
+}
@@ -0,0 +1,187 @@
+// ABOUTME: Local bookkeeping for `linear sync`, which mirrors issues as a directory of markdown files
+// ABOUTME: Reads issue files and remembers what each looked like after the last pull or push
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::constants::sync::STATE_FILE;
+use crate::frontmatter::{parse_markdown_content, MarkdownFile};
+
+/// Fingerprints of each file's contents as of the last sync, keyed by file name
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SyncState {
+    files: BTreeMap<String, String>,
+}
+
+impl SyncState {
+    /// Load the state for `dir`, or an empty state if it has never been synced
+    pub fn load(dir: &Path) -> Result<Self> {
+        let path = dir.join(STATE_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read '{}': {e}", path.display()))?;
+        serde_json::from_str(&content)
+            .map_err(|e| anyhow!("Failed to parse '{}': {e}", path.display()))
+    }
+
+    pub fn save(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(STATE_FILE);
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(&path, content + "\n")
+            .map_err(|e| anyhow!("Failed to write '{}': {e}", path.display()))
+    }
+
+    /// Whether `text` differs from what was last synced to `name`; unknown files count as modified
+    pub fn is_modified(&self, name: &str, text: &str) -> bool {
+        self.files.get(name) != Some(&fingerprint(text))
+    }
+
+    /// Remember `text` as the synced contents of `name`
+    pub fn record(&mut self, name: &str, text: &str) {
+        self.files.insert(name.to_string(), fingerprint(text));
+    }
+
+    pub fn forget(&mut self, name: &str) {
+        self.files.remove(name);
+    }
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` stays the same across Rust releases
+fn fingerprint(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// A markdown file in the sync directory
+#[derive(Debug)]
+pub struct IssueFile {
+    pub name: String,
+    pub path: PathBuf,
+    pub text: String,
+    /// The parsed file, or why it couldn't be parsed
+    pub parsed: std::result::Result<MarkdownFile, String>,
+}
+
+impl IssueFile {
+    /// The Linear issue ID recorded in the frontmatter, if the file parsed and has one
+    pub fn issue_id(&self) -> Option<&str> {
+        self.parsed.as_ref().ok()?.frontmatter.id.as_deref()
+    }
+}
+
+/// Every `.md` file directly inside `dir`, sorted by name
+pub fn read_issue_files(dir: &Path) -> Result<Vec<IssueFile>> {
+    let entries =
+        fs::read_dir(dir).map_err(|e| anyhow!("Failed to read '{}': {e}", dir.display()))?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            continue;
+        }
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        let text = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Failed to read '{}': {e}", path.display()))?;
+        let parsed = parse_markdown_content(&text).map_err(|e| e.to_string());
+        files.push(IssueFile {
+            name: name.to_string(),
+            path: path.clone(),
+            text,
+            parsed,
+        });
+    }
+
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(files)
+}
+
+/// The file name a pulled issue is written to, e.g. `ENG-123.md`
+pub fn file_name_for(identifier: &str) -> String {
+    format!("{identifier}.md")
+}
+
+/// Write `file` to `path`, returning the text written
+pub fn write_issue_file(path: &Path, file: &MarkdownFile) -> Result<String> {
+    let text = file.to_markdown()?;
+    fs::write(path, &text).map_err(|e| anyhow!("Failed to write '{}': {e}", path.display()))?;
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const ISSUE: &str = "---\ntitle: Fix login\nteam: ENG\nid: issue-1\nupdatedAt: 2026-10-02T00:00:00Z\n---\n\nBody\n";
+
+    #[test]
+    fn test_state_tracks_modified_files() {
+        let temp = TempDir::new().unwrap();
+        let mut state = SyncState::load(temp.path()).unwrap();
+        assert!(state.is_modified("ENG-1.md", ISSUE));
+
+        state.record("ENG-1.md", ISSUE);
+        assert!(!state.is_modified("ENG-1.md", ISSUE));
+        assert!(state.is_modified("ENG-1.md", &ISSUE.replace("Body", "Edited")));
+
+        state.save(temp.path()).unwrap();
+        let reloaded = SyncState::load(temp.path()).unwrap();
+        assert!(!reloaded.is_modified("ENG-1.md", ISSUE));
+
+        let mut reloaded = reloaded;
+        reloaded.forget("ENG-1.md");
+        assert!(reloaded.is_modified("ENG-1.md", ISSUE));
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable() {
+        assert_eq!(fingerprint(""), "cbf29ce484222325");
+        assert_eq!(fingerprint("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_read_issue_files() {
+        let temp = TempDir::new().unwrap();
+        fs::write(temp.path().join("ENG-1.md"), ISSUE).unwrap();
+        fs::write(temp.path().join("idea.md"), "no frontmatter").unwrap();
+        fs::write(temp.path().join("notes.txt"), "ignored").unwrap();
+        fs::write(temp.path().join(STATE_FILE), "{}").unwrap();
+
+        let files = read_issue_files(temp.path()).unwrap();
+        let names: Vec<_> = files.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["ENG-1.md", "idea.md"]);
+
+        let parsed = files[0].parsed.as_ref().unwrap();
+        assert_eq!(files[0].issue_id(), Some("issue-1"));
+        assert_eq!(
+            parsed.frontmatter.updated_at.as_deref(),
+            Some("2026-10-02T00:00:00Z")
+        );
+        assert!(files[1].parsed.is_err());
+        assert_eq!(files[1].issue_id(), None);
+    }
+
+    #[test]
+    fn test_write_issue_file_round_trips() {
+        let temp = TempDir::new().unwrap();
+        let file = parse_markdown_content(ISSUE).unwrap();
+        let path = temp.path().join(file_name_for("ENG-1"));
+
+        let text = write_issue_file(&path, &file).unwrap();
+
+        assert_eq!(text, ISSUE);
+        assert_eq!(fs::read_to_string(path).unwrap(), ISSUE);
+    }
+}
@@ -47,9 +47,7 @@ fn test_parse_issues_command() {
             limit,
             json,
             pretty,
-            assignee: _,
-            status: _,
-            team: _,
+            ..
         } => {
             assert_eq!(limit, 20);
             assert!(!json);
@@ -63,7 +61,19 @@ fn test_parse_issues_command() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -82,9 +92,7 @@ fn test_parse_issues_command() {
             limit,
             json,
             pretty,
-            assignee: _,
-            status: _,
-            team: _,
+            ..
         } => {
             assert_eq!(limit, 5);
             assert!(!json);
@@ -98,7 +106,19 @@ fn test_parse_issues_command() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -117,9 +137,7 @@ fn test_parse_issues_command() {
             limit,
             json,
             pretty,
-            assignee: _,
-            status: _,
-            team: _,
+            ..
         } => {
             assert_eq!(limit, 10);
             assert!(!json);
@@ -133,7 +151,19 @@ fn test_parse_issues_command() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -152,9 +182,7 @@ fn test_parse_issues_command() {
             limit,
             json,
             pretty,
-            assignee: _,
-            status: _,
-            team: _,
+            ..
         } => {
             assert_eq!(limit, 20);
             assert!(json);
@@ -168,7 +196,19 @@ fn test_parse_issues_command() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -187,9 +227,7 @@ fn test_parse_issues_command() {
             limit,
             json,
             pretty,
-            assignee: _,
-            status: _,
-            team: _,
+            ..
         } => {
             assert_eq!(limit, 20);
             assert!(json);
@@ -203,7 +241,19 @@ fn test_parse_issues_command() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -234,6 +284,8 @@ fn test_parse_issue_command() {
         }
         #[cfg(feature = "oauth")]
         Commands::Projects { .. } => panic!("Expected Issue command"),
+        Commands::Project { .. } => panic!("Expected Issue command"),
+        Commands::Milestones { .. } => panic!("Expected Issue command"),
         Commands::Teams { .. } => panic!("Expected Issue command"),
         Commands::Comments { .. } => panic!("Expected Issue command"),
         Commands::MyWork { .. } => panic!("Expected Issue command"),
@@ -254,6 +306,8 @@ fn test_parse_issue_command() {
         }
         #[cfg(feature = "oauth")]
         Commands::Projects { .. } => panic!("Expected Issue command"),
+        Commands::Project { .. } => panic!("Expected Issue command"),
+        Commands::Milestones { .. } => panic!("Expected Issue command"),
         Commands::Teams { .. } => panic!("Expected Issue command"),
         Commands::Comments { .. } => panic!("Expected Issue command"),
         Commands::MyWork { .. } => panic!("Expected Issue command"),
@@ -274,6 +328,8 @@ fn test_parse_issue_command() {
         }
         #[cfg(feature = "oauth")]
         Commands::Projects { .. } => panic!("Expected Issue command"),
+        Commands::Project { .. } => panic!("Expected Issue command"),
+        Commands::Milestones { .. } => panic!("Expected Issue command"),
         Commands::Teams { .. } => panic!("Expected Issue command"),
         Commands::Comments { .. } => panic!("Expected Issue command"),
         Commands::MyWork { .. } => panic!("Expected Issue command"),
@@ -294,6 +350,8 @@ fn test_parse_issue_command() {
         }
         #[cfg(feature = "oauth")]
         Commands::Projects { .. } => panic!("Expected Issue command"),
+        Commands::Project { .. } => panic!("Expected Issue command"),
+        Commands::Milestones { .. } => panic!("Expected Issue command"),
         Commands::Teams { .. } => panic!("Expected Issue command"),
         Commands::Comments { .. } => panic!("Expected Issue command"),
         Commands::MyWork { .. } => panic!("Expected Issue command"),
@@ -314,6 +372,8 @@ fn test_parse_issue_command() {
         }
         #[cfg(feature = "oauth")]
         Commands::Projects { .. } => panic!("Expected Issue command"),
+        Commands::Project { .. } => panic!("Expected Issue command"),
+        Commands::Milestones { .. } => panic!("Expected Issue command"),
         Commands::Teams { .. } => panic!("Expected Issue command"),
         Commands::Comments { .. } => panic!("Expected Issue command"),
         Commands::MyWork { .. } => panic!("Expected Issue command"),
@@ -336,12 +396,13 @@ fn test_parse_filter_arguments() {
     // Test assignee filter
     let cli = Cli::try_parse_from(["linear", "issues", "--assignee", "me"]).unwrap();
     match cli.command {
-        Commands::Issues {
-            assignee,
-            status,
-            team,
-            ..
-        } => {
+        Commands::Issues { filters, .. } => {
+            let IssueFilterArgs {
+                assignee,
+                status,
+                team,
+                ..
+            } = filters;
             assert_eq!(assignee, Some("me".to_string()));
             assert_eq!(status, None);
             assert_eq!(team, None);
@@ -354,7 +415,19 @@ fn test_parse_filter_arguments() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -369,12 +442,13 @@ fn test_parse_filter_arguments() {
     // Test status filter
     let cli = Cli::try_parse_from(["linear", "issues", "--status", "done"]).unwrap();
     match cli.command {
-        Commands::Issues {
-            assignee,
-            status,
-            team,
-            ..
-        } => {
+        Commands::Issues { filters, .. } => {
+            let IssueFilterArgs {
+                assignee,
+                status,
+                team,
+                ..
+            } = filters;
             assert_eq!(assignee, None);
             assert_eq!(status, Some("done".to_string()));
             assert_eq!(team, None);
@@ -387,7 +461,19 @@ fn test_parse_filter_arguments() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -402,12 +488,13 @@ fn test_parse_filter_arguments() {
     // Test team filter
     let cli = Cli::try_parse_from(["linear", "issues", "--team", "ENG"]).unwrap();
     match cli.command {
-        Commands::Issues {
-            assignee,
-            status,
-            team,
-            ..
-        } => {
+        Commands::Issues { filters, .. } => {
+            let IssueFilterArgs {
+                assignee,
+                status,
+                team,
+                ..
+            } = filters;
             assert_eq!(assignee, None);
             assert_eq!(status, None);
             assert_eq!(team, Some("ENG".to_string()));
@@ -420,7 +507,19 @@ fn test_parse_filter_arguments() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -445,12 +544,13 @@ fn test_parse_filter_arguments() {
     ])
     .unwrap();
     match cli.command {
-        Commands::Issues {
-            assignee,
-            status,
-            team,
-            ..
-        } => {
+        Commands::Issues { filters, .. } => {
+            let IssueFilterArgs {
+                assignee,
+                status,
+                team,
+                ..
+            } = filters;
             assert_eq!(assignee, Some("me".to_string()));
             assert_eq!(status, Some("in progress".to_string()));
             assert_eq!(team, Some("ENG".to_string()));
@@ -463,7 +563,19 @@ fn test_parse_filter_arguments() {
         Commands::Comment { .. } => panic!("Expected Issues command"),
         Commands::Attach { .. } => panic!("Expected Issues command"),
         Commands::Relate { .. } => panic!("Expected Issues command"),
+        Commands::Api { .. } => panic!("Expected Issues command"),
+        Commands::Tui { .. } => panic!("Expected Issues command"),
+        Commands::Pick => panic!("Expected Issues command"),
+        Commands::Cycles { .. } => panic!("Expected Issues command"),
+        Commands::Report { .. } => panic!("Expected Issues command"),
+        Commands::Sync { .. } => panic!("Expected Issues command"),
+        Commands::Edit { .. } => panic!("Expected Issues command"),
+        Commands::Archive { .. } => panic!("Expected Issues command"),
+        Commands::Unarchive { .. } => panic!("Expected Issues command"),
+        Commands::Delete { .. } => panic!("Expected Issues command"),
         Commands::Projects { .. } => panic!("Expected Issues command"),
+        Commands::Project { .. } => panic!("Expected Issues command"),
+        Commands::Milestones { .. } => panic!("Expected Issues command"),
         Commands::Teams { .. } => panic!("Expected Issues command"),
         Commands::Comments { .. } => panic!("Expected Issues command"),
         Commands::MyWork { .. } => panic!("Expected Issues command"),
@@ -672,6 +784,296 @@ fn test_limit_validation() {
     }
 }
 
+#[test]
+fn test_parse_all_flag() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "issues", "--all"]).unwrap();
+    match cli.command {
+        Commands::Issues { all, .. } => assert!(all),
+        _ => panic!("Expected Issues command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "my-work", "--all"]).unwrap();
+    match cli.command {
+        Commands::MyWork { all, .. } => assert!(all),
+        _ => panic!("Expected MyWork command"),
+    }
+
+    // --all and an explicit --limit contradict each other
+    let result = Cli::try_parse_from(["linear", "projects", "--all", "--limit", "5"]);
+    assert!(result.is_err());
+    let result = Cli::try_parse_from(["linear", "comments", "ENG-1", "--all", "-l", "5"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_parse_rich_issue_filters() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "issues",
+        "--label",
+        "bug",
+        "--label",
+        "ui",
+        "--project",
+        "Mobile App",
+        "--cycle",
+        "current",
+        "--priority",
+        "0",
+        "--creator",
+        "me",
+        "--state-type",
+        "started",
+        "--state-type",
+        "unstarted",
+        "--created-after",
+        "7d",
+        "--updated-since",
+        "2026-01-31",
+        "--due-before",
+        "14d",
+        "--parent",
+        "ENG-1",
+        "--no-estimate",
+        "--include-archived",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Commands::Issues { filters, .. } => {
+            let IssueFilterArgs {
+                labels,
+                project,
+                cycle,
+                priority,
+                creator,
+                state_types,
+                created_after,
+                updated_since,
+                due_before,
+                parent,
+                no_estimate,
+                include_archived,
+                ..
+            } = filters;
+            assert_eq!(labels, vec!["bug", "ui"]);
+            assert_eq!(project.as_deref(), Some("Mobile App"));
+            assert_eq!(cycle.as_deref(), Some("current"));
+            assert_eq!(priority, Some(0));
+            assert_eq!(creator.as_deref(), Some("me"));
+            assert_eq!(state_types, vec!["started", "unstarted"]);
+            assert_eq!(created_after.as_deref(), Some("-P7D"));
+            assert_eq!(updated_since.as_deref(), Some("2026-01-31T00:00:00.000Z"));
+            assert_eq!(due_before.as_deref(), Some("P14D"));
+            assert_eq!(parent.as_deref(), Some("ENG-1"));
+            assert!(no_estimate);
+            assert!(include_archived);
+        }
+        _ => panic!("Expected Issues command"),
+    }
+
+    // Invalid values are rejected at parse time
+    assert!(Cli::try_parse_from(["linear", "issues", "--state-type", "doing"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "issues", "--priority", "5"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "issues", "--updated-since", "lately"]).is_err());
+}
+
+#[test]
+fn test_parse_sort_and_columns() {
+    use crate::columns::{IssueColumn, SortKey};
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "issues",
+        "--sort",
+        "priority,-updated",
+        "--columns",
+        "identifier,title,due",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Commands::Issues { sort, columns, .. } => {
+            assert_eq!(
+                sort.unwrap().0,
+                vec![
+                    SortKey {
+                        column: IssueColumn::Priority,
+                        descending: false
+                    },
+                    SortKey {
+                        column: IssueColumn::Updated,
+                        descending: true
+                    },
+                ]
+            );
+            assert_eq!(
+                columns.unwrap().0,
+                vec![
+                    IssueColumn::Identifier,
+                    IssueColumn::Title,
+                    IssueColumn::Due
+                ]
+            );
+        }
+        _ => panic!("Expected Issues command"),
+    }
+
+    assert!(Cli::try_parse_from(["linear", "issues", "--columns", "identifier,colour"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "issues", "--sort", "-"]).is_err());
+}
+
+#[test]
+fn test_parse_format_flag() {
+    use crate::formats::Format;
+    use clap::Parser;
+
+    for (args, expected) in [
+        (vec!["linear", "issues", "--format", "csv"], Format::Csv),
+        (vec!["linear", "projects", "--format", "yaml"], Format::Yaml),
+        (vec!["linear", "teams", "--format", "tsv"], Format::Tsv),
+        (
+            vec!["linear", "comments", "ENG-1", "--format", "ndjson"],
+            Format::Ndjson,
+        ),
+        (
+            vec!["linear", "my-work", "--format", "table"],
+            Format::Table,
+        ),
+        (
+            vec!["linear", "search", "bug", "--format", "json"],
+            Format::Json,
+        ),
+    ] {
+        let cli = Cli::try_parse_from(&args).unwrap();
+        let format = match cli.command {
+            Commands::Issues { format, .. }
+            | Commands::Projects { format, .. }
+            | Commands::Teams { format, .. }
+            | Commands::Comments { format, .. }
+            | Commands::MyWork { format, .. }
+            | Commands::Search { format, .. } => format,
+            _ => panic!("Expected a list command for {args:?}"),
+        };
+        assert_eq!(format, Some(expected));
+    }
+
+    // --pretty works with --format json, --json and --format are mutually exclusive
+    assert!(Cli::try_parse_from(["linear", "issues", "--format", "json", "--pretty"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "issues", "--json", "--format", "csv"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "issues", "--format", "xml"]).is_err());
+}
+
+#[test]
+fn test_template_flags_and_named_templates() {
+    use crate::config::{Config, ConfigTemplates};
+    use crate::formats::Format;
+    use clap::Parser;
+    use std::collections::HashMap;
+
+    let config = Config {
+        templates: Some(ConfigTemplates {
+            named: HashMap::from([("brief".to_string(), "{{identifier}}".to_string())]),
+        }),
+        ..Default::default()
+    };
+
+    let mut cli = Cli::try_parse_from(["linear", "issues", "--template", "brief"]).unwrap();
+    apply_config_defaults(&mut cli, &config).unwrap();
+    match cli.command {
+        Commands::Issues {
+            format, template, ..
+        } => {
+            assert_eq!(format, Some(Format::Template("{{identifier}}".to_string())));
+            assert!(template.is_none());
+        }
+        _ => panic!("Expected Issues command"),
+    }
+
+    let mut cli = Cli::try_parse_from(["linear", "teams", "--format", "template={{key}}"]).unwrap();
+    apply_config_defaults(&mut cli, &config).unwrap();
+    match cli.command {
+        Commands::Teams { format, .. } => {
+            assert_eq!(format, Some(Format::Template("{{key}}".to_string())));
+        }
+        _ => panic!("Expected Teams command"),
+    }
+
+    assert!(Cli::try_parse_from(["linear", "issues", "--template", "{{title | nope}}"]).is_err());
+
+    let mut cli = Cli::try_parse_from(["linear", "issues", "--template", "breif"]).unwrap();
+    let err = apply_config_defaults(&mut cli, &config).unwrap_err();
+    assert!(err.to_string().contains("Unknown template 'breif'"));
+    assert!(err.to_string().contains("configured templates: brief"));
+
+    let mut cli = Cli::try_parse_from(["linear", "teams", "--template", "brief"]).unwrap();
+    let err = apply_config_defaults(&mut cli, &Config::default()).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("no named templates are configured"));
+    assert!(
+        Cli::try_parse_from(["linear", "issues", "--template", "x", "--format", "csv"]).is_err()
+    );
+}
+
+#[test]
+fn test_parse_api_command() {
+    let cli = Cli::try_parse_from([
+        "linear",
+        "api",
+        "query($first: Int) { issues(first: $first) { nodes { id } } }",
+        "-F",
+        "first=5",
+        "--field",
+        "team=ENG",
+        "--paginate",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Api {
+            query,
+            fields,
+            paginate,
+        } => {
+            assert!(query.unwrap().starts_with("query($first: Int)"));
+            assert_eq!(fields.len(), 2);
+            assert_eq!(fields[0].name, "first");
+            assert_eq!(fields[0].value, serde_json::json!(5));
+            assert_eq!(fields[1].value, serde_json::json!("ENG"));
+            assert!(paginate);
+        }
+        _ => panic!("Expected Api command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "api"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Commands::Api { query: None, ref fields, paginate: false } if fields.is_empty()
+    ));
+
+    assert!(Cli::try_parse_from(["linear", "api", "{ viewer { id } }", "-F", "novalue"]).is_err());
+}
+
+#[test]
+fn test_output_format_resolution() {
+    use crate::formats::Format;
+
+    assert_eq!(output_format(false, None), Format::Table);
+    assert_eq!(output_format(true, None), Format::Json);
+    assert_eq!(output_format(false, Some(Format::Csv)), Format::Csv);
+}
+
+#[test]
+fn test_page_limit_from_flags() {
+    assert_eq!(page_limit(20, false), PageLimit::Count(20));
+    assert_eq!(page_limit(20, true), PageLimit::All);
+}
+
 #[test]
 fn test_json_output_can_be_parsed() {
     use crate::output::{JsonFormatter, OutputFormat};
@@ -689,6 +1091,7 @@ fn test_json_output_can_be_parsed() {
             assignee_id: Some("user-1".to_string()),
             team: Some("ENG".to_string()),
             team_id: "team-eng-123".to_string(),
+            ..Default::default()
         },
         Issue {
             id: "2".to_string(),
@@ -700,6 +1103,7 @@ fn test_json_output_can_be_parsed() {
             assignee_id: None,
             team: Some("ENG".to_string()),
             team_id: "team-eng-124".to_string(),
+            ..Default::default()
         },
     ];
 
@@ -964,6 +1368,9 @@ fn test_create_command_args_struct() {
         cycle: None,
         project: None,
         project_id: None,
+        milestone: None,
+        parent: None,
+        due: None,
         from_file: None,
         open: true,
         dry_run: false,
@@ -1120,3 +1527,982 @@ fn test_parse_create_command_empty_values() {
         _ => panic!("Expected Create command"),
     }
 }
+
+#[test]
+fn test_parse_parent_arguments() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear", "create", "--title", "Child", "--team", "ENG", "--parent", "ENG-100",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Create { parent, .. } => assert_eq!(parent, Some("ENG-100".to_string())),
+        _ => panic!("Expected Create command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "update", "ENG-123", "--parent", "none"]).unwrap();
+    match cli.command {
+        Commands::Update { ids, parent, .. } => {
+            assert_eq!(ids, vec!["ENG-123"]);
+            assert_eq!(parent, Some("none".to_string()));
+        }
+        _ => panic!("Expected Update command"),
+    }
+}
+
+#[test]
+fn test_parse_issue_tree_flag() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "issue", "ENG-100", "--tree", "--json"]).unwrap();
+    match cli.command {
+        Commands::Issue { id, json, tree, .. } => {
+            assert_eq!(id, "ENG-100");
+            assert!(json);
+            assert!(tree);
+        }
+        _ => panic!("Expected Issue command"),
+    }
+
+    // A tree has no markdown to render raw
+    assert!(Cli::try_parse_from(["linear", "issue", "ENG-100", "--tree", "--raw"]).is_err());
+}
+
+#[test]
+fn test_parse_due_arguments() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "create",
+        "--title",
+        "Ship",
+        "--team",
+        "ENG",
+        "--due",
+        "2026-11-01",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Create { due, .. } => assert_eq!(due, Some("2026-11-01".to_string())),
+        _ => panic!("Expected Create command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "update", "ENG-123", "--due", "none"]).unwrap();
+    match cli.command {
+        Commands::Update { due, .. } => assert_eq!(due, Some(String::new())),
+        _ => panic!("Expected Update command"),
+    }
+
+    assert!(Cli::try_parse_from(["linear", "update", "ENG-123", "--due", "someday"]).is_err());
+}
+
+#[test]
+fn test_parse_overdue_filters() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "issues", "--overdue"]).unwrap();
+    match cli.command {
+        Commands::Issues { filters, .. } => assert!(filters.overdue),
+        _ => panic!("Expected Issues command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "my-work", "--due-before", "7d"]).unwrap();
+    match cli.command {
+        Commands::MyWork {
+            due_before,
+            overdue,
+            ..
+        } => {
+            assert_eq!(due_before, Some("P7D".to_string()));
+            assert!(!overdue);
+        }
+        _ => panic!("Expected MyWork command"),
+    }
+
+    assert!(Cli::try_parse_from(["linear", "my-work", "--overdue", "--due-before", "7d"]).is_err());
+}
+
+#[test]
+fn test_parse_archive_unarchive_delete() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "archive", "ENG-1", "ENG-2", "--force"]).unwrap();
+    match cli.command {
+        Commands::Archive { ids, force } => {
+            assert_eq!(ids, vec!["ENG-1", "ENG-2"]);
+            assert!(force);
+        }
+        _ => panic!("Expected Archive command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "unarchive", "ENG-1"]).unwrap();
+    match cli.command {
+        Commands::Unarchive { ids } => assert_eq!(ids, vec!["ENG-1"]),
+        _ => panic!("Expected Unarchive command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "delete", "ENG-3", "--permanent"]).unwrap();
+    match cli.command {
+        Commands::Delete {
+            ids,
+            permanent,
+            force,
+        } => {
+            assert_eq!(ids, vec!["ENG-3"]);
+            assert!(permanent);
+            assert!(!force);
+        }
+        _ => panic!("Expected Delete command"),
+    }
+
+    // At least one issue is required
+    assert!(Cli::try_parse_from(["linear", "archive"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "delete"]).is_err());
+}
+
+#[test]
+fn test_parse_bulk_targets() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "update",
+        "ENG-1",
+        "ENG-2",
+        "--cycle",
+        "current",
+        "--dry-run",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Update {
+            ids,
+            filter,
+            cycle,
+            dry_run,
+            ..
+        } => {
+            assert_eq!(ids, vec!["ENG-1", "ENG-2"]);
+            assert!(filter.is_none());
+            assert_eq!(cycle, Some("current".to_string()));
+            assert!(dry_run);
+        }
+        _ => panic!("Expected Update command"),
+    }
+
+    let cli =
+        Cli::try_parse_from(["linear", "close", "--where", "label:stale state:todo"]).unwrap();
+    match cli.command {
+        Commands::Close { ids, filter, .. } => {
+            assert!(ids.is_empty());
+            let filter = filter.unwrap();
+            assert_eq!(filter.labels, vec!["stale"]);
+            assert_eq!(filter.status, Some("todo".to_string()));
+        }
+        _ => panic!("Expected Close command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "reopen", "-"]).unwrap();
+    match cli.command {
+        Commands::Reopen { ids, .. } => assert_eq!(ids, vec!["-"]),
+        _ => panic!("Expected Reopen command"),
+    }
+
+    // Without IDs or --where, the command picks an issue interactively
+    let cli = Cli::try_parse_from(["linear", "close"]).unwrap();
+    match cli.command {
+        Commands::Close { ids, filter, .. } => {
+            assert!(ids.is_empty());
+            assert!(filter.is_none());
+        }
+        _ => panic!("Expected Close command"),
+    }
+    assert!(
+        Cli::try_parse_from(["linear", "update", "--where", "color:red", "--title", "x"]).is_err()
+    );
+}
+
+#[test]
+fn test_split_comment_args() {
+    let ids = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        split_comment_args(ids(&["ENG-1", "Fixed in #42"]), None),
+        (ids(&["ENG-1"]), Some("Fixed in #42".to_string()))
+    );
+    assert_eq!(
+        split_comment_args(ids(&["ENG-1", "ENG-2"]), None),
+        (ids(&["ENG-1", "ENG-2"]), None)
+    );
+    assert_eq!(
+        split_comment_args(ids(&["ENG-1", "done"]), Some("note".to_string())),
+        (ids(&["ENG-1", "done"]), Some("note".to_string()))
+    );
+    assert_eq!(
+        split_comment_args(ids(&["ENG-1"]), None),
+        (ids(&["ENG-1"]), None)
+    );
+}
+
+#[test]
+fn test_parse_pick_command() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "pick"]).unwrap();
+    assert!(matches!(cli.command, Commands::Pick));
+
+    let cli = Cli::try_parse_from(["linear", "comment", "-m", "Looks good"]).unwrap();
+    match cli.command {
+        Commands::Comment { ids, message, .. } => {
+            assert!(ids.is_empty());
+            assert_eq!(message.as_deref(), Some("Looks good"));
+        }
+        _ => panic!("Expected Comment command"),
+    }
+}
+
+#[test]
+fn test_parse_edit_command() {
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "edit", "ENG-123", "--force"]).unwrap();
+    match cli.command {
+        Commands::Edit { id, force } => {
+            assert_eq!(id.as_deref(), Some("ENG-123"));
+            assert!(force);
+        }
+        _ => panic!("Expected Edit command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "edit"]).unwrap();
+    assert!(matches!(cli.command, Commands::Edit { id: None, .. }));
+}
+
+#[test]
+fn test_parse_cycles_commands() {
+    use crate::cli::CycleCommand;
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "cycles", "list", "--team", "ENG", "--json"]).unwrap();
+    match cli.command {
+        Commands::Cycles {
+            command: CycleCommand::List { view },
+        } => {
+            assert_eq!(view.team.as_deref(), Some("ENG"));
+            assert!(view.json);
+        }
+        _ => panic!("Expected cycles list command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "cycles", "show", "42"]).unwrap();
+    assert!(matches!(
+        cli.command,
+        Commands::Cycles {
+            command: CycleCommand::Show { ref cycle, .. },
+        } if cycle == "42"
+    ));
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "cycles",
+        "add",
+        "ENG-1",
+        "ENG-2",
+        "--cycle",
+        "next",
+        "--dry-run",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Cycles {
+            command:
+                CycleCommand::Add {
+                    ids,
+                    cycle,
+                    dry_run,
+                    ..
+                },
+        } => {
+            assert_eq!(ids, ["ENG-1", "ENG-2"]);
+            assert_eq!(cycle, "next");
+            assert!(dry_run);
+        }
+        _ => panic!("Expected cycles add command"),
+    }
+
+    // Adding needs a target cycle
+    assert!(Cli::try_parse_from(["linear", "cycles", "add", "ENG-1"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "cycles", "rollover", "--force"]).is_ok());
+    assert!(Cli::try_parse_from(["linear", "cycles", "current", "--pretty"]).is_err());
+}
+
+#[test]
+fn test_parse_report_commands() {
+    use crate::cli::ReportCommand;
+    use crate::formats::Format;
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from(["linear", "report", "burndown", "--team", "ENG"]).unwrap();
+    match cli.command {
+        Commands::Report {
+            command:
+                ReportCommand::Burndown {
+                    team,
+                    cycle,
+                    format,
+                },
+        } => {
+            assert_eq!(team.as_deref(), Some("ENG"));
+            assert_eq!(cycle, "current");
+            assert_eq!(format, Format::Table);
+        }
+        _ => panic!("Expected report burndown command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "linear", "report", "velocity", "--last", "3", "--format", "csv",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Report {
+            command: ReportCommand::Velocity { last, format, .. },
+        } => {
+            assert_eq!(last, 3);
+            assert_eq!(format, Format::Csv);
+        }
+        _ => panic!("Expected report velocity command"),
+    }
+
+    assert!(Cli::try_parse_from(["linear", "report", "velocity", "--last", "0"]).is_err());
+    assert!(Cli::try_parse_from(["linear", "report", "burndown", "--format", "ndjson"]).is_err());
+    assert!(Cli::try_parse_from([
+        "linear",
+        "report",
+        "burndown",
+        "--format",
+        "template={{day}}"
+    ])
+    .is_err());
+}
+
+#[test]
+fn test_parse_sync_commands() {
+    use crate::cli::SyncCommand;
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear", "sync", "pull", "--dir", "./issues", "--team", "ENG", "--force",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Sync {
+            command:
+                SyncCommand::Pull {
+                    dir,
+                    filters,
+                    force,
+                },
+        } => {
+            assert_eq!(dir, "./issues");
+            assert_eq!(filters.team.as_deref(), Some("ENG"));
+            assert!(force);
+        }
+        _ => panic!("Expected sync pull command"),
+    }
+
+    let cli = Cli::try_parse_from(["linear", "sync", "push", "--dry-run"]).unwrap();
+    match cli.command {
+        Commands::Sync {
+            command: SyncCommand::Push { dir, team, dry_run },
+        } => {
+            assert_eq!(dir, ".");
+            assert!(team.is_none());
+            assert!(dry_run);
+        }
+        _ => panic!("Expected sync push command"),
+    }
+}
+
+#[test]
+fn test_parse_project_commands() {
+    use crate::cli::ProjectCommand;
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear", "projects", "--team", "ENG", "--state", "started", "--lead", "me",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Projects {
+            team, state, lead, ..
+        } => {
+            assert_eq!(team.as_deref(), Some("ENG"));
+            assert_eq!(state.as_deref(), Some("started"));
+            assert_eq!(lead.as_deref(), Some("me"));
+        }
+        _ => panic!("Expected projects command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "project",
+        "create",
+        "Mobile App",
+        "--lead",
+        "me",
+        "--target-date",
+        "2026-12-31",
+        "--state",
+        "Planned",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Project {
+            command:
+                ProjectCommand::Create {
+                    name,
+                    team,
+                    lead,
+                    target_date,
+                    state,
+                    ..
+                },
+        } => {
+            assert_eq!(name, "Mobile App");
+            assert!(team.is_none());
+            assert_eq!(lead.as_deref(), Some("me"));
+            assert_eq!(target_date.as_deref(), Some("2026-12-31"));
+            assert_eq!(state.as_deref(), Some("Planned"));
+        }
+        _ => panic!("Expected project create command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "project",
+        "update",
+        "Mobile App",
+        "--target-date",
+        "none",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Project {
+            command:
+                ProjectCommand::Update {
+                    project,
+                    target_date,
+                    name,
+                    ..
+                },
+        } => {
+            assert_eq!(project, "Mobile App");
+            assert_eq!(target_date.as_deref(), Some("none"));
+            assert!(name.is_none());
+        }
+        _ => panic!("Expected project update command"),
+    }
+
+    let cli =
+        Cli::try_parse_from(["linear", "project", "archive", "Mobile App", "--force"]).unwrap();
+    match cli.command {
+        Commands::Project {
+            command: ProjectCommand::Archive { project, force },
+        } => {
+            assert_eq!(project, "Mobile App");
+            assert!(force);
+        }
+        _ => panic!("Expected project archive command"),
+    }
+}
+
+#[test]
+fn test_parse_project_update_commands() {
+    use crate::cli::{HealthArg, ProjectCommand};
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "project",
+        "update-post",
+        "Mobile App",
+        "--health",
+        "at-risk",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Project {
+            command: ProjectCommand::UpdatePost { project, health },
+        } => {
+            assert_eq!(project, "Mobile App");
+            assert_eq!(health, Some(HealthArg::AtRisk));
+        }
+        _ => panic!("Expected project update-post command"),
+    }
+
+    let invalid = Cli::try_parse_from([
+        "linear",
+        "project",
+        "update-post",
+        "Mobile App",
+        "--health",
+        "fine",
+    ]);
+    assert!(invalid.is_err());
+
+    let cli =
+        Cli::try_parse_from(["linear", "project", "updates", "Mobile App", "--json"]).unwrap();
+    match cli.command {
+        Commands::Project {
+            command: ProjectCommand::Updates { limit, json, .. },
+        } => {
+            assert_eq!(limit, 10);
+            assert!(json);
+        }
+        _ => panic!("Expected project updates command"),
+    }
+}
+
+#[test]
+fn test_parse_milestone_commands() {
+    use crate::cli::MilestoneCommand;
+    use clap::Parser;
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "milestones",
+        "list",
+        "--project",
+        "Mobile App",
+        "--format",
+        "csv",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Milestones {
+            command: MilestoneCommand::List {
+                project, format, ..
+            },
+        } => {
+            assert_eq!(project, "Mobile App");
+            assert_eq!(format, Some(Format::Csv));
+        }
+        _ => panic!("Expected milestones list command"),
+    }
+
+    let cli = Cli::try_parse_from([
+        "linear",
+        "milestones",
+        "update",
+        "Beta",
+        "--project",
+        "Mobile App",
+        "--target-date",
+        "none",
+    ])
+    .unwrap();
+    match cli.command {
+        Commands::Milestones {
+            command:
+                MilestoneCommand::Update {
+                    milestone,
+                    target_date,
+                    name,
+                    ..
+                },
+        } => {
+            assert_eq!(milestone, "Beta");
+            assert_eq!(target_date.as_deref(), Some("none"));
+            assert!(name.is_none());
+        }
+        _ => panic!("Expected milestones update command"),
+    }
+
+    // Every milestone command is scoped to a project
+    let missing_project = Cli::try_parse_from(["linear", "milestones", "delete", "Beta"]);
+    assert!(missing_project.is_err());
+
+    let cli = Cli::try_parse_from(["linear", "update", "ENG-123", "--milestone", "Beta"]).unwrap();
+    match cli.command {
+        Commands::Update { milestone, .. } => assert_eq!(milestone.as_deref(), Some("Beta")),
+        _ => panic!("Expected update command"),
+    }
+}
+
+#[test]
+fn test_update_ignores_default_assignee() {
+    use crate::config::Config;
+    use clap::Parser;
+
+    let config = Config {
+        default_assignee: Some("me".to_string()),
+        ..Default::default()
+    };
+
+    let mut cli = Cli::try_parse_from([
+        "linear",
+        "update",
+        "--where",
+        "label:stale",
+        "--status",
+        "canceled",
+    ])
+    .unwrap();
+    apply_config_defaults(&mut cli, &config).unwrap();
+    match cli.command {
+        Commands::Update { assignee, .. } => assert!(assignee.is_none()),
+        _ => panic!("Expected update command"),
+    }
+
+    let mut cli =
+        Cli::try_parse_from(["linear", "update", "ENG-1", "ENG-2", "--priority", "2"]).unwrap();
+    apply_config_defaults(&mut cli, &config).unwrap();
+    match cli.command {
+        Commands::Update { assignee, .. } => assert!(assignee.is_none()),
+        _ => panic!("Expected update command"),
+    }
+}
+
+/// A ListIssues node with just the fields the sort test needs
+fn list_issue_node(identifier: &str, priority: f64) -> serde_json::Value {
+    serde_json::json!({
+        "id": format!("id-{identifier}"),
+        "identifier": identifier,
+        "title": format!("Issue {identifier}"),
+        "state": { "id": "state-todo", "name": "Todo" },
+        "assignee": null,
+        "team": { "id": "team-1", "key": "ENG" },
+        "priority": priority,
+        "priorityLabel": "",
+        "estimate": null,
+        "dueDate": null,
+        "createdAt": "2024-01-01T00:00:00.000Z",
+        "updatedAt": "2024-01-01T00:00:00.000Z",
+        "labels": { "nodes": [] },
+        "cycle": null,
+        "project": null
+    })
+}
+
+#[tokio::test]
+async fn test_sort_fetches_every_match_before_limiting() {
+    use crate::columns::{IssueColumn, SortKey};
+
+    let mut server = mockito::Server::new_async().await;
+    // Only a full-size page request is answered, so a `first: 1` fetch would fail the test
+    let mock = server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "operationName": "ListIssues",
+            "variables": { "first": MAX_PAGE_SIZE }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "data": {
+                    "issues": {
+                        "nodes": [
+                            list_issue_node("ENG-1", 3.0),
+                            list_issue_node("ENG-2", 1.0),
+                            list_issue_node("ENG-3", 2.0)
+                        ],
+                        "pageInfo": { "hasNextPage": false, "endCursor": null }
+                    }
+                }
+            })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = LinearClient::builder()
+        .auth_token(SecretString::new(
+            "test_api_key".to_string().into_boxed_str(),
+        ))
+        .base_url(Some(server.url()))
+        .build()
+        .unwrap();
+    let sort = [SortKey {
+        column: IssueColumn::Priority,
+        descending: false,
+    }];
+
+    let issues = fetch_sorted_issues(&client, PageLimit::Count(2), IssueFilters::default(), &sort)
+        .await
+        .unwrap();
+
+    let identifiers: Vec<&str> = issues.iter().map(|i| i.identifier.as_str()).collect();
+    assert_eq!(identifiers, vec!["ENG-2", "ENG-3"]);
+    mock.assert_async().await;
+}
+
+/// A GetIssue response for the sync push tests
+fn sync_issue_response(id: &str, title: &str, due: Option<&str>, updated_at: &str) -> String {
+    serde_json::json!({
+        "data": {
+            "issue": {
+                "id": id,
+                "identifier": "ENG-7",
+                "title": title,
+                "description": "Body",
+                "state": { "id": "state-todo", "name": "Todo", "type": "unstarted" },
+                "assignee": null,
+                "team": { "id": "team-1", "key": "ENG", "name": "Engineering" },
+                "project": null,
+                "projectMilestone": null,
+                "labels": { "nodes": [] },
+                "parent": null,
+                "children": { "nodes": [] },
+                "priority": 0.0,
+                "priorityLabel": "No priority",
+                "estimate": null,
+                "cycle": null,
+                "dueDate": due,
+                "createdAt": "2026-09-01T00:00:00Z",
+                "updatedAt": updated_at,
+                "url": "https://linear.app/test/issue/ENG-7"
+            }
+        }
+    })
+    .to_string()
+}
+
+/// An issueCreate or issueUpdate payload for the sync push tests
+fn sync_mutation_response(field: &str, id: &str, updated_at: &str) -> String {
+    serde_json::json!({
+        "data": {
+            field: {
+                "success": true,
+                "issue": {
+                    "id": id,
+                    "identifier": "ENG-7",
+                    "title": "Fix login",
+                    "description": "Body",
+                    "priority": 0.0,
+                    "priorityLabel": "No priority",
+                    "dueDate": null,
+                    "state": { "id": "state-todo", "name": "Todo", "type": "unstarted" },
+                    "assignee": null,
+                    "team": { "id": "team-1", "key": "ENG", "name": "Engineering" },
+                    "labels": { "nodes": [] },
+                    "url": "https://linear.app/test/issue/ENG-7",
+                    "createdAt": "2026-09-01T00:00:00Z",
+                    "updatedAt": updated_at
+                },
+                "lastSyncId": 1.0
+            }
+        }
+    })
+    .to_string()
+}
+
+fn sync_mock(server: &mut mockito::ServerGuard, operation: &str, body: String) -> mockito::Mock {
+    server
+        .mock("POST", "/graphql")
+        .match_body(mockito::Matcher::PartialJson(
+            serde_json::json!({ "operationName": operation }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(body)
+}
+
+fn sync_client(server: &mockito::ServerGuard) -> LinearClient {
+    LinearClient::builder()
+        .auth_token(SecretString::new(
+            "test_api_key".to_string().into_boxed_str(),
+        ))
+        .base_url(Some(server.url()))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sync_push_creates_issue_and_renames_file() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("idea.md"),
+        "---\ntitle: Fix login\nteam: 0f4c8a7e-2b1d-4c3e-9f6a-7d8e9f0a1b2c\ndue: 2026-10-23\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let mut server = mockito::Server::new_async().await;
+    let create = sync_mock(
+        &mut server,
+        "CreateIssue",
+        sync_mutation_response("issueCreate", "issue-7", "2026-10-02T00:00:00Z"),
+    )
+    .expect(1)
+    .create();
+    let refresh = sync_mock(
+        &mut server,
+        "GetIssue",
+        sync_issue_response(
+            "issue-7",
+            "Fix login",
+            Some("2026-10-23"),
+            "2026-10-02T00:00:00Z",
+        ),
+    )
+    .expect(1)
+    .create();
+
+    let outcome = push_issue_files(&sync_client(&server), dir.path(), None, false, false, false)
+        .await
+        .unwrap();
+
+    create.assert();
+    refresh.assert();
+    assert_eq!(outcome.report.succeeded, ["ENG-7"]);
+    assert!(outcome.report.failed.is_empty());
+    assert!(!dir.path().join("idea.md").exists());
+    let text = std::fs::read_to_string(dir.path().join("ENG-7.md")).unwrap();
+    assert!(text.contains("id: issue-7"));
+    assert!(text.contains("team: ENG"));
+    assert!(text.contains("updatedAt: 2026-10-02T00:00:00Z"));
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sync_push_updates_and_writes_back_resolved_values() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("ENG-7.md");
+    std::fs::write(
+        &path,
+        "---\ntitle: Fix login\nteam: ENG\nstate: Todo\ndue: friday\nid: issue-7\nupdatedAt: 2026-10-01T00:00:00Z\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let mut server = mockito::Server::new_async().await;
+    // Linear reports the date "friday" resolved to, both before and after the update
+    let get = sync_mock(
+        &mut server,
+        "GetIssue",
+        sync_issue_response(
+            "issue-7",
+            "Fix login",
+            Some("2026-10-23"),
+            "2026-10-01T00:00:00Z",
+        ),
+    )
+    .expect(3)
+    .create();
+    let update = sync_mock(
+        &mut server,
+        "UpdateIssue",
+        sync_mutation_response("issueUpdate", "issue-7", "2026-10-01T00:00:00Z"),
+    )
+    .expect(1)
+    .create();
+    let client = sync_client(&server);
+
+    let outcome = push_issue_files(&client, dir.path(), None, false, false, false)
+        .await
+        .unwrap();
+    assert_eq!(outcome.report.succeeded, ["ENG-7"]);
+    let text = std::fs::read_to_string(&path).unwrap();
+    assert!(text.contains("due: 2026-10-23"), "{text}");
+    assert!(!text.contains("friday"));
+
+    // Forgetting the sync state makes the next push compare the file with Linear again;
+    // the written-back values match, so nothing is pushed
+    std::fs::remove_file(dir.path().join(crate::constants::sync::STATE_FILE)).unwrap();
+    let outcome = push_issue_files(&client, dir.path(), None, false, false, false)
+        .await
+        .unwrap();
+    assert!(outcome.report.succeeded.is_empty());
+    assert!(outcome.conflicts.is_empty());
+
+    get.assert();
+    update.assert();
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sync_push_reports_conflicts_without_updating() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let original = "---\ntitle: Fix the login page\nteam: ENG\nstate: Todo\nid: issue-7\nupdatedAt: 2026-09-15T00:00:00Z\n---\n\nBody\n";
+    std::fs::write(dir.path().join("ENG-7.md"), original).unwrap();
+
+    let mut server = mockito::Server::new_async().await;
+    let get = sync_mock(
+        &mut server,
+        "GetIssue",
+        sync_issue_response("issue-7", "Fix login", None, "2026-10-01T00:00:00Z"),
+    )
+    .expect(1)
+    .create();
+    let update = sync_mock(
+        &mut server,
+        "UpdateIssue",
+        sync_mutation_response("issueUpdate", "issue-7", "2026-10-02T00:00:00Z"),
+    )
+    .expect(0)
+    .create();
+
+    let outcome = push_issue_files(&sync_client(&server), dir.path(), None, false, false, false)
+        .await
+        .unwrap();
+
+    get.assert();
+    update.assert();
+    assert!(outcome.report.succeeded.is_empty());
+    assert_eq!(
+        outcome.conflicts,
+        [(
+            "ENG-7.md".to_string(),
+            "ENG-7".to_string(),
+            "2026-10-01T00:00:00Z".to_string()
+        )]
+    );
+    let text = std::fs::read_to_string(dir.path().join("ENG-7.md")).unwrap();
+    assert_eq!(text, original);
+}
+
+#[tokio::test]
+#[serial]
+async fn test_sync_push_skips_unchanged_files() {
+    let dir = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("ENG-7.md"),
+        "---\ntitle: Fix login\nteam: ENG\nstate: Todo\nid: issue-7\nupdatedAt: 2026-10-01T00:00:00Z\n---\n\nBody\n",
+    )
+    .unwrap();
+
+    let mut server = mockito::Server::new_async().await;
+    let get = sync_mock(
+        &mut server,
+        "GetIssue",
+        sync_issue_response("issue-7", "Fix login", None, "2026-10-01T00:00:00Z"),
+    )
+    .expect(1)
+    .create();
+    let update = sync_mock(
+        &mut server,
+        "UpdateIssue",
+        sync_mutation_response("issueUpdate", "issue-7", "2026-10-02T00:00:00Z"),
+    )
+    .expect(0)
+    .create();
+    let client = sync_client(&server);
+
+    // The first push finds nothing to change and records the file as synced
+    let outcome = push_issue_files(&client, dir.path(), None, false, false, false)
+        .await
+        .unwrap();
+    assert!(outcome.report.succeeded.is_empty());
+    assert!(outcome.report.failed.is_empty());
+
+    // The second doesn't even ask Linear
+    push_issue_files(&client, dir.path(), None, false, false, false)
+        .await
+        .unwrap();
+
+    get.assert();
+    update.assert();
+}
@@ -0,0 +1,1062 @@
+// ABOUTME: Full-screen terminal UI for browsing and triaging issues (`linear tui`)
+// ABOUTME: A list pane, a rendered detail pane, and keys that edit the selected issue in place
+
+use chrono::Utc;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, style::Print, terminal};
+use linear_sdk::{
+    CreateCommentInput, Issue, IssueFilters, LinearClient, LinearError, PageLimit, Result, Team,
+    UpdateIssueInput, UpdatedIssue,
+};
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::{Duration, Instant};
+use unicode_width::UnicodeWidthChar;
+
+use crate::constants::tui::{POLL_INTERVAL_MS, REFRESH_INTERVAL_SECS};
+use crate::output::{OutputFormat, TableFormatter};
+
+const HELP: &str = "j/k move  J/K scroll  s state  a assignee  p priority  l labels  c cycle  \
+                    m comment  o open  r reload  q quit";
+
+const PRIORITIES: &[(&str, &str)] = &[
+    ("No priority", "0"),
+    ("Urgent", "1"),
+    ("High", "2"),
+    ("Normal", "3"),
+    ("Low", "4"),
+];
+
+/// Rows the detail pane scrolls per J/K press
+const DETAIL_SCROLL_STEP: usize = 3;
+
+/// The issue field a picker or prompt edits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    State,
+    Assignee,
+    Priority,
+    Labels,
+    Cycle,
+    Comment,
+}
+
+impl Field {
+    fn prompt(self) -> &'static str {
+        match self {
+            Field::State => "State",
+            Field::Assignee => "Assignee",
+            Field::Priority => "Priority",
+            Field::Labels => "Labels (comma-separated)",
+            Field::Cycle => "Cycle (current, a number, or a name)",
+            Field::Comment => "Comment",
+        }
+    }
+}
+
+/// One entry in a picker: what is shown and what is applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Choice {
+    pub label: String,
+    pub value: String,
+}
+
+impl Choice {
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// A list of choices narrowed by typing
+#[derive(Debug, Clone)]
+pub struct Picker {
+    field: Field,
+    choices: Vec<Choice>,
+    query: String,
+    selected: usize,
+}
+
+impl Picker {
+    /// Choices whose label contains the typed characters in order, ignoring case
+    fn visible(&self) -> Vec<&Choice> {
+        self.choices
+            .iter()
+            .filter(|choice| subsequence_match(&self.query, &choice.label))
+            .collect()
+    }
+}
+
+fn subsequence_match(query: &str, candidate: &str) -> bool {
+    let mut candidate = candidate.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|wanted| candidate.any(|c| c == wanted))
+}
+
+/// A single line of text being typed
+#[derive(Debug, Clone)]
+pub struct Prompt {
+    field: Field,
+    text: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Mode {
+    Browse,
+    Pick(Picker),
+    Input(Prompt),
+}
+
+/// What the event loop should do after a key press
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Quit,
+    Reload,
+    OpenInBrowser,
+    /// Fetch the choices for a picker over this field
+    Choose(Field),
+    /// Apply a picked value or typed text to the selected issue
+    Apply(Field, String),
+}
+
+/// A loaded issue's URL and its rendered detail view
+#[derive(Debug, Clone)]
+struct Detail {
+    url: String,
+    lines: Vec<String>,
+}
+
+/// Screen state; everything here is independent of the terminal and the API
+pub struct App {
+    issues: Vec<Issue>,
+    selected: usize,
+    list_offset: usize,
+    detail_scroll: usize,
+    details: HashMap<String, Detail>,
+    mode: Mode,
+    message: String,
+    use_color: bool,
+}
+
+impl App {
+    pub fn new(issues: Vec<Issue>, use_color: bool) -> Self {
+        Self {
+            issues,
+            selected: 0,
+            list_offset: 0,
+            detail_scroll: 0,
+            details: HashMap::new(),
+            mode: Mode::Browse,
+            message: String::new(),
+            use_color,
+        }
+    }
+
+    pub fn selected_issue(&self) -> Option<&Issue> {
+        self.issues.get(self.selected)
+    }
+
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = message.into();
+    }
+
+    /// Whether the selected issue's detail still has to be fetched
+    fn needs_detail(&self) -> bool {
+        self.selected_issue()
+            .is_some_and(|issue| !self.details.contains_key(&issue.id))
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.issues.is_empty() {
+            return;
+        }
+        let last = self.issues.len() - 1;
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+        self.detail_scroll = 0;
+    }
+
+    /// Show a picker over `choices`, starting on the one matching `current`
+    pub fn open_picker(&mut self, field: Field, choices: Vec<Choice>, current: Option<&str>) {
+        let selected = current
+            .and_then(|current| choices.iter().position(|choice| choice.value == current))
+            .unwrap_or(0);
+        self.mode = Mode::Pick(Picker {
+            field,
+            choices,
+            query: String::new(),
+            selected,
+        });
+    }
+
+    fn open_prompt(&mut self, field: Field, text: String) {
+        self.mode = Mode::Input(Prompt { field, text });
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.kind == KeyEventKind::Release {
+            return Action::None;
+        }
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+
+        match std::mem::replace(&mut self.mode, Mode::Browse) {
+            Mode::Browse => self.browse_key(key),
+            Mode::Pick(picker) => self.pick_key(picker, key),
+            Mode::Input(prompt) => self.input_key(prompt, key),
+        }
+    }
+
+    fn browse_key(&mut self, key: KeyEvent) -> Action {
+        self.message.clear();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Action::Quit,
+            KeyCode::Char('j') | KeyCode::Down => self.move_selection(1),
+            KeyCode::Char('k') | KeyCode::Up => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(10),
+            KeyCode::PageUp => self.move_selection(-10),
+            KeyCode::Char('g') | KeyCode::Home => self.move_selection(isize::MIN),
+            KeyCode::Char('G') | KeyCode::End => self.move_selection(isize::MAX),
+            KeyCode::Char('J') => self.detail_scroll += DETAIL_SCROLL_STEP,
+            KeyCode::Char('K') => {
+                self.detail_scroll = self.detail_scroll.saturating_sub(DETAIL_SCROLL_STEP)
+            }
+            KeyCode::Char('r') => return Action::Reload,
+            code => return self.edit_key(code),
+        }
+        Action::None
+    }
+
+    /// Keys that act on the selected issue
+    fn edit_key(&mut self, code: KeyCode) -> Action {
+        let Some(issue) = self.selected_issue() else {
+            return Action::None;
+        };
+
+        match code {
+            KeyCode::Char('s') => Action::Choose(Field::State),
+            KeyCode::Char('a') => Action::Choose(Field::Assignee),
+            KeyCode::Char('p') => {
+                let current = issue.priority.unwrap_or(0).to_string();
+                let choices = PRIORITIES
+                    .iter()
+                    .map(|(label, value)| Choice::new(*label, *value))
+                    .collect();
+                self.open_picker(Field::Priority, choices, Some(&current));
+                Action::None
+            }
+            KeyCode::Char('l') => {
+                let labels = issue
+                    .labels
+                    .iter()
+                    .map(|label| label.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.open_prompt(Field::Labels, labels);
+                Action::None
+            }
+            KeyCode::Char('c') => {
+                let cycle = issue
+                    .cycle
+                    .as_ref()
+                    .map(|cycle| cycle.number.to_string())
+                    .unwrap_or_default();
+                self.open_prompt(Field::Cycle, cycle);
+                Action::None
+            }
+            KeyCode::Char('m') => {
+                self.open_prompt(Field::Comment, String::new());
+                Action::None
+            }
+            KeyCode::Char('o') => Action::OpenInBrowser,
+            _ => Action::None,
+        }
+    }
+
+    fn pick_key(&mut self, mut picker: Picker, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Esc => return Action::None,
+            KeyCode::Enter => {
+                return match picker.visible().get(picker.selected) {
+                    Some(choice) => Action::Apply(picker.field, choice.value.clone()),
+                    None => Action::None,
+                };
+            }
+            KeyCode::Up => picker.selected = picker.selected.saturating_sub(1),
+            KeyCode::Down => {
+                let last = picker.visible().len().saturating_sub(1);
+                picker.selected = (picker.selected + 1).min(last);
+            }
+            KeyCode::Backspace => {
+                picker.query.pop();
+                picker.selected = 0;
+            }
+            KeyCode::Char(c) => {
+                picker.query.push(c);
+                picker.selected = 0;
+            }
+            _ => {}
+        }
+        self.mode = Mode::Pick(picker);
+        Action::None
+    }
+
+    fn input_key(&mut self, mut prompt: Prompt, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Esc => return Action::None,
+            KeyCode::Enter => return Action::Apply(prompt.field, prompt.text),
+            KeyCode::Backspace => {
+                prompt.text.pop();
+            }
+            KeyCode::Char(c) => prompt.text.push(c),
+            _ => {}
+        }
+        self.mode = Mode::Input(prompt);
+        Action::None
+    }
+
+    /// Replace or add changed issues, keeping the same issue selected; returns how many changed
+    pub fn merge(&mut self, changed: Vec<Issue>) -> usize {
+        let selected_id = self.selected_issue().map(|issue| issue.id.clone());
+        let count = changed.len();
+
+        for issue in changed.into_iter().rev() {
+            self.details.remove(&issue.id);
+            match self
+                .issues
+                .iter_mut()
+                .find(|existing| existing.id == issue.id)
+            {
+                Some(existing) => *existing = issue,
+                None => self.issues.insert(0, issue),
+            }
+        }
+
+        if let Some(id) = selected_id {
+            if let Some(index) = self.issues.iter().position(|issue| issue.id == id) {
+                self.selected = index;
+            }
+        }
+        count
+    }
+
+    /// Show the result of a mutation in the list right away
+    fn apply_update(&mut self, updated: &UpdatedIssue) {
+        self.details.remove(&updated.id);
+        if let Some(issue) = self.issues.iter_mut().find(|issue| issue.id == updated.id) {
+            issue.title = updated.title.clone();
+            issue.status = updated.state.name.clone();
+            issue.state_id = updated.state.id.clone();
+            issue.assignee = updated.assignee.as_ref().map(|a| a.name.clone());
+            issue.priority = updated.priority;
+            issue.priority_label = updated.priority_label.clone();
+            issue.labels = updated.labels.clone();
+            issue.due_date = updated.due_date.clone();
+        }
+    }
+
+    /// Lay out the whole screen as `height` rows of exactly `width` columns
+    pub fn render(&mut self, width: usize, height: usize) -> Vec<String> {
+        if height < 3 {
+            return vec![fit("Terminal too small", width); height];
+        }
+
+        let body = height - 2;
+        if self.selected < self.list_offset {
+            self.list_offset = self.selected;
+        } else if self.selected >= self.list_offset + body {
+            self.list_offset = self.selected + 1 - body;
+        }
+
+        let list_width = (width * 2 / 5).max(20).min(width);
+        let detail_width = width.saturating_sub(list_width + 1);
+        let detail = self.detail_rows(detail_width, body);
+
+        let mut rows = Vec::with_capacity(height);
+        let title = format!(" linear · {} issues", self.issues.len());
+        rows.push(if self.use_color {
+            fit(&title.bold().to_string(), width)
+        } else {
+            fit(&title, width)
+        });
+
+        for row in 0..body {
+            let mut line = self.list_row(self.list_offset + row, list_width);
+            if detail_width > 0 {
+                line.push('│');
+                match detail.get(row) {
+                    Some(detail_row) => line.push_str(detail_row),
+                    None => line.push_str(&" ".repeat(detail_width)),
+                }
+            }
+            rows.push(line);
+        }
+
+        // Leave the bottom-right cell empty so the terminal never scrolls
+        rows.push(fit(&self.status_line(), width.saturating_sub(1)));
+        rows
+    }
+
+    fn list_row(&self, index: usize, width: usize) -> String {
+        let Some(issue) = self.issues.get(index) else {
+            return " ".repeat(width);
+        };
+
+        let marker = if index == self.selected { '▶' } else { ' ' };
+        let status: String = issue.status.chars().take(12).collect();
+        let row = fit(
+            &format!(
+                "{marker} {:<10} {status:<12} {}",
+                issue.identifier, issue.title
+            ),
+            width,
+        );
+        if index == self.selected && self.use_color {
+            row.reversed().to_string()
+        } else {
+            row
+        }
+    }
+
+    fn detail_rows(&mut self, width: usize, height: usize) -> Vec<String> {
+        let lines: Vec<String> = match &self.mode {
+            Mode::Pick(picker) => picker_lines(picker, height),
+            _ => match self.selected_issue() {
+                None => vec!["No issues match these filters".to_string()],
+                Some(issue) => match self.details.get(&issue.id) {
+                    Some(detail) => detail.lines.clone(),
+                    None => vec!["Loading…".to_string()],
+                },
+            },
+        };
+
+        let rows: Vec<(String, usize)> = lines
+            .iter()
+            .flat_map(|line| wrap_ansi(&line.replace('\t', "    "), width))
+            .collect();
+        if !matches!(self.mode, Mode::Pick(_)) {
+            self.detail_scroll = self.detail_scroll.min(rows.len().saturating_sub(height));
+        } else {
+            self.detail_scroll = 0;
+        }
+
+        rows.into_iter()
+            .skip(self.detail_scroll)
+            .take(height)
+            .map(|(row, row_width)| pad(row, row_width, width))
+            .collect()
+    }
+
+    fn status_line(&self) -> String {
+        match &self.mode {
+            Mode::Input(prompt) => format!(" {}: {}█", prompt.field.prompt(), prompt.text),
+            Mode::Pick(_) => " ↑/↓ choose  type to filter  enter apply  esc cancel".to_string(),
+            Mode::Browse if !self.message.is_empty() => format!(" {}", self.message),
+            Mode::Browse => format!(" {HELP}"),
+        }
+    }
+}
+
+fn picker_lines(picker: &Picker, height: usize) -> Vec<String> {
+    let visible = picker.visible();
+    let mut lines = vec![
+        format!(
+            "Set {}: {}",
+            picker.field.prompt().to_lowercase(),
+            picker.query
+        ),
+        String::new(),
+    ];
+
+    let room = height.saturating_sub(lines.len()).max(1);
+    let first = (picker.selected + 1).saturating_sub(room);
+    lines.extend(
+        visible
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(room)
+            .map(|(index, choice)| {
+                let marker = if index == picker.selected { '▶' } else { ' ' };
+                format!("{marker} {}", choice.label)
+            }),
+    );
+    if visible.is_empty() {
+        lines.push("  No matches".to_string());
+    }
+    lines
+}
+
+/// Cut a line that may contain ANSI styling into rows of at most `width` columns
+///
+/// Styling is carried over to each new row; OSC sequences such as hyperlinks are dropped, since
+/// a link can't survive being split. Returns each row with its visible width.
+fn wrap_ansi(line: &str, width: usize) -> Vec<(String, usize)> {
+    let mut rows = Vec::new();
+    let mut row = String::new();
+    let mut row_width = 0;
+    let mut active = String::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            match chars.next() {
+                Some('[') => {
+                    let mut sequence = String::from("\x1b[");
+                    for next in chars.by_ref() {
+                        sequence.push(next);
+                        if ('@'..='~').contains(&next) {
+                            break;
+                        }
+                    }
+                    if sequence == "\x1b[0m" || sequence == "\x1b[m" {
+                        active.clear();
+                    } else if sequence.ends_with('m') {
+                        active.push_str(&sequence);
+                    }
+                    row.push_str(&sequence);
+                }
+                Some(']') => {
+                    while let Some(next) = chars.next() {
+                        if next == '\x07' {
+                            break;
+                        }
+                        if next == '\x1b' {
+                            chars.next();
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let char_width = c.width().unwrap_or(0);
+        if row_width + char_width > width && row_width > 0 {
+            rows.push((std::mem::replace(&mut row, active.clone()), row_width));
+            row_width = 0;
+        }
+        if char_width <= width {
+            row.push(c);
+            row_width += char_width;
+        }
+    }
+
+    rows.push((row, row_width));
+    rows
+}
+
+/// Pad a row to `width` columns, closing any styling it opened
+fn pad(mut row: String, row_width: usize, width: usize) -> String {
+    if row.contains('\x1b') {
+        row.push_str("\x1b[0m");
+    }
+    row.push_str(&" ".repeat(width.saturating_sub(row_width)));
+    row
+}
+
+/// Truncate or pad a line to exactly `width` columns
+fn fit(line: &str, width: usize) -> String {
+    let (row, row_width) = wrap_ansi(line, width).swap_remove(0);
+    pad(row, row_width, width)
+}
+
+fn terminal_error(error: std::io::Error) -> LinearError {
+    LinearError::Configuration(format!("Terminal error: {error}"))
+}
+
+/// Raw mode and the alternate screen for the session, restored when dropped
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Result<Self> {
+        terminal::enable_raw_mode().map_err(terminal_error)?;
+        let guard = Self;
+        execute!(
+            std::io::stdout(),
+            terminal::EnterAlternateScreen,
+            cursor::Hide
+        )
+        .map_err(terminal_error)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(
+            std::io::stdout(),
+            cursor::Show,
+            terminal::LeaveAlternateScreen
+        );
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// The running TUI: screen state plus the client and filters it refreshes from
+struct Session<'a> {
+    client: &'a LinearClient,
+    app: App,
+    filters: IssueFilters,
+    limit: PageLimit,
+    formatter: TableFormatter,
+    teams: Option<Vec<Team>>,
+    last_refresh: Instant,
+    changed_since: String,
+}
+
+impl Session<'_> {
+    fn mark_refreshed(&mut self) {
+        self.last_refresh = Instant::now();
+        // A little overlap so edits made while a refresh was in flight aren't missed
+        self.changed_since = (Utc::now() - chrono::Duration::seconds(5)).to_rfc3339();
+    }
+
+    async fn event_loop(&mut self) -> Result<()> {
+        let poll_interval = Duration::from_millis(POLL_INTERVAL_MS);
+        let refresh_interval = Duration::from_secs(REFRESH_INTERVAL_SECS);
+
+        loop {
+            self.draw()?;
+
+            // Fetch the selected issue only once the user stops moving through the list
+            if self.app.needs_detail() && !event::poll(Duration::ZERO).map_err(terminal_error)? {
+                self.load_detail().await;
+                continue;
+            }
+
+            if event::poll(poll_interval).map_err(terminal_error)? {
+                match event::read().map_err(terminal_error)? {
+                    Event::Key(key) => {
+                        let action = self.app.handle_key(key);
+                        if action == Action::Quit {
+                            return Ok(());
+                        }
+                        if let Err(e) = self.perform(action).await {
+                            self.app.set_message(format!("Error: {e}"));
+                        }
+                    }
+                    Event::Resize(..) => {
+                        execute!(std::io::stdout(), terminal::Clear(terminal::ClearType::All))
+                            .map_err(terminal_error)?;
+                    }
+                    _ => {}
+                }
+            } else if self.last_refresh.elapsed() >= refresh_interval {
+                if let Err(e) = self.refresh_changed().await {
+                    self.app.set_message(format!("Refresh failed: {e}"));
+                }
+            }
+        }
+    }
+
+    fn draw(&mut self) -> Result<()> {
+        let (width, height) = terminal::size().map_err(terminal_error)?;
+        let rows = self.app.render(width as usize, height as usize);
+
+        let mut stdout = std::io::stdout();
+        for (y, row) in rows.iter().enumerate() {
+            queue!(stdout, cursor::MoveTo(0, y as u16), Print(row)).map_err(terminal_error)?;
+        }
+        stdout.flush().map_err(terminal_error)
+    }
+
+    async fn load_detail(&mut self) {
+        let Some(id) = self.app.selected_issue().map(|issue| issue.id.clone()) else {
+            return;
+        };
+
+        let detail = match self.client.get_issue(id.clone()).await {
+            Ok(issue) => {
+                let rendered = self
+                    .formatter
+                    .format_detailed_issue_rich(&issue, true)
+                    .unwrap_or_else(|e| format!("Failed to render issue: {e}"));
+                Detail {
+                    url: issue.url,
+                    lines: rendered.lines().map(str::to_string).collect(),
+                }
+            }
+            Err(e) => Detail {
+                url: String::new(),
+                lines: vec![format!("Failed to load issue: {e}")],
+            },
+        };
+        self.app.details.insert(id, detail);
+    }
+
+    /// Merge in issues changed since the last refresh
+    async fn refresh_changed(&mut self) -> Result<()> {
+        let filters = IssueFilters {
+            updated_since: Some(self.changed_since.clone()),
+            ..self.filters.clone()
+        };
+        self.mark_refreshed();
+
+        let changed = self
+            .client
+            .list_issues_filtered(PageLimit::All, Some(filters))
+            .await?;
+        let count = self.app.merge(changed);
+        if count > 0 {
+            let noun = if count == 1 { "issue" } else { "issues" };
+            self.app.set_message(format!("Refreshed {count} {noun}"));
+        }
+        Ok(())
+    }
+
+    async fn perform(&mut self, action: Action) -> Result<()> {
+        let Some(issue) = self.app.selected_issue().cloned() else {
+            return match action {
+                Action::Reload => self.reload().await,
+                _ => Ok(()),
+            };
+        };
+
+        match action {
+            Action::None | Action::Quit => Ok(()),
+            Action::Reload => self.reload().await,
+            Action::OpenInBrowser => {
+                if !self.app.details.contains_key(&issue.id) {
+                    self.load_detail().await;
+                }
+                match self.app.details.get(&issue.id) {
+                    Some(detail) if !detail.url.is_empty() => {
+                        open::that_detached(&detail.url).map_err(terminal_error)?;
+                        self.app.set_message(format!("Opened {}", detail.url));
+                    }
+                    _ => self.app.set_message("No URL for this issue"),
+                }
+                Ok(())
+            }
+            Action::Choose(field) => self.choose(field, &issue).await,
+            Action::Apply(field, value) => self.apply(field, value, &issue).await,
+        }
+    }
+
+    async fn reload(&mut self) -> Result<()> {
+        self.mark_refreshed();
+        let issues = self
+            .client
+            .list_issues_filtered(self.limit, Some(self.filters.clone()))
+            .await?;
+        let count = issues.len();
+        self.app = App::new(issues, self.app.use_color);
+        self.app.set_message(format!("Reloaded {count} issues"));
+        Ok(())
+    }
+
+    /// Open a picker with the selected issue's team's states or members
+    async fn choose(&mut self, field: Field, issue: &Issue) -> Result<()> {
+        match field {
+            Field::State => {
+                let team = self.client.get_team_states(issue.team_id.clone()).await?;
+                let mut states = team.states;
+                // States without a position sort first, as Option's ordering would put them
+                let position = |p: Option<f64>| p.unwrap_or(f64::NEG_INFINITY);
+                states.sort_by(|a, b| position(a.position).total_cmp(&position(b.position)));
+                let choices = states
+                    .into_iter()
+                    .map(|state| Choice::new(state.name, state.id))
+                    .collect();
+                self.app
+                    .open_picker(field, choices, Some(issue.state_id.as_str()));
+            }
+            Field::Assignee => {
+                if self.teams.is_none() {
+                    self.teams = Some(self.client.list_teams().await?);
+                }
+                let members = self
+                    .teams
+                    .iter()
+                    .flatten()
+                    .find(|team| team.id == issue.team_id)
+                    .map(|team| team.members.as_slice())
+                    .unwrap_or_default();
+
+                let mut choices = vec![Choice::new("Unassigned", "")];
+                choices.extend(
+                    members
+                        .iter()
+                        .filter(|member| member.active)
+                        .map(|member| Choice::new(member.name.clone(), member.id.clone())),
+                );
+                let current = issue.assignee_id.as_deref().unwrap_or_default();
+                self.app.open_picker(field, choices, Some(current));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    async fn apply(&mut self, field: Field, value: String, issue: &Issue) -> Result<()> {
+        let value = value.trim().to_string();
+        let mut input = UpdateIssueInput::default();
+
+        match field {
+            Field::State => input.state_id = Some(value),
+            Field::Assignee => input.assignee_id = Some(value),
+            Field::Priority => input.priority = value.parse().ok(),
+            Field::Labels => {
+                let names: Vec<String> = value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(str::to_string)
+                    .collect();
+                input.label_ids = Some(if names.is_empty() {
+                    Vec::new()
+                } else {
+                    self.client
+                        .resolve_label_names_to_ids(&issue.team_id, &names)
+                        .await?
+                });
+            }
+            Field::Cycle => {
+                if value.is_empty() {
+                    self.app.set_message("Cycle unchanged");
+                    return Ok(());
+                }
+                input.cycle_id = Some(
+                    self.client
+                        .resolve_cycle_to_id(&issue.team_id, &value)
+                        .await?,
+                );
+            }
+            Field::Comment => {
+                if value.is_empty() {
+                    self.app.set_message("Empty comment discarded");
+                    return Ok(());
+                }
+                self.client
+                    .create_comment(CreateCommentInput {
+                        body: value,
+                        issue_id: issue.id.clone(),
+                    })
+                    .await?;
+                self.app.details.remove(&issue.id);
+                self.app
+                    .set_message(format!("Commented on {}", issue.identifier));
+                return Ok(());
+            }
+        }
+
+        let assignee_id = input.assignee_id.clone();
+        let updated = self.client.update_issue(issue.id.clone(), input).await?;
+        self.app.apply_update(&updated);
+        if let Some(assignee_id) = assignee_id {
+            if let Some(row) = self.app.issues.iter_mut().find(|row| row.id == updated.id) {
+                row.assignee_id = Some(assignee_id).filter(|id| !id.is_empty());
+            }
+        }
+
+        // Pick up the fields a mutation response doesn't carry, such as the cycle
+        self.refresh_changed().await?;
+        self.app
+            .set_message(format!("Updated {}", updated.identifier));
+        Ok(())
+    }
+}
+
+/// Run the TUI over `issues` until the user quits
+pub async fn run(
+    client: &LinearClient,
+    issues: Vec<Issue>,
+    filters: IssueFilters,
+    limit: PageLimit,
+    use_color: bool,
+) -> Result<()> {
+    let mut session = Session {
+        client,
+        app: App::new(issues, use_color),
+        filters,
+        limit,
+        formatter: TableFormatter::new_with_interactive(use_color, true),
+        teams: None,
+        last_refresh: Instant::now(),
+        changed_since: String::new(),
+    };
+    session.mark_refreshed();
+
+    let _terminal = TerminalGuard::enter()?;
+    session.event_loop().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linear_sdk::{IssueCycle, IssueLabel};
+
+    fn issue(identifier: &str, title: &str) -> Issue {
+        Issue {
+            id: format!("id-{identifier}"),
+            identifier: identifier.to_string(),
+            title: title.to_string(),
+            status: "Todo".to_string(),
+            state_id: "state-todo".to_string(),
+            team_id: "team-eng".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn app() -> App {
+        App::new(
+            vec![issue("ENG-1", "First"), issue("ENG-2", "Second")],
+            false,
+        )
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            assert_eq!(app.handle_key(key(KeyCode::Char(c))), Action::None);
+        }
+    }
+
+    #[test]
+    fn test_navigation_stays_in_bounds() {
+        let mut app = app();
+        app.handle_key(key(KeyCode::Char('k')));
+        assert_eq!(app.selected, 0);
+        app.handle_key(key(KeyCode::Char('j')));
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(app.selected, 1);
+        app.handle_key(key(KeyCode::Char('g')));
+        assert_eq!(app.selected, 0);
+        app.handle_key(key(KeyCode::Char('G')));
+        assert_eq!(app.selected, 1);
+        assert_eq!(app.handle_key(key(KeyCode::Char('q'))), Action::Quit);
+    }
+
+    #[test]
+    fn test_priority_picker_filters_and_applies() {
+        let mut app = app();
+        app.issues[0].priority = Some(3);
+
+        assert_eq!(app.handle_key(key(KeyCode::Char('p'))), Action::None);
+        match &app.mode {
+            Mode::Pick(picker) => assert_eq!(picker.visible()[picker.selected].label, "Normal"),
+            mode => panic!("Expected a picker, got {mode:?}"),
+        }
+
+        type_text(&mut app, "urg");
+        assert_eq!(
+            app.handle_key(key(KeyCode::Enter)),
+            Action::Apply(Field::Priority, "1".to_string())
+        );
+        assert!(matches!(app.mode, Mode::Browse));
+    }
+
+    #[test]
+    fn test_prompts_prefill_and_cancel() {
+        let mut app = app();
+        app.issues[0].labels = vec![IssueLabel {
+            name: "bug".to_string(),
+            color: "#f00".to_string(),
+        }];
+        app.issues[0].cycle = Some(IssueCycle {
+            number: 7,
+            name: None,
+        });
+
+        app.handle_key(key(KeyCode::Char('l')));
+        type_text(&mut app, ", ui");
+        assert_eq!(
+            app.handle_key(key(KeyCode::Enter)),
+            Action::Apply(Field::Labels, "bug, ui".to_string())
+        );
+
+        app.handle_key(key(KeyCode::Char('c')));
+        app.handle_key(key(KeyCode::Backspace));
+        type_text(&mut app, "8");
+        assert_eq!(
+            app.handle_key(key(KeyCode::Enter)),
+            Action::Apply(Field::Cycle, "8".to_string())
+        );
+
+        app.handle_key(key(KeyCode::Char('m')));
+        type_text(&mut app, "Looks good");
+        assert_eq!(app.handle_key(key(KeyCode::Esc)), Action::None);
+        assert!(matches!(app.mode, Mode::Browse));
+    }
+
+    #[test]
+    fn test_merge_replaces_and_keeps_selection() {
+        let mut app = app();
+        app.selected = 1;
+        app.details.insert(
+            "id-ENG-2".to_string(),
+            Detail {
+                url: String::new(),
+                lines: vec![],
+            },
+        );
+
+        let mut changed = issue("ENG-2", "Second, renamed");
+        changed.status = "Done".to_string();
+        let count = app.merge(vec![issue("ENG-3", "Third"), changed]);
+
+        assert_eq!(count, 2);
+        let identifiers: Vec<_> = app.issues.iter().map(|i| i.identifier.as_str()).collect();
+        assert_eq!(identifiers, ["ENG-3", "ENG-1", "ENG-2"]);
+        assert_eq!(app.selected_issue().unwrap().title, "Second, renamed");
+        assert!(app.needs_detail());
+    }
+
+    #[test]
+    fn test_render_fills_the_screen() {
+        let mut app = app();
+        app.details.insert(
+            "id-ENG-1".to_string(),
+            Detail {
+                url: String::new(),
+                lines: vec!["A description long enough to wrap".to_string()],
+            },
+        );
+
+        let rows = app.render(60, 6);
+
+        assert_eq!(rows.len(), 6);
+        assert!(rows[0].contains("2 issues"));
+        assert!(rows[1].starts_with("▶ ENG-1"));
+        assert!(rows[1].contains("│A description long enough to wrap"));
+        assert!(rows[5].contains("q quit") || rows[5].contains("j/k"));
+        for row in &rows[..5] {
+            assert_eq!(wrap_ansi(row, usize::MAX)[0].1, 60, "row {row:?}");
+        }
+    }
+
+    #[test]
+    fn test_wrap_ansi_carries_styles() {
+        let rows = wrap_ansi("\x1b[1mabcdef\x1b[0mgh", 4);
+        assert_eq!(
+            rows,
+            vec![
+                ("\x1b[1mabcd".to_string(), 4),
+                ("\x1b[1mef\x1b[0mgh".to_string(), 4)
+            ]
+        );
+
+        let link = wrap_ansi("\x1b]8;;https://linear.app\x1b\\ENG-1\x1b]8;;\x1b\\", 10);
+        assert_eq!(link, vec![("ENG-1".to_string(), 5)]);
+
+        assert_eq!(wrap_ansi("🌳 tree", 4)[0], ("🌳 t".to_string(), 4));
+    }
+
+    #[test]
+    fn test_fit_pads_and_truncates() {
+        assert_eq!(fit("abc", 5), "abc  ");
+        assert_eq!(fit("abcdef", 3), "abc");
+        assert_eq!(fit("\x1b[1mab\x1b[0m", 3), "\x1b[1mab\x1b[0m\x1b[0m ");
+    }
+}
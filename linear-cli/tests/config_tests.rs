@@ -115,6 +115,9 @@ fn test_config_merge_precedence() {
         default_assignee: Some("base_user".to_string()),
         preferred_format: Some("json".to_string()),
         api_url: None,
+        max_concurrent_requests: None,
+        requests_per_hour: None,
+        max_rate_limit_wait: None,
         aliases: Some(ConfigAliases {
             commands: {
                 let mut map = HashMap::new();
@@ -123,6 +126,8 @@ fn test_config_merge_precedence() {
             },
         }),
         completions: None,
+        columns: None,
+        templates: None,
     };
 
     let override_config = Config {
@@ -130,6 +135,9 @@ fn test_config_merge_precedence() {
         default_assignee: None,
         preferred_format: None,
         api_url: Some("https://custom.api.com".to_string()),
+        max_concurrent_requests: None,
+        requests_per_hour: None,
+        max_rate_limit_wait: None,
         aliases: Some(ConfigAliases {
             commands: {
                 let mut map = HashMap::new();
@@ -141,6 +149,8 @@ fn test_config_merge_precedence() {
             cache_duration: Some("30m".to_string()),
             enable_dynamic: Some(false),
         }),
+        columns: None,
+        templates: None,
     };
 
     let merged = base_config.merge(override_config);
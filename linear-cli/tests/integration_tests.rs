@@ -405,12 +405,12 @@ fn test_update_command_project_arguments() {
 
     match cli.command {
         Commands::Update {
-            id,
+            ids,
             project,
             project_id,
             ..
         } => {
-            assert_eq!(id, "ISSUE-123");
+            assert_eq!(ids, vec!["ISSUE-123"]);
             assert_eq!(project, Some("Backend API".to_string()));
             assert_eq!(project_id, None);
         }
@@ -423,12 +423,12 @@ fn test_update_command_project_arguments() {
 
     match cli.command {
         Commands::Update {
-            id,
+            ids,
             project,
             project_id,
             ..
         } => {
-            assert_eq!(id, "ISSUE-456");
+            assert_eq!(ids, vec!["ISSUE-456"]);
             assert_eq!(project, None);
             assert_eq!(project_id, Some("proj-789".to_string()));
         }
@@ -1,6 +1,7 @@
 // ABOUTME: Builder pattern implementation for LinearClient configuration
 // ABOUTME: Provides type-safe configuration with compile-time validation
 
+use crate::constants::retry;
 use crate::error::LinearError;
 use crate::LinearClient;
 use secrecy::SecretString;
@@ -28,6 +29,18 @@ pub struct LinearClientConfig {
 
     #[builder(default = None)]
     pub base_url: Option<String>,
+
+    /// Longest rate-limit reset to wait out before returning `RateLimit`
+    #[builder(default = retry::MAX_RATE_LIMIT_WAIT)]
+    pub max_rate_limit_wait: Duration,
+
+    /// Most requests allowed in flight at once (unlimited when `None`)
+    #[builder(default = None)]
+    pub max_concurrent_requests: Option<usize>,
+
+    /// Client-side cap on requests per hour (unlimited when `None`)
+    #[builder(default = None)]
+    pub requests_per_hour: Option<u32>,
 }
 
 impl From<LinearClientConfig> for Result<LinearClient, LinearError> {
@@ -36,8 +49,11 @@ impl From<LinearClientConfig> for Result<LinearClient, LinearError> {
     }
 }
 
+/// A `LinearClientConfig` builder with no fields set yet
+pub type EmptyConfigBuilder = LinearClientConfigBuilder<((), (), (), (), (), (), (), (), ())>;
+
 impl LinearClient {
-    pub fn builder() -> LinearClientConfigBuilder<((), (), (), (), (), ())> {
+    pub fn builder() -> EmptyConfigBuilder {
         LinearClientConfig::builder()
     }
 
@@ -69,6 +85,9 @@ pub struct TypedLinearClientBuilder<State = Initial> {
     proxy: Option<reqwest::Proxy>,
     max_attempts: usize,
     base_url: Option<String>,
+    max_rate_limit_wait: Duration,
+    max_concurrent_requests: Option<usize>,
+    requests_per_hour: Option<u32>,
     _state: PhantomData<State>,
 }
 
@@ -88,6 +107,9 @@ impl TypedLinearClientBuilder<Initial> {
             proxy: None,
             max_attempts: 3,
             base_url: None,
+            max_rate_limit_wait: retry::MAX_RATE_LIMIT_WAIT,
+            max_concurrent_requests: None,
+            requests_per_hour: None,
             _state: PhantomData,
         }
     }
@@ -101,6 +123,9 @@ impl TypedLinearClientBuilder<Initial> {
             proxy: self.proxy,
             max_attempts: self.max_attempts,
             base_url: self.base_url,
+            max_rate_limit_wait: self.max_rate_limit_wait,
+            max_concurrent_requests: self.max_concurrent_requests,
+            requests_per_hour: self.requests_per_hour,
             _state: PhantomData,
         }
     }
@@ -131,6 +156,23 @@ impl<State> TypedLinearClientBuilder<State> {
         self.base_url = base_url;
         self
     }
+
+    pub fn max_rate_limit_wait(mut self, max_rate_limit_wait: Duration) -> Self {
+        self.max_rate_limit_wait = max_rate_limit_wait;
+        self
+    }
+
+    /// Cap how many requests this client keeps in flight at once
+    pub fn max_concurrent_requests(mut self, max_concurrent_requests: usize) -> Self {
+        self.max_concurrent_requests = Some(max_concurrent_requests);
+        self
+    }
+
+    /// Pace requests so this client stays under `requests_per_hour`
+    pub fn requests_per_hour(mut self, requests_per_hour: u32) -> Self {
+        self.requests_per_hour = Some(requests_per_hour);
+        self
+    }
 }
 
 impl TypedLinearClientBuilder<WithAuth> {
@@ -142,6 +184,9 @@ impl TypedLinearClientBuilder<WithAuth> {
             proxy: self.proxy,
             max_attempts: self.max_attempts,
             base_url: self.base_url,
+            max_rate_limit_wait: self.max_rate_limit_wait,
+            max_concurrent_requests: self.max_concurrent_requests,
+            requests_per_hour: self.requests_per_hour,
         };
 
         LinearClient::from_config(config)
@@ -247,6 +292,20 @@ mod tests {
         assert!(client_result.is_ok());
     }
 
+    #[test]
+    fn test_typed_builder_with_request_limits() {
+        let api_key = SecretString::new("test-api-key".to_string().into_boxed_str());
+
+        let client = LinearClient::typed_builder()
+            .auth_token(api_key)
+            .max_concurrent_requests(4)
+            .requests_per_hour(1200)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.usage(), crate::RequestUsage::default());
+    }
+
     #[test]
     fn test_builder_with_base_url() {
         let api_key = SecretString::new("test-api-key".to_string().into_boxed_str());
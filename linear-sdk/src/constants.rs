@@ -1,5 +1,5 @@
 // ABOUTME: Centralized constants for the Linear SDK
-// ABOUTME: Contains retry configuration, timeouts, and error handling constants
+// ABOUTME: Contains retry configuration, timeouts, pagination, and error handling constants
 
 /// Retry configuration constants
 pub mod retry {
@@ -16,6 +16,13 @@ pub mod retry {
 
     /// Backoff multiplier for exponential backoff
     pub const BACKOFF_MULTIPLIER: f64 = 2.0;
+
+    /// Longest rate-limit reset the client sleeps through before giving up; Linear's request
+    /// budget resets hourly
+    pub const MAX_RATE_LIMIT_WAIT: Duration = Duration::from_secs(3600);
+
+    /// Rate-limit waits allowed for one request, counted apart from `MAX_ATTEMPTS`
+    pub const MAX_RATE_LIMIT_RETRIES: u32 = 5;
 }
 
 /// HTTP and request timeouts
@@ -41,6 +48,18 @@ pub mod urls {
     pub const OAUTH_CALLBACK_BASE: &str = "http://localhost";
 }
 
+/// Cursor pagination constants
+pub mod pagination {
+    /// Largest page size requested from the Linear API in a single query
+    pub const MAX_PAGE_SIZE: usize = 250;
+}
+
+/// Issue hierarchy limits
+pub mod issues {
+    /// Deepest sub-issue level fetched when building an issue tree
+    pub const MAX_SUB_ISSUE_DEPTH: usize = 10;
+}
+
 /// Default status state names
 pub mod status {
     /// Default "Done" state name for closing issues
@@ -74,6 +93,8 @@ mod tests {
         assert_eq!(retry::INITIAL_DELAY, Duration::from_millis(100));
         assert_eq!(retry::MAX_DELAY, Duration::from_secs(10));
         assert_eq!(retry::BACKOFF_MULTIPLIER, 2.0);
+        assert_eq!(retry::MAX_RATE_LIMIT_WAIT, Duration::from_secs(3600));
+        assert_eq!(retry::MAX_RATE_LIMIT_RETRIES, 5);
     }
 
     #[test]
@@ -81,6 +102,11 @@ mod tests {
         assert_eq!(timeouts::HTTP_REQUEST_TIMEOUT, Duration::from_secs(30));
     }
 
+    #[test]
+    fn test_pagination_constants() {
+        assert_eq!(pagination::MAX_PAGE_SIZE, 250);
+    }
+
     #[test]
     fn test_url_constants() {
         assert!(urls::LINEAR_API_BASE.starts_with("https://"));
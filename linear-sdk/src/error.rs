@@ -35,6 +35,14 @@ pub enum LinearError {
     InvalidInput {
         message: String,
     },
+    /// The API refused access to an entity or operation
+    Forbidden {
+        message: String,
+    },
+    /// A referenced entity (issue, team, project, ...) does not exist or is not visible
+    NotFound {
+        message: String,
+    },
 }
 
 impl fmt::Display for LinearError {
@@ -55,6 +63,8 @@ impl fmt::Display for LinearError {
             LinearError::OAuthConfig => write!(f, "OAuth configuration error"),
             LinearError::Configuration(msg) => write!(f, "Configuration error: {msg}"),
             LinearError::InvalidInput { message } => write!(f, "Invalid input: {message}"),
+            LinearError::Forbidden { message } => write!(f, "Permission denied: {message}"),
+            LinearError::NotFound { message } => write!(f, "Not found: {message}"),
         }
     }
 }
@@ -78,6 +88,55 @@ pub struct GraphQLError {
     pub extensions: Option<serde_json::Value>,
 }
 
+impl GraphQLError {
+    /// Linear's error code from `extensions.code`, falling back to `extensions.type`
+    /// (e.g. "INPUT_ERROR", "RATELIMITED", "FORBIDDEN")
+    pub fn code(&self) -> Option<&str> {
+        let extensions = self.extensions.as_ref()?;
+        extensions
+            .get("code")
+            .or_else(|| extensions.get("type"))
+            .and_then(serde_json::Value::as_str)
+    }
+
+    /// The message meant for end users (`extensions.userPresentableMessage`), or the raw message
+    pub fn user_message(&self) -> &str {
+        self.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.get("userPresentableMessage"))
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(&self.message)
+    }
+
+    fn is_not_found(&self) -> bool {
+        self.message.starts_with("Entity not found")
+            || matches!(
+                self.normalized_code().as_deref(),
+                Some("NOT_FOUND" | "ENTITY_NOT_FOUND")
+            )
+    }
+
+    /// Code upper-cased with spaces as underscores, so "invalid input" matches "INVALID_INPUT"
+    fn normalized_code(&self) -> Option<String> {
+        self.code()
+            .map(|code| code.trim().to_uppercase().replace([' ', '-'], "_"))
+    }
+}
+
+impl From<graphql_client::Error> for GraphQLError {
+    fn from(error: graphql_client::Error) -> Self {
+        GraphQLError {
+            message: error.message,
+            path: error
+                .path
+                .map(|path| path.iter().map(ToString::to_string).collect()),
+            extensions: error
+                .extensions
+                .map(|extensions| serde_json::Value::Object(extensions.into_iter().collect())),
+        }
+    }
+}
+
 pub fn format_error_with_suggestion(err: &LinearError) -> String {
     let mut output = err.to_string();
 
@@ -93,6 +152,37 @@ pub fn format_error_with_suggestion(err: &LinearError) -> String {
 }
 
 impl LinearError {
+    /// Classify the `errors` array of a GraphQL response, using the first error's code to pick a
+    /// specific variant and keeping every error on the generic `GraphQL` variant otherwise.
+    /// Either way the message joins every error's message, so later errors aren't lost
+    pub fn from_graphql_errors(errors: Vec<GraphQLError>) -> Self {
+        let Some(first) = errors.first() else {
+            return LinearError::InvalidResponse;
+        };
+        let message = errors
+            .iter()
+            .map(GraphQLError::user_message)
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        if first.is_not_found() {
+            return LinearError::NotFound { message };
+        }
+
+        match first.normalized_code().as_deref() {
+            Some("RATELIMITED" | "RATE_LIMITED") => LinearError::RateLimit { reset_seconds: 0 },
+            Some("AUTHENTICATION_ERROR" | "UNAUTHENTICATED") => LinearError::Auth {
+                reason: Cow::Owned(message),
+                source: None,
+            },
+            Some("FORBIDDEN") => LinearError::Forbidden { message },
+            Some("INPUT_ERROR" | "INVALID_INPUT" | "VALIDATION_ERROR" | "BAD_USER_INPUT") => {
+                LinearError::InvalidInput { message }
+            }
+            _ => LinearError::GraphQL { message, errors },
+        }
+    }
+
     pub fn help_text(&self) -> Cow<'static, str> {
         match self {
             LinearError::Auth { .. } => {
@@ -109,6 +199,12 @@ impl LinearError {
                 Cow::Borrowed("Wait a moment before making another request")
             }
             LinearError::Timeout => Cow::Borrowed("Try again or check your network connection"),
+            LinearError::Forbidden { .. } => Cow::Borrowed(
+                "Check that your account can access this workspace and that your token has the write scope",
+            ),
+            LinearError::NotFound { .. } => {
+                Cow::Borrowed("Check the identifier and that you have access to it")
+            }
             LinearError::OAuthConfig => Cow::Borrowed(
                 "Set up OAuth by creating an application at https://linear.app/settings/api/applications/new\n\nCallback URL: http://localhost:8089/callback\nThen set LINEAR_OAUTH_CLIENT_ID environment variable with your Client ID",
             ),
@@ -467,4 +563,102 @@ mod tests {
         );
         assert!(msg.len() > 30, "Message too short (no details): {msg}");
     }
+
+    fn graphql_error(message: &str, extensions: serde_json::Value) -> GraphQLError {
+        GraphQLError {
+            message: message.to_string(),
+            path: None,
+            extensions: Some(extensions),
+        }
+    }
+
+    #[test]
+    fn test_from_graphql_errors_maps_codes() {
+        let err = LinearError::from_graphql_errors(vec![graphql_error(
+            "Entity not found: Issue",
+            serde_json::json!({
+                "type": "invalid input",
+                "code": "INPUT_ERROR",
+                "userPresentableMessage": "Could not find referenced Issue."
+            }),
+        )]);
+        assert!(matches!(err, LinearError::NotFound { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Not found: Could not find referenced Issue."
+        );
+
+        let err = LinearError::from_graphql_errors(vec![graphql_error(
+            "Argument Validation Error",
+            serde_json::json!({ "code": "INPUT_ERROR", "userPresentableMessage": "Title is too long" }),
+        )]);
+        assert_eq!(err.to_string(), "Invalid input: Title is too long");
+
+        let err = LinearError::from_graphql_errors(vec![graphql_error(
+            "ratelimited",
+            serde_json::json!({ "code": "RATELIMITED" }),
+        )]);
+        assert!(matches!(err, LinearError::RateLimit { .. }));
+
+        let err = LinearError::from_graphql_errors(vec![graphql_error(
+            "Forbidden",
+            serde_json::json!({ "type": "forbidden", "userPresentableMessage": "You cannot edit this issue" }),
+        )]);
+        assert_eq!(
+            err.to_string(),
+            "Permission denied: You cannot edit this issue"
+        );
+
+        let err = LinearError::from_graphql_errors(vec![graphql_error(
+            "Authentication required",
+            serde_json::json!({ "code": "UNAUTHENTICATED" }),
+        )]);
+        assert!(matches!(err, LinearError::Auth { .. }));
+    }
+
+    #[test]
+    fn test_from_graphql_errors_keeps_every_message() {
+        let err = LinearError::from_graphql_errors(vec![
+            graphql_error(
+                "Argument Validation Error",
+                serde_json::json!({ "code": "INPUT_ERROR", "userPresentableMessage": "Title is too long" }),
+            ),
+            graphql_error(
+                "Argument Validation Error",
+                serde_json::json!({ "code": "INPUT_ERROR", "userPresentableMessage": "Priority must be 0-4" }),
+            ),
+        ]);
+        assert!(matches!(err, LinearError::InvalidInput { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Invalid input: Title is too long; Priority must be 0-4"
+        );
+    }
+
+    #[test]
+    fn test_from_graphql_errors_keeps_unknown_errors() {
+        let err = LinearError::from_graphql_errors(vec![
+            graphql_error(
+                "Cannot query field 'unknown' on type 'Query'",
+                serde_json::json!({ "code": "GRAPHQL_VALIDATION_FAILED" }),
+            ),
+            GraphQLError {
+                message: "Something else".to_string(),
+                path: Some(vec!["issues".to_string()]),
+                extensions: None,
+            },
+        ]);
+
+        match err {
+            LinearError::GraphQL { message, errors } => {
+                assert_eq!(
+                    message,
+                    "Cannot query field 'unknown' on type 'Query'; Something else"
+                );
+                assert_eq!(errors.len(), 2);
+                assert_eq!(errors[0].code(), Some("GRAPHQL_VALIDATION_FAILED"));
+            }
+            other => panic!("Expected GraphQL error, got {other:?}"),
+        }
+    }
 }
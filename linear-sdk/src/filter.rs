@@ -0,0 +1,590 @@
+// ABOUTME: Fluent builder for Linear issue filters used by issue list queries
+// ABOUTME: Produces the generated list_issues::IssueFilter without spelling out unused fields
+
+use crate::list_issues::{
+    BooleanComparator, DateComparator, EstimateComparator, IDComparator, IssueFilter,
+    IssueLabelCollectionFilter, IssueLabelFilter, NullableCycleFilter, NullableIssueFilter,
+    NullableNumberComparator, NullableProjectFilter, NullableTimelessDateComparator,
+    NullableUserFilter, NumberComparator, StringComparator, TeamFilter, WorkflowStateFilter,
+};
+
+/// Relative date Linear's date comparators read as today
+pub(crate) const TODAY: &str = "P0D";
+
+/// Workflow state types of finished work
+pub(crate) const CLOSED_STATE_TYPES: &[&str] = &["completed", "canceled"];
+
+/// Builds a `list_issues::IssueFilter` one condition at a time.
+///
+/// Conditions added with the builder methods are combined with AND. Use [`or`](Self::or)
+/// and [`and`](Self::and) to nest groups of filters.
+///
+/// ```
+/// use linear_sdk::IssueFilterBuilder;
+///
+/// let filter = IssueFilterBuilder::new()
+///     .assignee_is_me()
+///     .state_type_in(["started", "unstarted"])
+///     .label("bug")
+///     .priority_lte(2)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IssueFilterBuilder {
+    filter: IssueFilter,
+    labels: Vec<String>,
+    and: Vec<IssueFilter>,
+    or: Vec<IssueFilter>,
+}
+
+impl IssueFilterBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Issues assigned to the authenticated user
+    pub fn assignee_is_me(mut self) -> Self {
+        self.filter.assignee = Box::new(Some(NullableUserFilter {
+            is_me: Some(BooleanComparator {
+                eq: Some(true),
+                neq: None,
+            }),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues assigned to the user with this ID
+    pub fn assignee_id(mut self, id: impl Into<String>) -> Self {
+        self.filter.assignee = Box::new(Some(NullableUserFilter {
+            id: Some(id_eq(id)),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues assigned to a user with this exact name
+    pub fn assignee_name(mut self, name: impl Into<String>) -> Self {
+        self.filter.assignee = Box::new(Some(NullableUserFilter {
+            name: Some(string_eq(name)),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues without an assignee
+    pub fn unassigned(mut self) -> Self {
+        self.filter.assignee = Box::new(Some(NullableUserFilter {
+            null: Some(true),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues in a workflow state with this exact name
+    pub fn state_name(mut self, name: impl Into<String>) -> Self {
+        self.state_filter().name = Some(string_eq(name));
+        self
+    }
+
+    /// Issues whose workflow state type is one of `types`
+    /// (e.g. "triage", "backlog", "unstarted", "started", "completed", "canceled")
+    pub fn state_type_in<I, S>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.state_filter().type_ = Some(StringComparator {
+            in_: Some(types.into_iter().map(Into::into).collect()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Issues belonging to the team with this key
+    pub fn team_key(mut self, key: impl Into<String>) -> Self {
+        self.filter.team = Box::new(Some(TeamFilter {
+            key: Some(string_eq(key)),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues carrying a label with this name (case insensitive).
+    /// Calling this more than once requires every label to be present.
+    pub fn label(mut self, name: impl Into<String>) -> Self {
+        self.labels.push(name.into());
+        self
+    }
+
+    /// Issues created by the authenticated user
+    pub fn creator_is_me(mut self) -> Self {
+        self.filter.creator = Box::new(Some(NullableUserFilter {
+            is_me: Some(BooleanComparator {
+                eq: Some(true),
+                neq: None,
+            }),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues created by a user with this exact name
+    pub fn creator_name(mut self, name: impl Into<String>) -> Self {
+        self.filter.creator = Box::new(Some(NullableUserFilter {
+            name: Some(string_eq(name)),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues in the project with this ID
+    pub fn project_id(mut self, id: impl Into<String>) -> Self {
+        self.filter.project = Box::new(Some(NullableProjectFilter {
+            id: Some(id_eq(id)),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues in a project with this name (case insensitive)
+    pub fn project_name(mut self, name: impl Into<String>) -> Self {
+        self.filter.project = Box::new(Some(NullableProjectFilter {
+            name: Some(StringComparator {
+                eq_ignore_case: Some(name.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues in their team's active cycle
+    pub fn cycle_is_active(mut self) -> Self {
+        self.filter.cycle = Box::new(Some(NullableCycleFilter {
+            is_active: Some(BooleanComparator {
+                eq: Some(true),
+                neq: None,
+            }),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues in their team's upcoming cycle
+    pub fn cycle_is_next(mut self) -> Self {
+        self.filter.cycle = Box::new(Some(NullableCycleFilter {
+            is_next: Some(BooleanComparator {
+                eq: Some(true),
+                neq: None,
+            }),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues in the cycle with this number
+    pub fn cycle_number(mut self, number: i64) -> Self {
+        self.filter.cycle = Box::new(Some(NullableCycleFilter {
+            number: Some(NumberComparator {
+                eq: Some(number as f64),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues whose parent is the issue with this ID
+    pub fn parent_id(mut self, id: impl Into<String>) -> Self {
+        self.filter.parent = Box::new(Some(NullableIssueFilter {
+            id: Some(id_eq(id)),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues whose parent is the issue with this team key and number (e.g. ENG-123)
+    pub fn parent_identifier(mut self, team_key: impl Into<String>, number: i64) -> Self {
+        self.filter.parent = Box::new(Some(NullableIssueFilter {
+            number: Some(NumberComparator {
+                eq: Some(number as f64),
+                ..Default::default()
+            }),
+            team: Box::new(Some(TeamFilter {
+                key: Some(string_eq(team_key)),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }));
+        self
+    }
+
+    /// Issues with exactly this priority (1 = urgent, 4 = low, 0 = none)
+    pub fn priority_eq(mut self, priority: impl Into<f64>) -> Self {
+        self.filter.priority = Some(NullableNumberComparator {
+            eq: Some(priority.into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Issues with a priority value at or below `priority` (1 = urgent, 4 = low, 0 = none)
+    pub fn priority_lte(mut self, priority: impl Into<f64>) -> Self {
+        self.filter.priority = Some(NullableNumberComparator {
+            lte: Some(priority.into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Issues updated after a timestamp or ISO 8601 duration (e.g. "2026-01-01" or "-P7D")
+    pub fn updated_after(mut self, date: impl Into<String>) -> Self {
+        self.filter.updated_at = Some(DateComparator {
+            gt: Some(date.into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Issues created after a timestamp or ISO 8601 duration
+    pub fn created_after(mut self, date: impl Into<String>) -> Self {
+        self.filter.created_at = Some(DateComparator {
+            gt: Some(date.into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Issues due before a date or ISO 8601 duration (e.g. "2026-11-01" or "P7D")
+    pub fn due_before(mut self, date: impl Into<String>) -> Self {
+        self.filter.due_date = Some(NullableTimelessDateComparator {
+            lt: Some(date.into()),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Open issues whose due date is before today
+    pub fn overdue(mut self) -> Self {
+        self = self.due_before(TODAY);
+        self.state_filter()
+            .type_
+            .get_or_insert_with(Default::default)
+            .nin = Some(CLOSED_STATE_TYPES.iter().map(|t| t.to_string()).collect());
+        self
+    }
+
+    /// Issues without an estimate
+    pub fn no_estimate(mut self) -> Self {
+        self.filter.estimate = Some(EstimateComparator {
+            null: Some(true),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Require at least one of `filters` to match in addition to this builder's conditions
+    pub fn or(mut self, filters: impl IntoIterator<Item = IssueFilterBuilder>) -> Self {
+        self.or
+            .extend(filters.into_iter().map(IssueFilterBuilder::build));
+        self
+    }
+
+    /// Require every one of `filters` to match in addition to this builder's conditions
+    pub fn and(mut self, filters: impl IntoIterator<Item = IssueFilterBuilder>) -> Self {
+        self.and
+            .extend(filters.into_iter().map(IssueFilterBuilder::build));
+        self
+    }
+
+    /// True when no condition has been added
+    pub fn is_empty(&self) -> bool {
+        self.filter == IssueFilter::default()
+            && self.labels.is_empty()
+            && self.and.is_empty()
+            && self.or.is_empty()
+    }
+
+    pub fn build(self) -> IssueFilter {
+        let mut filter = self.filter;
+        let mut and = self.and;
+
+        let mut label_filters = self
+            .labels
+            .into_iter()
+            .map(|name| IssueLabelCollectionFilter {
+                some: Box::new(Some(IssueLabelFilter {
+                    name: Some(StringComparator {
+                        eq_ignore_case: Some(name),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })),
+                ..Default::default()
+            });
+
+        // The first label sits on the filter itself; any further labels need their own clause
+        if let Some(first) = label_filters.next() {
+            filter.labels = Box::new(Some(first));
+        }
+        and.extend(label_filters.map(|labels| IssueFilter {
+            labels: Box::new(Some(labels)),
+            ..Default::default()
+        }));
+
+        if !and.is_empty() {
+            filter.and = Box::new(Some(and));
+        }
+        if !self.or.is_empty() {
+            filter.or = Box::new(Some(self.or));
+        }
+        filter
+    }
+
+    fn state_filter(&mut self) -> &mut WorkflowStateFilter {
+        self.filter.state.get_or_insert_with(Default::default)
+    }
+}
+
+fn string_eq(value: impl Into<String>) -> StringComparator {
+    StringComparator {
+        eq: Some(value.into()),
+        ..Default::default()
+    }
+}
+
+fn id_eq(value: impl Into<String>) -> IDComparator {
+    IDComparator {
+        eq: Some(value.into()),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn to_json(builder: IssueFilterBuilder) -> serde_json::Value {
+        serde_json::to_value(builder.build()).unwrap()
+    }
+
+    #[test]
+    fn test_empty_builder() {
+        let builder = IssueFilterBuilder::new();
+        assert!(builder.is_empty());
+        assert_eq!(to_json(builder), json!({}));
+    }
+
+    #[test]
+    fn test_assignee_filters() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().assignee_is_me()),
+            json!({ "assignee": { "isMe": { "eq": true } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().assignee_id("user-1")),
+            json!({ "assignee": { "id": { "eq": "user-1" } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().assignee_name("Alice")),
+            json!({ "assignee": { "name": { "eq": "Alice" } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().unassigned()),
+            json!({ "assignee": { "null": true } })
+        );
+    }
+
+    #[test]
+    fn test_state_and_team_filters() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().state_name("In Progress")),
+            json!({ "state": { "name": { "eq": "In Progress" } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().state_type_in(["started", "unstarted"])),
+            json!({ "state": { "type": { "in": ["started", "unstarted"] } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().team_key("ENG")),
+            json!({ "team": { "key": { "eq": "ENG" } } })
+        );
+    }
+
+    #[test]
+    fn test_priority_and_date_filters() {
+        assert_eq!(
+            to_json(
+                IssueFilterBuilder::new()
+                    .priority_lte(2)
+                    .updated_after("2026-01-01T00:00:00Z")
+            ),
+            json!({
+                "priority": { "lte": 2.0 },
+                "updatedAt": { "gt": "2026-01-01T00:00:00Z" }
+            })
+        );
+    }
+
+    #[test]
+    fn test_overdue_filter() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().overdue()),
+            json!({
+                "dueDate": { "lt": "P0D" },
+                "state": { "type": { "nin": ["completed", "canceled"] } }
+            })
+        );
+        assert_eq!(
+            to_json(
+                IssueFilterBuilder::new()
+                    .state_type_in(["started"])
+                    .overdue()
+            ),
+            json!({
+                "dueDate": { "lt": "P0D" },
+                "state": { "type": { "in": ["started"], "nin": ["completed", "canceled"] } }
+            })
+        );
+    }
+
+    #[test]
+    fn test_single_label() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().label("bug")),
+            json!({ "labels": { "some": { "name": { "eqIgnoreCase": "bug" } } } })
+        );
+    }
+
+    #[test]
+    fn test_multiple_labels_require_all() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().label("bug").label("ui")),
+            json!({
+                "labels": { "some": { "name": { "eqIgnoreCase": "bug" } } },
+                "and": [
+                    { "labels": { "some": { "name": { "eqIgnoreCase": "ui" } } } }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_or_and() {
+        let filter = IssueFilterBuilder::new().team_key("ENG").or([
+            IssueFilterBuilder::new().assignee_is_me(),
+            IssueFilterBuilder::new()
+                .unassigned()
+                .and([IssueFilterBuilder::new().priority_lte(1)]),
+        ]);
+
+        assert!(!filter.is_empty());
+        assert_eq!(
+            to_json(filter),
+            json!({
+                "team": { "key": { "eq": "ENG" } },
+                "or": [
+                    { "assignee": { "isMe": { "eq": true } } },
+                    {
+                        "assignee": { "null": true },
+                        "and": [{ "priority": { "lte": 1.0 } }]
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_later_condition_replaces_earlier_on_same_field() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().assignee_is_me().unassigned()),
+            json!({ "assignee": { "null": true } })
+        );
+    }
+
+    #[test]
+    fn test_state_name_and_type_combine() {
+        assert_eq!(
+            to_json(
+                IssueFilterBuilder::new()
+                    .state_name("In Review")
+                    .state_type_in(["started"])
+            ),
+            json!({
+                "state": {
+                    "name": { "eq": "In Review" },
+                    "type": { "in": ["started"] }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_creator_and_project_filters() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().creator_is_me()),
+            json!({ "creator": { "isMe": { "eq": true } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().creator_name("Bob")),
+            json!({ "creator": { "name": { "eq": "Bob" } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().project_name("Mobile App")),
+            json!({ "project": { "name": { "eqIgnoreCase": "Mobile App" } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().project_id("proj-1")),
+            json!({ "project": { "id": { "eq": "proj-1" } } })
+        );
+    }
+
+    #[test]
+    fn test_cycle_filters() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().cycle_is_active()),
+            json!({ "cycle": { "isActive": { "eq": true } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().cycle_is_next()),
+            json!({ "cycle": { "isNext": { "eq": true } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().cycle_number(42)),
+            json!({ "cycle": { "number": { "eq": 42.0 } } })
+        );
+    }
+
+    #[test]
+    fn test_parent_filters() {
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().parent_identifier("ENG", 123)),
+            json!({ "parent": { "number": { "eq": 123.0 }, "team": { "key": { "eq": "ENG" } } } })
+        );
+        assert_eq!(
+            to_json(IssueFilterBuilder::new().parent_id("issue-1")),
+            json!({ "parent": { "id": { "eq": "issue-1" } } })
+        );
+    }
+
+    #[test]
+    fn test_date_priority_and_estimate_filters() {
+        assert_eq!(
+            to_json(
+                IssueFilterBuilder::new()
+                    .created_after("-P7D")
+                    .due_before("2026-11-01")
+                    .priority_eq(1)
+                    .no_estimate()
+            ),
+            json!({
+                "createdAt": { "gt": "-P7D" },
+                "dueDate": { "lt": "2026-11-01" },
+                "priority": { "eq": 1.0 },
+                "estimate": { "null": true }
+            })
+        );
+    }
+}
@@ -1,12 +1,22 @@
-// ABOUTME: GraphQL abstraction layer providing executor trait and query builders
-// ABOUTME: Implements comprehensive abstraction for Linear GraphQL API interactions
+// ABOUTME: GraphQL abstraction layer providing the executor trait, its reqwest implementation,
+// ABOUTME: and query builders for Linear GraphQL API interactions
 
 use async_trait::async_trait;
-use graphql_client::GraphQLQuery;
+use graphql_client::{GraphQLQuery, Response};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use secrecy::ExposeSecret;
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
-use crate::error::LinearError;
+use crate::builder::LinearClientConfig;
+use crate::constants::urls;
+use crate::error::{GraphQLError, LinearError};
+use crate::limiter::{LimiterConfig, RequestLimiter, RequestUsage};
+use crate::rate_limit::RateLimitStatus;
+use crate::retry::{self, RetryConfig};
 
 /// Trait for executing GraphQL queries with instrumentation support
 #[async_trait]
@@ -20,6 +30,8 @@ pub trait GraphQLExecutor: Send + Sync {
         V: Into<Q::Variables> + Send + Debug;
 
     /// Execute a batch of GraphQL queries
+    ///
+    /// The default runs them one after another and stops at the first error.
     async fn execute_batch<Q, V>(
         &self,
         queries: Vec<(Q, V)>,
@@ -28,14 +40,263 @@ pub trait GraphQLExecutor: Send + Sync {
         Q: GraphQLQuery + Send + Sync,
         Q::ResponseData: Debug + serde::de::DeserializeOwned + Send,
         Q::Variables: Debug + Send + Sync + Clone,
-        V: Into<Q::Variables> + Send + Debug;
+        V: Into<Q::Variables> + Send + Debug,
+    {
+        let mut results = Vec::with_capacity(queries.len());
+        for (_, variables) in queries {
+            results.push(self.execute::<Q, V>(variables).await?);
+        }
+        Ok(results)
+    }
+
+    /// Execute a hand-written GraphQL document, returning its `data` as JSON
+    async fn execute_raw(&self, query: &QueryBuilder) -> Result<serde_json::Value, LinearError>;
+
+    /// Rate-limit budget from the most recent response, if the executor tracks one
+    fn rate_limit(&self) -> Option<RateLimitStatus> {
+        None
+    }
+}
+
+/// [`GraphQLExecutor`] that posts queries to Linear's `/graphql` endpoint with reqwest,
+/// retrying transient failures with backoff
+#[derive(Clone)]
+pub struct ReqwestExecutor {
+    client: reqwest::Client,
+    base_url: String,
+    verbose: bool,
+    retry_config: RetryConfig,
+    rate_limit: Arc<Mutex<RateLimitStatus>>,
+    limiter: Arc<RequestLimiter>,
+}
+
+impl ReqwestExecutor {
+    /// Build an authenticated HTTP executor from client configuration
+    pub fn from_config(config: LinearClientConfig) -> Result<Self, LinearError> {
+        let auth_token = config.auth_token.expose_secret();
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(auth_token).map_err(|_| LinearError::Auth {
+                reason: Cow::Borrowed("Invalid API key format"),
+                source: None,
+            })?,
+        );
+        headers.insert(USER_AGENT, HeaderValue::from_static("linear-cli/0.1.0"));
+
+        let mut client_builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout);
+
+        if let Some(proxy) = config.proxy {
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        let client = client_builder.build().map_err(LinearError::from)?;
+
+        let retry_config = retry::RetryConfig {
+            max_attempts: config.max_attempts as u32,
+            initial_delay: std::time::Duration::from_millis(100),
+            max_delay: std::time::Duration::from_secs(10),
+            backoff_multiplier: 2.0,
+            max_rate_limit_wait: config.max_rate_limit_wait,
+            max_rate_limit_retries: crate::constants::retry::MAX_RATE_LIMIT_RETRIES,
+        };
+
+        Ok(Self {
+            client,
+            base_url: config
+                .base_url
+                .unwrap_or_else(|| urls::LINEAR_API_BASE.to_string()),
+            verbose: config.verbose,
+            retry_config,
+            rate_limit: Arc::new(Mutex::new(RateLimitStatus::default())),
+            limiter: Arc::new(RequestLimiter::new(LimiterConfig {
+                max_concurrent_requests: config.max_concurrent_requests,
+                requests_per_hour: config.requests_per_hour,
+            })),
+        })
+    }
+
+    /// Requests sent and query complexity spent through this executor so far
+    pub fn usage(&self) -> RequestUsage {
+        self.limiter.usage()
+    }
+
+    /// Remember the budget from a response's headers and log it when verbose
+    fn record_rate_limit(&self, status: &RateLimitStatus) {
+        if !status.is_known() {
+            return;
+        }
+
+        if let Some(complexity) = status.complexity {
+            self.limiter.record_complexity(complexity);
+            if self.verbose {
+                let usage = self.limiter.usage();
+                log::debug!(
+                    "Query complexity: {complexity} ({} over {} requests)",
+                    usage.complexity,
+                    usage.requests
+                );
+            }
+        }
+
+        if self.verbose {
+            if let (Some(remaining), Some(limit)) =
+                (status.requests_remaining, status.requests_limit)
+            {
+                log::debug!("Rate limit: {remaining}/{limit} requests remaining");
+            }
+        }
+
+        if let Ok(mut current) = self.rate_limit.lock() {
+            *current = status.clone();
+        }
+    }
+
+    /// Send a GraphQL request body with retries, mapping HTTP and GraphQL errors
+    async fn send<B, T>(&self, request_body: &B) -> Result<T, LinearError>
+    where
+        B: serde::Serialize + Sync,
+        T: serde::de::DeserializeOwned,
+    {
+        if self.verbose {
+            log::debug!(
+                "Request body: {}",
+                serde_json::to_string_pretty(request_body).unwrap_or_default()
+            );
+        }
+
+        // Execute using the client's retry logic
+        retry::retry_with_backoff(&self.retry_config, self.verbose, || {
+            let client = &self.client;
+            let base_url = &self.base_url;
+            let verbose = self.verbose;
+
+            async move {
+                let _permit = self.limiter.acquire(verbose).await;
+                let start_time = std::time::Instant::now();
+                let response = client
+                    .post(format!("{base_url}/graphql"))
+                    .json(request_body)
+                    .send()
+                    .await
+                    .map_err(LinearError::from)?;
+
+                if verbose {
+                    log::debug!("Request completed in {:?}", start_time.elapsed());
+                    log::debug!("Response status: {}", response.status());
+                }
+
+                let limits = RateLimitStatus::from_headers(response.headers());
+                self.record_rate_limit(&limits);
+
+                // Check for HTTP error status codes
+                if !response.status().is_success() {
+                    let status = response.status();
+                    // Capture the response body so API errors (e.g. scope/validation
+                    // failures) surface instead of a bare "HTTP error: 400".
+                    let body = response.text().await.unwrap_or_default();
+                    let mut err = LinearError::from_status(
+                        http::StatusCode::from_u16(status.as_u16()).unwrap(),
+                    );
+                    if let LinearError::Network {
+                        ref mut message,
+                        retryable: false,
+                        ..
+                    } = err
+                    {
+                        // Linear reports rate limits and invalid input as 400s with a GraphQL
+                        // `errors` body; classify those like errors on a successful response.
+                        if let Some(errors) =
+                            serde_json::from_str::<Response<serde_json::Value>>(&body)
+                                .ok()
+                                .and_then(|response| response.errors)
+                                .filter(|errors| !errors.is_empty())
+                        {
+                            return Err(with_reset(graphql_error(errors), &limits));
+                        }
+                        if !body.trim().is_empty() {
+                            *message = format!("HTTP error: {status}: {body}");
+                        }
+                    }
+                    return Err(with_reset(err, &limits));
+                }
+
+                let response_body: Response<T> =
+                    response.json().await.map_err(LinearError::from)?;
+
+                if let Some(errors) = response_body.errors {
+                    return Err(with_reset(graphql_error(errors), &limits));
+                }
+
+                response_body.data.ok_or(LinearError::InvalidResponse)
+            }
+        })
+        .await
+    }
+}
+
+fn graphql_error(errors: Vec<graphql_client::Error>) -> LinearError {
+    LinearError::from_graphql_errors(errors.into_iter().map(GraphQLError::from).collect())
+}
+
+/// Fill in how long a rate-limit error should wait, from `Retry-After` or the budget reset
+fn with_reset(mut error: LinearError, limits: &RateLimitStatus) -> LinearError {
+    if let LinearError::RateLimit { reset_seconds } = &mut error {
+        if let Some(wait) = limits.wait_time(SystemTime::now()) {
+            *reset_seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+        }
+    }
+    error
+}
+
+#[async_trait]
+impl GraphQLExecutor for ReqwestExecutor {
+    async fn execute<Q, V>(&self, variables: V) -> Result<Q::ResponseData, LinearError>
+    where
+        Q: GraphQLQuery + Send + Sync,
+        Q::ResponseData: Debug + serde::de::DeserializeOwned + Send,
+        Q::Variables: Debug + Send + Sync + Clone,
+        V: Into<Q::Variables> + Send + Debug,
+    {
+        let request_body = Q::build_query(variables.into());
+
+        if self.verbose {
+            let query_name = std::any::type_name::<Q>()
+                .split("::")
+                .last()
+                .unwrap_or("unknown");
+            log::debug!("Sending GraphQL query: {query_name}");
+        }
+
+        self.send(&request_body).await
+    }
+
+    async fn execute_raw(&self, query: &QueryBuilder) -> Result<serde_json::Value, LinearError> {
+        if self.verbose {
+            log::debug!("Sending raw GraphQL document");
+        }
+
+        self.send(query).await
+    }
+
+    fn rate_limit(&self) -> Option<RateLimitStatus> {
+        let status = self.rate_limit.lock().ok()?.clone();
+        status.is_known().then_some(status)
+    }
 }
 
 /// Builder for constructing complex GraphQL queries
-#[derive(Debug, Clone)]
+///
+/// Serializes to a GraphQL request body, so it can be sent with
+/// [`LinearClient::execute_raw`](crate::LinearClient::execute_raw).
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct QueryBuilder {
     query: String,
     variables: HashMap<String, serde_json::Value>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
     extensions: HashMap<String, serde_json::Value>,
 }
 
@@ -98,4 +359,21 @@ mod tests {
         assert_eq!(builder.variables().len(), 2);
         assert_eq!(builder.extensions().len(), 1);
     }
+
+    #[test]
+    fn test_query_builder_serializes_request_body() {
+        let builder =
+            QueryBuilder::new("query($first: Int) { issues(first: $first) { nodes { id } } }")
+                .variable("first", 5);
+
+        let body = serde_json::to_value(&builder).unwrap();
+
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "query": "query($first: Int) { issues(first: $first) { nodes { id } } }",
+                "variables": { "first": 5 }
+            })
+        );
+    }
 }
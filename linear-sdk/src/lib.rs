@@ -1,23 +1,30 @@
 // ABOUTME: Linear SDK library providing type-safe GraphQL client for Linear API
 // ABOUTME: Includes authentication, queries, mutations, and generated types
 
-use graphql_client::{GraphQLQuery, Response};
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
-use std::borrow::Cow;
+use constants::issues::MAX_SUB_ISSUE_DEPTH;
+use constants::pagination::MAX_PAGE_SIZE;
+use graphql_client::GraphQLQuery;
+use std::collections::HashMap;
 use std::fmt::Debug;
 
 pub mod builder;
 pub mod constants;
 pub mod error;
+pub mod filter;
 pub mod graphql;
+pub mod limiter;
+pub mod pagination;
+pub mod rate_limit;
 pub mod retry;
 
 pub use builder::LinearClientConfig;
-use constants::urls;
-use secrecy::ExposeSecret;
 
 pub use builder::{Initial, LinearClientConfigBuilder, TypedLinearClientBuilder, WithAuth};
-pub use graphql::{GraphQLExecutor, QueryBuilder};
+pub use filter::IssueFilterBuilder;
+pub use graphql::{GraphQLExecutor, QueryBuilder, ReqwestExecutor};
+pub use limiter::RequestUsage;
+pub use pagination::{PageInfo, PageLimit, PageStream};
+pub use rate_limit::RateLimitStatus;
 
 #[cfg(feature = "oauth")]
 pub mod oauth;
@@ -58,7 +65,7 @@ pub struct Viewer;
     schema_path = "graphql/schema.json",
     query_path = "graphql/queries/issues.graphql",
     response_derives = "Debug, Clone",
-    variables_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default, PartialEq",
     skip_serializing_none
 )]
 pub struct ListIssues;
@@ -73,6 +80,16 @@ pub struct ListIssues;
 )]
 pub struct GetIssue;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/sub_issues.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ListSubIssues;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "graphql/schema.json",
@@ -113,6 +130,66 @@ pub struct ListTeams;
 )]
 pub struct UpdateIssue;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/clear_issue_due_date.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ClearIssueDueDate;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/clear_issue_milestone.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ClearIssueMilestone;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/clear_issue_parent.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ClearIssueParent;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/archive_issue.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ArchiveIssue;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/unarchive_issue.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct UnarchiveIssue;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/delete_issue.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct DeleteIssue;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "graphql/schema.json",
@@ -153,6 +230,46 @@ pub struct ListTeamLabels;
 )]
 pub struct ListTeamCycles;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/cycles.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ListCycles;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/cycle.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct GetCycle;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/cycle_issues.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ListCycleIssues;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/cycle_history.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct GetCycleHistory;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "graphql/schema.json",
@@ -178,11 +295,161 @@ pub struct CreateIssueRelation;
     schema_path = "graphql/schema.json",
     query_path = "graphql/queries/projects.graphql",
     response_derives = "Debug, Clone",
-    variables_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
     skip_serializing_none
 )]
 pub struct ListProjects;
 
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/project.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
+    skip_serializing_none
+)]
+pub struct GetProject;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/project_issues.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ListProjectIssues;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/project_statuses.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
+    skip_serializing_none
+)]
+pub struct ListProjectStatuses;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/create_project.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
+    skip_serializing_none
+)]
+pub struct CreateProject;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/update_project.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
+    skip_serializing_none
+)]
+pub struct UpdateProject;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/clear_project_target_date.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ClearProjectTargetDate;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/clear_project_lead.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ClearProjectLead;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/archive_project.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ArchiveProject;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/project_milestones.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ListProjectMilestones;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/create_project_milestone.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
+    skip_serializing_none
+)]
+pub struct CreateProjectMilestone;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/update_project_milestone.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone, Default",
+    skip_serializing_none
+)]
+pub struct UpdateProjectMilestone;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/clear_project_milestone_target_date.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct ClearProjectMilestoneTargetDate;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/delete_project_milestone.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct DeleteProjectMilestone;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/queries/project_updates.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct GetProjectUpdates;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "graphql/schema.json",
+    query_path = "graphql/mutations/create_project_update.graphql",
+    response_derives = "Debug, Clone",
+    variables_derives = "Debug, Clone",
+    skip_serializing_none
+)]
+pub struct CreateProjectUpdate;
+
 #[derive(GraphQLQuery)]
 #[graphql(
     schema_path = "graphql/schema.json",
@@ -235,7 +502,7 @@ pub struct SemanticSearch;
 
 pub use viewer::ResponseData as ViewerResponseData;
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Issue {
     pub id: String,
@@ -247,6 +514,22 @@ pub struct Issue {
     pub assignee_id: Option<String>,
     pub team: Option<String>,
     pub team_id: String,
+    pub priority: Option<i64>,
+    pub priority_label: Option<String>,
+    pub estimate: Option<f64>,
+    pub labels: Vec<IssueLabel>,
+    pub cycle: Option<IssueCycle>,
+    pub project: Option<String>,
+    pub due_date: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCycle {
+    pub number: i64,
+    pub name: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -260,12 +543,34 @@ pub struct DetailedIssue {
     pub assignee: Option<IssueAssignee>,
     pub team: Option<IssueTeam>,
     pub project: Option<IssueProject>,
+    /// Name of the project milestone the issue belongs to
+    pub milestone: Option<String>,
     pub labels: Vec<IssueLabel>,
     pub priority: Option<i64>,
     pub priority_label: Option<String>,
+    pub estimate: Option<f64>,
+    pub cycle: Option<IssueCycle>,
+    pub due_date: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     pub url: String,
+    pub parent: Option<IssueNode>,
+    /// Direct sub-issues
+    pub children: Vec<IssueNode>,
+}
+
+/// An issue within a parent/sub-issue hierarchy
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueNode {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub state: IssueState,
+    pub assignee: Option<String>,
+    /// Sub-issues; only filled in by [`LinearClient::get_issue_tree`]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<IssueNode>,
 }
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
@@ -295,6 +600,7 @@ pub struct IssueTeam {
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueProject {
+    pub id: String,
     pub name: String,
 }
 
@@ -318,6 +624,7 @@ pub struct CreatedIssue {
     pub labels: Vec<IssueLabel>,
     pub priority: Option<i64>,
     pub priority_label: Option<String>,
+    pub due_date: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     pub url: String,
@@ -328,15 +635,22 @@ pub struct CreateIssueInput {
     pub title: String,
     pub description: Option<String>,
     pub team_id: Option<String>,
+    /// Workflow state; the team's default state when unset
+    pub state_id: Option<String>,
     pub assignee_id: Option<String>,
     pub priority: Option<i64>,
     pub label_ids: Option<Vec<String>>,
     pub project_id: Option<String>,
+    /// Milestone within `project_id`
+    pub project_milestone_id: Option<String>,
     pub estimate: Option<i64>,
     pub cycle_id: Option<String>,
+    pub parent_id: Option<String>,
+    /// Due date as YYYY-MM-DD
+    pub due_date: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct UpdateIssueInput {
     pub title: Option<String>,
     pub description: Option<String>,
@@ -345,8 +659,14 @@ pub struct UpdateIssueInput {
     pub priority: Option<i64>,
     pub label_ids: Option<Vec<String>>,
     pub project_id: Option<String>,
+    /// Project milestone ID; an empty string removes the issue from its milestone
+    pub project_milestone_id: Option<String>,
     pub estimate: Option<i64>,
     pub cycle_id: Option<String>,
+    /// Parent issue ID; an empty string detaches the issue from its parent
+    pub parent_id: Option<String>,
+    /// Due date as YYYY-MM-DD; an empty string clears it
+    pub due_date: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -416,6 +736,7 @@ pub struct Project {
     pub created_at: String,
     pub updated_at: String,
     pub lead: Option<ProjectLead>,
+    pub target_date: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -434,34 +755,185 @@ pub struct ProjectLead {
     pub display_name: String,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ProjectFilters {
+    /// Team key; matches projects the team takes part in
+    pub team: Option<String>,
+    /// Project status name or type (e.g. "In Progress" or "started")
+    pub state: Option<String>,
+    /// Lead name, or "me"
+    pub lead: Option<String>,
+}
+
+/// A project with its people, milestones, and issues
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Comment {
+pub struct ProjectDetail {
     pub id: String,
-    pub body: String,
+    pub name: String,
+    /// One-line summary
+    pub description: Option<String>,
+    /// Markdown body
+    pub content: Option<String>,
+    pub status: ProjectStatus,
+    pub progress: f64,
+    /// "onTrack", "atRisk", or "offTrack", as of the latest project update
+    pub health: Option<String>,
+    pub start_date: Option<String>,
+    pub target_date: Option<String>,
+    pub url: String,
     pub created_at: String,
     pub updated_at: String,
-    pub user: CommentUser,
+    pub lead: Option<ProjectLead>,
+    pub members: Vec<ProjectLead>,
+    pub teams: Vec<ProjectTeam>,
+    pub milestones: Vec<ProjectMilestone>,
+    pub issues: Vec<IssueNode>,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct IssueWithComments {
+pub struct ProjectStatus {
     pub id: String,
-    pub identifier: String,
-    pub title: String,
-    pub comments: Vec<Comment>,
+    pub name: String,
+    /// backlog, planned, started, paused, completed, or canceled
+    #[serde(rename = "type")]
+    pub type_: String,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
-pub struct MyWork {
-    pub assigned_issues: Vec<Issue>,
-    pub created_issues: Vec<Issue>,
+pub struct ProjectMilestone {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub target_date: Option<String>,
+    /// Share of the milestone's issues that are done, from 0.0 to 1.0
+    pub progress: f64,
+    /// "unstarted", "next", "overdue", or "done"
+    pub status: String,
 }
 
-#[derive(Debug, Clone, serde::Serialize)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone, Default)]
+pub struct CreateProjectMilestoneInput {
+    pub project_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Target date as YYYY-MM-DD
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateProjectMilestoneInput {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    /// Target date as YYYY-MM-DD; an empty string clears it
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CreateProjectInput {
+    pub name: String,
+    pub team_ids: Vec<String>,
+    /// One-line summary
+    pub description: Option<String>,
+    /// Markdown body
+    pub content: Option<String>,
+    pub lead_id: Option<String>,
+    pub status_id: Option<String>,
+    /// Target date as YYYY-MM-DD
+    pub target_date: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UpdateProjectInput {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub content: Option<String>,
+    /// Lead user ID; an empty string removes the lead
+    pub lead_id: Option<String>,
+    /// Replaces the project's teams
+    pub team_ids: Option<Vec<String>>,
+    pub status_id: Option<String>,
+    /// Target date as YYYY-MM-DD; an empty string clears it
+    pub target_date: Option<String>,
+}
+
+/// A project's health as reported in a project update
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectHealth {
+    OnTrack,
+    AtRisk,
+    OffTrack,
+}
+
+/// A status post on a project
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectUpdate {
+    pub id: String,
+    /// Markdown body
+    pub body: String,
+    /// "onTrack", "atRisk", or "offTrack"
+    pub health: String,
+    pub url: String,
+    pub created_at: String,
+    pub updated_at: String,
+    /// Name of the user who posted it
+    pub author: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateProjectUpdateInput {
+    pub project_id: String,
+    pub body: String,
+    pub health: Option<ProjectHealth>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedProject {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comment {
+    pub id: String,
+    pub body: String,
+    pub created_at: String,
+    pub updated_at: String,
+    pub user: CommentUser,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueWithComments {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyWork {
+    pub assigned_issues: Vec<Issue>,
+    pub created_issues: Vec<Issue>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MyWorkFilters {
+    /// Date or ISO 8601 duration (e.g. "P7D")
+    pub due_before: Option<String>,
+    /// Only open issues whose due date has passed
+    pub overdue: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SearchResult {
     pub issues: Vec<Issue>,
     pub documents: Vec<Document>,
@@ -521,6 +993,7 @@ pub struct UpdatedIssue {
     pub labels: Vec<IssueLabel>,
     pub priority: Option<i64>,
     pub priority_label: Option<String>,
+    pub due_date: Option<String>,
     pub created_at: String,
     pub updated_at: String,
     pub url: String,
@@ -564,6 +1037,69 @@ pub struct WorkflowState {
     pub position: Option<f64>,
 }
 
+/// A team's cycle, with its scope as of the latest point in Linear's cycle history
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cycle {
+    pub id: String,
+    pub number: i64,
+    pub name: Option<String>,
+    pub starts_at: String,
+    pub ends_at: String,
+    pub completed_at: Option<String>,
+    /// Whether this is the team's active cycle
+    pub is_active: bool,
+    /// Fraction of the scope completed, from 0 to 1
+    pub progress: f64,
+    /// Estimate points planned into the cycle
+    pub scope: f64,
+    /// Points in started states
+    pub started_scope: f64,
+    pub completed_scope: f64,
+    pub issue_count: i64,
+    pub completed_issue_count: i64,
+}
+
+/// A cycle with its team and issues
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleDetail {
+    #[serde(flatten)]
+    pub cycle: Cycle,
+    pub team: IssueTeam,
+    pub issues: Vec<CycleIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleIssue {
+    pub id: String,
+    pub identifier: String,
+    pub title: String,
+    pub estimate: Option<f64>,
+    pub state: IssueState,
+    pub assignee: Option<String>,
+}
+
+/// A cycle's day-by-day history; each series has one entry per day since the cycle started
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleHistory {
+    #[serde(flatten)]
+    pub cycle: Cycle,
+    pub team: IssueTeam,
+    pub scope_history: Vec<f64>,
+    pub in_progress_scope_history: Vec<f64>,
+    pub completed_scope_history: Vec<f64>,
+    pub issue_count_history: Vec<f64>,
+    pub completed_issue_count_history: Vec<f64>,
+}
+
+/// The last value of a cycle history series, or zero before the cycle has any history
+fn latest(history: &[f64]) -> f64 {
+    history.last().copied().unwrap_or(0.0)
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TeamWithStates {
@@ -606,375 +1142,181 @@ pub struct TeamMember {
     pub active: bool,
 }
 
-pub struct LinearClient {
-    pub(crate) client: reqwest::Client,
-    pub(crate) base_url: String,
-    pub(crate) verbose: bool,
-    pub(crate) retry_config: retry::RetryConfig,
+/// Report a missing entity in an issue mutation as the issue the caller asked for
+fn issue_not_found(id: &str, error: LinearError) -> LinearError {
+    match error {
+        LinearError::NotFound { .. } => LinearError::IssueNotFound {
+            identifier: id.to_string(),
+            suggestion: None,
+        },
+        e => e,
+    }
+}
+
+/// The name Linear gives a generated enum value, e.g. "onTrack" or "done". Every query gets
+/// its own copy of an enum like `ProjectMilestoneStatus`, but they all serialize the same way,
+/// unknown values included
+fn enum_name(value: impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => String::new(),
+    }
+}
+
+fn mutation_succeeded(success: bool, action: &str) -> Result<()> {
+    if success {
+        Ok(())
+    } else {
+        Err(LinearError::GraphQL {
+            message: format!("{action} failed"),
+            errors: vec![],
+        })
+    }
+}
+
+/// Move each node's sub-issues out of `children_of` into place, depth first
+fn attach_children(node: &mut IssueNode, children_of: &mut HashMap<String, Vec<IssueNode>>) {
+    node.children = children_of.remove(&node.id).unwrap_or_default();
+    for child in &mut node.children {
+        attach_children(child, children_of);
+    }
+}
+
+/// Client for the Linear API
+///
+/// Queries are sent through a [`GraphQLExecutor`]. The default [`ReqwestExecutor`] talks to
+/// Linear over HTTP; [`LinearClient::with_executor`] accepts any other implementation.
+pub struct LinearClient<E = ReqwestExecutor> {
+    pub(crate) executor: E,
 }
 
+#[derive(Debug, Clone, Default)]
 pub struct IssueFilters {
     pub assignee: Option<String>,
     pub status: Option<String>,
     pub team: Option<String>,
+    /// Label names; every label must be present
+    pub labels: Vec<String>,
+    /// Project name or ID
+    pub project: Option<String>,
+    /// "current", "next", or a cycle number
+    pub cycle: Option<String>,
+    pub priority: Option<i64>,
+    /// Creator name, or "me"
+    pub creator: Option<String>,
+    /// Workflow state types such as "started" or "backlog"
+    pub state_types: Vec<String>,
+    /// Timestamp or ISO 8601 duration (e.g. "-P7D")
+    pub created_after: Option<String>,
+    /// Timestamp or ISO 8601 duration (e.g. "-P7D")
+    pub updated_since: Option<String>,
+    /// Date or ISO 8601 duration (e.g. "P7D")
+    pub due_before: Option<String>,
+    /// Only open issues whose due date has passed
+    pub overdue: bool,
+    /// Parent issue identifier (e.g. ENG-123) or ID
+    pub parent: Option<String>,
+    pub no_estimate: bool,
+    pub include_archived: bool,
 }
 
 impl LinearClient {
     pub fn from_config(config: LinearClientConfig) -> Result<Self> {
-        let auth_token = config.auth_token.expose_secret();
-
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(auth_token).map_err(|_| LinearError::Auth {
-                reason: Cow::Borrowed("Invalid API key format"),
-                source: None,
-            })?,
-        );
-        headers.insert(USER_AGENT, HeaderValue::from_static("linear-cli/0.1.0"));
-
-        let mut client_builder = reqwest::Client::builder()
-            .default_headers(headers)
-            .timeout(config.timeout);
-
-        if let Some(proxy) = config.proxy {
-            client_builder = client_builder.proxy(proxy);
-        }
-
-        let client = client_builder.build().map_err(LinearError::from)?;
+        Ok(Self::with_executor(ReqwestExecutor::from_config(config)?))
+    }
 
-        let retry_config = retry::RetryConfig {
-            max_attempts: config.max_attempts as u32,
-            initial_delay: std::time::Duration::from_millis(100),
-            max_delay: std::time::Duration::from_secs(10),
-            backoff_multiplier: 2.0,
-        };
+    /// Requests sent and query complexity spent through this client so far
+    pub fn usage(&self) -> RequestUsage {
+        self.executor.usage()
+    }
 
-        Ok(Self {
-            client,
-            base_url: config
-                .base_url
-                .unwrap_or_else(|| urls::LINEAR_API_BASE.to_string()),
-            verbose: config.verbose,
-            retry_config,
-        })
+    fn looks_like_uuid(value: &str) -> bool {
+        value.len() == 36 && value.chars().all(|c| c.is_ascii_hexdigit() || c == '-')
     }
 
-    /// Execute a GraphQL query using the abstraction layer
-    async fn execute_graphql<Q, V>(&self, variables: V) -> Result<Q::ResponseData>
-    where
-        Q: GraphQLQuery + Send + Sync,
-        Q::ResponseData: Debug + serde::de::DeserializeOwned + Send,
-        Q::Variables: Debug + Send + Sync + Clone,
-        V: Into<Q::Variables> + Send + Debug,
-    {
-        let variables = variables.into();
-
-        // Build the query request
-        let request_body = Q::build_query(variables);
-
-        if self.verbose {
-            let query_name = std::any::type_name::<Q>()
-                .split("::")
-                .last()
-                .unwrap_or("unknown");
-            log::debug!("Sending GraphQL query: {query_name}");
-            log::debug!(
-                "Request body: {}",
-                serde_json::to_string_pretty(&request_body).unwrap_or_default()
-            );
+    /// Split an identifier like "ENG-123" into its team key and issue number
+    fn parse_issue_identifier(identifier: &str) -> Option<(String, i64)> {
+        let (team_key, number) = identifier.rsplit_once('-')?;
+        if team_key.is_empty() {
+            return None;
         }
-
-        // Execute using the client's retry logic
-        retry::retry_with_backoff(&self.retry_config, self.verbose, || {
-            let client = &self.client;
-            let base_url = &self.base_url;
-            let request_body = &request_body;
-            let verbose = self.verbose;
-
-            async move {
-                let start_time = std::time::Instant::now();
-                let response = client
-                    .post(format!("{base_url}/graphql"))
-                    .json(request_body)
-                    .send()
-                    .await
-                    .map_err(LinearError::from)?;
-
-                if verbose {
-                    log::debug!("Request completed in {:?}", start_time.elapsed());
-                    log::debug!("Response status: {}", response.status());
-                }
-
-                // Check for HTTP error status codes
-                if !response.status().is_success() {
-                    let status = response.status();
-                    // Capture the response body so API errors (e.g. scope/validation
-                    // failures) surface instead of a bare "HTTP error: 400".
-                    let body = response.text().await.unwrap_or_default();
-                    let mut err = LinearError::from_status(
-                        http::StatusCode::from_u16(status.as_u16()).unwrap(),
-                    );
-                    if let LinearError::Network {
-                        ref mut message, ..
-                    } = err
-                    {
-                        if !body.trim().is_empty() {
-                            *message = format!("HTTP error: {status}: {body}");
-                        }
-                    }
-                    return Err(err);
-                }
-
-                let response_body: Response<Q::ResponseData> =
-                    response.json().await.map_err(LinearError::from)?;
-
-                if let Some(errors) = response_body.errors {
-                    return Err(LinearError::GraphQL {
-                        message: format!("{errors:?}"),
-                        errors: vec![],
-                    });
-                }
-
-                response_body.data.ok_or(LinearError::InvalidResponse)
-            }
-        })
-        .await
+        let number = number.parse().ok()?;
+        Some((team_key.to_uppercase(), number))
     }
 
-    async fn build_issue_filter(
-        &self,
-        filters: &IssueFilters,
-    ) -> Result<Option<list_issues::IssueFilter>> {
-        use list_issues::*;
-
-        // Create a minimal issue filter with only the fields we need
-        let mut issue_filter = IssueFilter {
-            id: None,
-            created_at: None,
-            updated_at: None,
-            number: None,
-            title: None,
-            description: None,
-            priority: None,
-            estimate: None,
-            started_at: None,
-            triaged_at: None,
-            completed_at: None,
-            canceled_at: None,
-            archived_at: None,
-            auto_closed_at: None,
-            auto_archived_at: None,
-            added_to_cycle_at: None,
-            added_to_cycle_period: None,
-            due_date: None,
-            snoozed_until_at: None,
-            assignee: Box::new(None),
-            last_applied_template: None,
-            recurring_issue_template: None,
-            source_metadata: None,
-            creator: Box::new(None),
-            parent: Box::new(None),
-            snoozed_by: Box::new(None),
-            labels: Box::new(None),
-            subscribers: Box::new(None),
-            team: Box::new(None),
-            project_milestone: None,
-            comments: Box::new(None),
-            cycle: Box::new(None),
-            project: Box::new(None),
-            state: Box::new(None),
-            children: Box::new(None),
-            attachments: Box::new(None),
-            searchable_content: None,
-            has_related_relations: None,
-            has_duplicate_relations: None,
-            has_blocked_by_relations: None,
-            has_blocking_relations: None,
-            sla_status: None,
-            reactions: None,
-            needs: Box::new(None),
-            customer_count: None,
-            lead_time: None,
-            cycle_time: None,
-            age_time: None,
-            triage_time: None,
-            and: Box::new(None),
-            or: Box::new(None),
-        };
-
-        let mut has_filters = false;
+    /// Translate CLI-style project filters into a GraphQL `ProjectFilter`
+    pub fn build_project_filter(filters: &ProjectFilters) -> Option<list_projects::ProjectFilter> {
+        use list_projects::*;
 
-        // Handle assignee filter
-        if let Some(assignee_value) = &filters.assignee {
-            match assignee_value.as_str() {
-                "me" => {
-                    // We need to query for the current user's ID
-                    let viewer_data = self.execute_viewer_query().await?;
-                    let viewer_id = viewer_data.viewer.id;
-
-                    // Create a minimal filter with only the fields we need
-                    issue_filter.assignee = Box::new(Some(NullableUserFilter {
-                        id: Some(IDComparator {
-                            eq: Some(viewer_id),
-                            neq: None,
-                            in_: None,
-                            nin: None,
-                        }),
-                        created_at: None,
-                        updated_at: None,
-                        name: None,
-                        display_name: None,
-                        email: None,
-                        active: None,
-                        assigned_issues: Box::new(None),
-                        admin: None,
-                        invited: None,
-                        app: None,
-                        is_me: None,
-                        null: None,
-                        and: Box::new(None),
-                        or: Box::new(None),
-                    }));
-                    has_filters = true;
-                }
-                "unassigned" => {
-                    // For unassigned, we only need the null field
-                    issue_filter.assignee = Box::new(Some(NullableUserFilter {
-                        id: None,
-                        created_at: None,
-                        updated_at: None,
-                        name: None,
-                        display_name: None,
-                        email: None,
-                        active: None,
-                        assigned_issues: Box::new(None),
-                        admin: None,
-                        invited: None,
-                        app: None,
-                        is_me: None,
-                        null: Some(true),
-                        and: Box::new(None),
-                        or: Box::new(None),
-                    }));
-                    has_filters = true;
-                }
-                _ => {
-                    // Filter by assignee name
-                    issue_filter.assignee = Box::new(Some(NullableUserFilter {
-                        id: None,
-                        created_at: None,
-                        updated_at: None,
-                        name: Some(StringComparator {
-                            eq: Some(assignee_value.clone()),
-                            neq: None,
-                            in_: None,
-                            nin: None,
-                            eq_ignore_case: None,
-                            neq_ignore_case: None,
-                            starts_with: None,
-                            starts_with_ignore_case: None,
-                            not_starts_with: None,
-                            ends_with: None,
-                            not_ends_with: None,
-                            contains: None,
-                            contains_ignore_case: None,
-                            not_contains: None,
-                            not_contains_ignore_case: None,
-                            contains_ignore_case_and_accent: None,
-                        }),
-                        display_name: None,
-                        email: None,
-                        active: None,
-                        assigned_issues: Box::new(None),
-                        admin: None,
-                        invited: None,
-                        app: None,
-                        is_me: None,
-                        null: None,
-                        and: Box::new(None),
-                        or: Box::new(None),
-                    }));
-                    has_filters = true;
-                }
+        fn eq_ignore_case(value: &str) -> StringComparator {
+            StringComparator {
+                eq_ignore_case: Some(value.to_string()),
+                ..Default::default()
             }
         }
 
-        // Handle status filter
-        if let Some(status_value) = &filters.status {
-            let normalized_status = Self::normalize_status(status_value);
-            issue_filter.state = Box::new(Some(WorkflowStateFilter {
-                id: None,
-                created_at: None,
-                updated_at: None,
-                name: Some(StringComparator {
-                    eq: Some(normalized_status),
-                    neq: None,
-                    in_: None,
-                    nin: None,
-                    eq_ignore_case: None,
-                    neq_ignore_case: None,
-                    starts_with: None,
-                    starts_with_ignore_case: None,
-                    not_starts_with: None,
-                    ends_with: None,
-                    not_ends_with: None,
-                    contains: None,
-                    contains_ignore_case: None,
-                    not_contains: None,
-                    not_contains_ignore_case: None,
-                    contains_ignore_case_and_accent: None,
-                }),
-                description: None,
-                position: None,
-                type_: None,
-                team: Box::new(None),
-                issues: Box::new(None),
-                and: Box::new(None),
-                or: Box::new(None),
+        let mut filter = ProjectFilter::default();
+        let mut empty = true;
+
+        if let Some(team) = &filters.team {
+            filter.accessible_teams = Box::new(Some(TeamCollectionFilter {
+                some: Box::new(Some(TeamFilter {
+                    key: Some(eq_ignore_case(team)),
+                    ..Default::default()
+                })),
+                ..Default::default()
             }));
-            has_filters = true;
+            empty = false;
         }
 
-        // Handle team filter
-        if let Some(team_value) = &filters.team {
-            issue_filter.team = Box::new(Some(TeamFilter {
-                id: None,
-                created_at: None,
-                updated_at: None,
-                name: None,
-                key: Some(StringComparator {
-                    eq: Some(team_value.clone()),
-                    neq: None,
-                    in_: None,
-                    nin: None,
-                    eq_ignore_case: None,
-                    neq_ignore_case: None,
-                    starts_with: None,
-                    starts_with_ignore_case: None,
-                    not_starts_with: None,
-                    ends_with: None,
-                    not_ends_with: None,
-                    contains: None,
-                    contains_ignore_case: None,
-                    not_contains: None,
-                    not_contains_ignore_case: None,
-                    contains_ignore_case_and_accent: None,
-                }),
-                description: None,
-                issues: Box::new(None),
-                parent: Box::new(None),
-                and: Box::new(None),
-                or: Box::new(None),
+        if let Some(state) = &filters.state {
+            filter.status = Box::new(Some(ProjectStatusFilter {
+                or: Box::new(Some(vec![
+                    ProjectStatusFilter {
+                        name: Some(eq_ignore_case(state)),
+                        ..Default::default()
+                    },
+                    ProjectStatusFilter {
+                        type_: Some(StringComparator {
+                            eq: Some(state.to_lowercase()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                ])),
+                ..Default::default()
             }));
-            has_filters = true;
+            empty = false;
         }
 
-        if has_filters {
-            Ok(Some(issue_filter))
-        } else {
-            Ok(None)
+        if let Some(lead) = &filters.lead {
+            filter.lead = Box::new(Some(if lead.eq_ignore_ascii_case("me") {
+                NullableUserFilter {
+                    is_me: Some(BooleanComparator {
+                        eq: Some(true),
+                        neq: None,
+                    }),
+                    ..Default::default()
+                }
+            } else {
+                NullableUserFilter {
+                    or: Box::new(Some(vec![
+                        NullableUserFilter {
+                            name: Some(eq_ignore_case(lead)),
+                            ..Default::default()
+                        },
+                        NullableUserFilter {
+                            display_name: Some(eq_ignore_case(lead)),
+                            ..Default::default()
+                        },
+                    ])),
+                    ..Default::default()
+                }
+            }));
+            empty = false;
         }
+
+        (!empty).then_some(filter)
     }
 
     fn normalize_status(status: &str) -> String {
@@ -1001,80 +1343,322 @@ impl LinearClient {
             }
         }
     }
+}
 
-    pub async fn execute_viewer_query(&self) -> Result<viewer::ResponseData> {
-        self.execute_graphql::<Viewer, _>(viewer::Variables {})
-            .await
+impl<E: GraphQLExecutor> LinearClient<E> {
+    /// Build a client over any [`GraphQLExecutor`], such as a mock, a recording executor, or
+    /// middleware wrapping a [`ReqwestExecutor`]
+    pub fn with_executor(executor: E) -> Self {
+        Self { executor }
     }
 
-    pub async fn list_issues(&self, limit: i32) -> Result<Vec<Issue>> {
-        self.list_issues_with_filter(limit, None).await
+    /// The executor this client sends queries through
+    pub fn executor(&self) -> &E {
+        &self.executor
     }
 
-    pub async fn list_issues_filtered(
-        &self,
-        limit: i32,
-        filters: Option<IssueFilters>,
-    ) -> Result<Vec<Issue>> {
-        let graphql_filter = if let Some(filters) = filters {
-            self.build_issue_filter(&filters).await?
-        } else {
-            None
-        };
+    /// Remaining request and complexity budget as of the last response, when known
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        self.executor.rate_limit()
+    }
 
-        self.list_issues_with_filter(limit, graphql_filter).await
+    /// Execute a GraphQL query through the client's executor
+    async fn execute_graphql<Q, V>(&self, variables: V) -> Result<Q::ResponseData>
+    where
+        Q: GraphQLQuery + Send + Sync,
+        Q::ResponseData: Debug + serde::de::DeserializeOwned + Send,
+        Q::Variables: Debug + Send + Sync + Clone,
+        V: Into<Q::Variables> + Send + Debug,
+    {
+        self.executor.execute::<Q, V>(variables).await
     }
 
-    pub async fn list_issues_with_filter(
-        &self,
-        limit: i32,
-        filter: Option<list_issues::IssueFilter>,
-    ) -> Result<Vec<Issue>> {
-        let variables = list_issues::Variables {
-            first: limit as i64,
-            filter,
-        };
+    /// Execute an arbitrary GraphQL document built with [`QueryBuilder`], returning its `data`
+    ///
+    /// Requests go through the same executor as the typed methods (with the default
+    /// [`ReqwestExecutor`], the same authentication, retries, and verbose logging), so this is
+    /// the escape hatch for fields the SDK doesn't model.
+    pub async fn execute_raw(&self, query: &QueryBuilder) -> Result<serde_json::Value> {
+        self.executor.execute_raw(query).await
+    }
 
-        let data = self.execute_graphql::<ListIssues, _>(variables).await?;
-        let issues = data
-            .issues
-            .nodes
-            .into_iter()
-            .map(|issue| Issue {
-                id: issue.id,
-                identifier: issue.identifier,
-                title: issue.title,
-                status: issue.state.name.clone(),
-                state_id: issue.state.id,
-                assignee: issue.assignee.as_ref().map(|a| a.name.clone()),
-                assignee_id: issue.assignee.map(|a| a.id),
-                team: Some(issue.team.key.clone()),
-                team_id: issue.team.id,
-            })
-            .collect();
-        Ok(issues)
+    /// Execute a raw query page by page, passing each page's `endCursor` as the `$after`
+    /// variable, and return the first page's `data` with every page's `nodes` merged into it
+    ///
+    /// The document must declare an `$after: String` variable and select
+    /// `pageInfo { hasNextPage endCursor }` alongside `nodes` on the paginated connection.
+    pub async fn execute_raw_paginated(&self, query: QueryBuilder) -> Result<serde_json::Value> {
+        if !query.query().contains("$after") {
+            return Err(LinearError::InvalidInput {
+                message: "Paginated queries must accept an `$after: String` variable".to_string(),
+            });
+        }
+
+        let mut merged = self.execute_raw(&query).await?;
+        let mut cursor = pagination::raw_next_cursor(&merged)?;
+
+        while let Some(after) = cursor {
+            let page = self
+                .execute_raw(&query.clone().variable("after", after))
+                .await?;
+            cursor = pagination::raw_next_cursor(&page)?;
+            pagination::merge_raw_page(&mut merged, page);
+        }
+
+        Ok(merged)
     }
 
-    pub async fn get_issue(&self, id: String) -> Result<DetailedIssue> {
-        let variables = get_issue::Variables { id: id.clone() };
+    /// Translate simple CLI-style filters into a GraphQL `IssueFilter`
+    pub async fn build_issue_filter(
+        &self,
+        filters: &IssueFilters,
+    ) -> Result<Option<list_issues::IssueFilter>> {
+        let mut builder = IssueFilterBuilder::new();
 
-        // Execute query through the GraphQL abstraction layer with error handling
-        let data = match self.execute_graphql::<GetIssue, _>(variables).await {
-            Ok(data) => data,
-            Err(LinearError::GraphQL { message, .. }) => {
-                // Check if this is a "not found" error
-                if message.contains("not found") || message.contains("not exist") {
-                    return Err(LinearError::IssueNotFound {
-                        identifier: id,
-                        suggestion: None,
-                    });
+        // Handle assignee filter
+        if let Some(assignee_value) = &filters.assignee {
+            builder = match assignee_value.as_str() {
+                "me" => {
+                    // We need to query for the current user's ID
+                    let viewer_data = self.execute_viewer_query().await?;
+                    builder.assignee_id(viewer_data.viewer.id)
                 }
-                return Err(LinearError::GraphQL {
-                    message,
-                    errors: vec![],
-                });
-            }
-            Err(e) => return Err(e),
+                "unassigned" => builder.unassigned(),
+                _ => builder.assignee_name(assignee_value.clone()),
+            };
+        }
+
+        // Handle status filter
+        if let Some(status_value) = &filters.status {
+            builder = builder.state_name(LinearClient::normalize_status(status_value));
+        }
+
+        // Handle team filter
+        if let Some(team_value) = &filters.team {
+            builder = builder.team_key(team_value.clone());
+        }
+
+        if !filters.state_types.is_empty() {
+            builder = builder.state_type_in(filters.state_types.iter().cloned());
+        }
+
+        for label in &filters.labels {
+            builder = builder.label(label.clone());
+        }
+
+        if let Some(project) = &filters.project {
+            builder = if LinearClient::looks_like_uuid(project) {
+                builder.project_id(project.clone())
+            } else {
+                builder.project_name(project.clone())
+            };
+        }
+
+        if let Some(cycle) = &filters.cycle {
+            builder = match cycle.to_lowercase().as_str() {
+                "current" | "active" => builder.cycle_is_active(),
+                "next" => builder.cycle_is_next(),
+                other => match other.parse::<i64>() {
+                    Ok(number) => builder.cycle_number(number),
+                    Err(_) => {
+                        return Err(LinearError::InvalidInput {
+                            message: format!(
+                                "Invalid cycle '{cycle}'. Use 'current', 'next', or a cycle number"
+                            ),
+                        })
+                    }
+                },
+            };
+        }
+
+        if let Some(priority) = filters.priority {
+            builder = builder.priority_eq(priority as f64);
+        }
+
+        if let Some(creator) = &filters.creator {
+            builder = match creator.as_str() {
+                "me" => builder.creator_is_me(),
+                _ => builder.creator_name(creator.clone()),
+            };
+        }
+
+        if let Some(created_after) = &filters.created_after {
+            builder = builder.created_after(created_after.clone());
+        }
+
+        if let Some(updated_since) = &filters.updated_since {
+            builder = builder.updated_after(updated_since.clone());
+        }
+
+        if let Some(due_before) = &filters.due_before {
+            builder = builder.due_before(due_before.clone());
+        }
+
+        if filters.overdue {
+            builder = builder.overdue();
+        }
+
+        if let Some(parent) = &filters.parent {
+            builder =
+                if LinearClient::looks_like_uuid(parent) {
+                    builder.parent_id(parent.clone())
+                } else {
+                    let (team_key, number) = LinearClient::parse_issue_identifier(parent)
+                        .ok_or_else(|| LinearError::InvalidInput {
+                            message: format!(
+                                "Invalid parent issue '{parent}'. Use an identifier like ENG-123"
+                            ),
+                        })?;
+                    builder.parent_identifier(team_key, number)
+                };
+        }
+
+        if filters.no_estimate {
+            builder = builder.no_estimate();
+        }
+
+        if builder.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(builder.build()))
+        }
+    }
+
+    pub async fn execute_viewer_query(&self) -> Result<viewer::ResponseData> {
+        self.execute_graphql::<Viewer, _>(viewer::Variables {})
+            .await
+    }
+
+    pub async fn list_issues(&self, limit: impl Into<PageLimit>) -> Result<Vec<Issue>> {
+        self.list_issues_with_filter(limit, None).await
+    }
+
+    pub async fn list_issues_filtered(
+        &self,
+        limit: impl Into<PageLimit>,
+        filters: Option<IssueFilters>,
+    ) -> Result<Vec<Issue>> {
+        let (graphql_filter, include_archived) = if let Some(filters) = filters {
+            (
+                self.build_issue_filter(&filters).await?,
+                filters.include_archived,
+            )
+        } else {
+            (None, false)
+        };
+
+        self.list_issues_with_options(limit, graphql_filter, include_archived)
+            .await
+    }
+
+    pub async fn list_issues_with_filter(
+        &self,
+        limit: impl Into<PageLimit>,
+        filter: Option<list_issues::IssueFilter>,
+    ) -> Result<Vec<Issue>> {
+        self.list_issues_with_options(limit, filter, false).await
+    }
+
+    /// List issues matching `filter`, optionally including archived issues
+    pub async fn list_issues_with_options(
+        &self,
+        limit: impl Into<PageLimit>,
+        filter: Option<list_issues::IssueFilter>,
+        include_archived: bool,
+    ) -> Result<Vec<Issue>> {
+        pagination::collect_pages(limit.into(), |first, after| {
+            self.fetch_issues_page(first, after, filter.clone(), include_archived)
+        })
+        .await
+    }
+
+    /// Stream issues matching `filter`, fetching further pages only as the stream is polled
+    pub fn issues_stream(&self, filter: Option<list_issues::IssueFilter>) -> PageStream<'_, Issue> {
+        self.issues_stream_with_options(filter, false)
+    }
+
+    /// Stream issues matching `filter`, optionally including archived issues
+    pub fn issues_stream_with_options(
+        &self,
+        filter: Option<list_issues::IssueFilter>,
+        include_archived: bool,
+    ) -> PageStream<'_, Issue> {
+        pagination::stream_pages(move |first, after| {
+            self.fetch_issues_page(first, after, filter.clone(), include_archived)
+        })
+    }
+
+    async fn fetch_issues_page(
+        &self,
+        first: i64,
+        after: Option<String>,
+        filter: Option<list_issues::IssueFilter>,
+        include_archived: bool,
+    ) -> Result<(Vec<Issue>, PageInfo)> {
+        let variables = list_issues::Variables {
+            first,
+            after,
+            filter,
+            include_archived: include_archived.then_some(true),
+        };
+
+        let data = self.execute_graphql::<ListIssues, _>(variables).await?;
+        let issues = data
+            .issues
+            .nodes
+            .into_iter()
+            .map(|issue| Issue {
+                id: issue.id,
+                identifier: issue.identifier,
+                title: issue.title,
+                status: issue.state.name.clone(),
+                state_id: issue.state.id,
+                assignee: issue.assignee.as_ref().map(|a| a.name.clone()),
+                assignee_id: issue.assignee.map(|a| a.id),
+                team: Some(issue.team.key.clone()),
+                team_id: issue.team.id,
+                priority: Some(issue.priority as i64),
+                priority_label: Some(issue.priority_label),
+                estimate: issue.estimate,
+                labels: issue
+                    .labels
+                    .nodes
+                    .into_iter()
+                    .map(|label| IssueLabel {
+                        name: label.name,
+                        color: label.color,
+                    })
+                    .collect(),
+                cycle: issue.cycle.map(|cycle| IssueCycle {
+                    number: cycle.number as i64,
+                    name: cycle.name,
+                }),
+                project: issue.project.map(|project| project.name),
+                due_date: issue.due_date,
+                created_at: Some(issue.created_at),
+                updated_at: Some(issue.updated_at),
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_next_page: data.issues.page_info.has_next_page,
+            end_cursor: data.issues.page_info.end_cursor,
+        };
+        Ok((issues, page_info))
+    }
+
+    pub async fn get_issue(&self, id: String) -> Result<DetailedIssue> {
+        let variables = get_issue::Variables { id: id.clone() };
+
+        // Execute query through the GraphQL abstraction layer with error handling
+        let data = match self.execute_graphql::<GetIssue, _>(variables).await {
+            Ok(data) => data,
+            Err(LinearError::NotFound { .. }) => {
+                return Err(LinearError::IssueNotFound {
+                    identifier: id,
+                    suggestion: None,
+                });
+            }
+            Err(e) => return Err(e),
         };
 
         let issue = data.issue;
@@ -1097,7 +1681,11 @@ impl LinearClient {
                 key: issue.team.key,
                 name: issue.team.name,
             }),
-            project: issue.project.map(|p| IssueProject { name: p.name }),
+            project: issue.project.map(|p| IssueProject {
+                id: p.id,
+                name: p.name,
+            }),
+            milestone: issue.project_milestone.map(|m| m.name),
             labels: issue
                 .labels
                 .nodes
@@ -1109,12 +1697,202 @@ impl LinearClient {
                 .collect(),
             priority: Some(issue.priority as i64),
             priority_label: Some(issue.priority_label),
+            estimate: issue.estimate,
+            cycle: issue.cycle.map(|cycle| IssueCycle {
+                number: cycle.number as i64,
+                name: cycle.name,
+            }),
+            due_date: issue.due_date,
             created_at: issue.created_at,
             updated_at: issue.updated_at,
             url: issue.url,
+            parent: issue.parent.map(|parent| IssueNode {
+                id: parent.id,
+                identifier: parent.identifier,
+                title: parent.title,
+                state: IssueState {
+                    id: parent.state.id,
+                    name: parent.state.name,
+                    type_: parent.state.type_,
+                },
+                assignee: parent.assignee.map(|a| a.name),
+                children: Vec::new(),
+            }),
+            children: issue
+                .children
+                .nodes
+                .into_iter()
+                .map(|child| IssueNode {
+                    id: child.id,
+                    identifier: child.identifier,
+                    title: child.title,
+                    state: IssueState {
+                        id: child.state.id,
+                        name: child.state.name,
+                        type_: child.state.type_,
+                    },
+                    assignee: child.assignee.map(|a| a.name),
+                    children: Vec::new(),
+                })
+                .collect(),
         })
     }
 
+    async fn clear_issue_due_date(&self, id: &str) -> Result<()> {
+        let variables = clear_issue_due_date::Variables { id: id.to_string() };
+        let data = self
+            .execute_graphql::<ClearIssueDueDate, _>(variables)
+            .await
+            .map_err(|e| issue_not_found(id, e))?;
+
+        mutation_succeeded(data.issue_update.success, "Clearing the due date")
+    }
+
+    async fn clear_issue_milestone(&self, id: &str) -> Result<()> {
+        let variables = clear_issue_milestone::Variables { id: id.to_string() };
+        let data = self
+            .execute_graphql::<ClearIssueMilestone, _>(variables)
+            .await
+            .map_err(|e| issue_not_found(id, e))?;
+
+        mutation_succeeded(data.issue_update.success, "Removing the milestone")
+    }
+
+    async fn clear_issue_parent(&self, id: &str) -> Result<()> {
+        let variables = clear_issue_parent::Variables { id: id.to_string() };
+        let data = self
+            .execute_graphql::<ClearIssueParent, _>(variables)
+            .await
+            .map_err(|e| issue_not_found(id, e))?;
+
+        mutation_succeeded(data.issue_update.success, "Detaching from the parent")
+    }
+
+    /// Archive an issue; archived issues are hidden from lists but can be restored
+    pub async fn archive_issue(&self, id: &str) -> Result<()> {
+        let variables = archive_issue::Variables { id: id.to_string() };
+        let data = self
+            .execute_graphql::<ArchiveIssue, _>(variables)
+            .await
+            .map_err(|e| issue_not_found(id, e))?;
+
+        mutation_succeeded(data.issue_archive.success, "Issue archive")
+    }
+
+    /// Restore an archived or trashed issue
+    pub async fn unarchive_issue(&self, id: &str) -> Result<()> {
+        let variables = unarchive_issue::Variables { id: id.to_string() };
+        let data = self
+            .execute_graphql::<UnarchiveIssue, _>(variables)
+            .await
+            .map_err(|e| issue_not_found(id, e))?;
+
+        mutation_succeeded(data.issue_unarchive.success, "Issue restore")
+    }
+
+    /// Move an issue to the trash, or delete it for good when `permanently` is set
+    pub async fn delete_issue(&self, id: &str, permanently: bool) -> Result<()> {
+        let variables = delete_issue::Variables {
+            id: id.to_string(),
+            permanently_delete: permanently.then_some(true),
+        };
+        let data = self
+            .execute_graphql::<DeleteIssue, _>(variables)
+            .await
+            .map_err(|e| issue_not_found(id, e))?;
+
+        mutation_succeeded(data.issue_delete.success, "Issue deletion")
+    }
+
+    /// Resolve an issue identifier such as "ENG-123" to its ID, passing UUIDs through unchanged
+    pub async fn resolve_issue_id(&self, identifier: &str) -> Result<String> {
+        if LinearClient::looks_like_uuid(identifier) {
+            return Ok(identifier.to_string());
+        }
+        Ok(self.get_issue(identifier.to_string()).await?.id)
+    }
+
+    /// Fetch an issue with its whole sub-issue hierarchy, one request per level of depth
+    pub async fn get_issue_tree(&self, id: String) -> Result<IssueNode> {
+        let issue = self.get_issue(id).await?;
+        let mut root = IssueNode {
+            id: issue.id,
+            identifier: issue.identifier,
+            title: issue.title,
+            state: issue.state,
+            assignee: issue.assignee.map(|a| a.name),
+            children: Vec::new(),
+        };
+
+        let mut children_of: HashMap<String, Vec<IssueNode>> = HashMap::new();
+        let mut parents = vec![root.id.clone()];
+
+        for _ in 0..MAX_SUB_ISSUE_DEPTH {
+            if parents.is_empty() {
+                break;
+            }
+
+            let mut next_parents = Vec::new();
+            for chunk in parents.chunks(MAX_PAGE_SIZE) {
+                let nodes = pagination::collect_pages(PageLimit::All, |first, after| {
+                    self.fetch_sub_issues_page(chunk.to_vec(), first, after)
+                })
+                .await?;
+
+                for (parent_id, node) in nodes {
+                    next_parents.push(node.id.clone());
+                    children_of.entry(parent_id).or_default().push(node);
+                }
+            }
+            parents = next_parents;
+        }
+
+        attach_children(&mut root, &mut children_of);
+        Ok(root)
+    }
+
+    /// One page of issues whose parent is any of `parent_ids`, paired with their parent's ID
+    async fn fetch_sub_issues_page(
+        &self,
+        parent_ids: Vec<String>,
+        first: i64,
+        after: Option<String>,
+    ) -> Result<(Vec<(String, IssueNode)>, PageInfo)> {
+        let variables = list_sub_issues::Variables {
+            parent_ids: Some(parent_ids),
+            first,
+            after,
+        };
+
+        let data = self.execute_graphql::<ListSubIssues, _>(variables).await?;
+        let nodes = data
+            .issues
+            .nodes
+            .into_iter()
+            .filter_map(|issue| {
+                let parent_id = issue.parent?.id;
+                let node = IssueNode {
+                    id: issue.id,
+                    identifier: issue.identifier,
+                    title: issue.title,
+                    state: IssueState {
+                        id: issue.state.id,
+                        name: issue.state.name,
+                        type_: issue.state.type_,
+                    },
+                    assignee: issue.assignee.map(|a| a.name),
+                    children: Vec::new(),
+                };
+                Some((parent_id, node))
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_next_page: data.issues.page_info.has_next_page,
+            end_cursor: data.issues.page_info.end_cursor,
+        };
+        Ok((nodes, page_info))
+    }
+
     pub async fn create_issue(&self, input: CreateIssueInput) -> Result<CreatedIssue> {
         // For now, let's create a simpler implementation that we'll enhance in later phases
         // This builds the actual GraphQL variables properly
@@ -1129,11 +1907,11 @@ impl LinearClient {
                 label_ids: input.label_ids,
 
                 // Initialize other available fields to sensible defaults
-                state_id: None,
+                state_id: input.state_id,
                 project_id: input.project_id,
-                project_milestone_id: None,
-                parent_id: None,
-                due_date: None,
+                project_milestone_id: input.project_milestone_id,
+                parent_id: input.parent_id,
+                due_date: input.due_date.filter(|date| !date.is_empty()),
                 estimate: input.estimate,
                 sort_order: None,
                 create_as_user: None,
@@ -1205,23 +1983,44 @@ impl LinearClient {
                 .collect(),
             priority: Some(issue.priority as i64),
             priority_label: Some(issue.priority_label),
+            due_date: issue.due_date,
             created_at: issue.created_at,
             updated_at: issue.updated_at,
             url: issue.url,
         })
     }
 
-    pub async fn list_users(&self, limit: i32) -> Result<Vec<User>> {
+    pub async fn list_users(&self, limit: impl Into<PageLimit>) -> Result<Vec<User>> {
         self.list_users_filtered(limit, None).await
     }
 
     pub async fn list_users_filtered(
         &self,
-        limit: i32,
+        limit: impl Into<PageLimit>,
         filter: Option<list_users::UserFilter>,
     ) -> Result<Vec<User>> {
+        pagination::collect_pages(limit.into(), |first, after| {
+            self.fetch_users_page(first, after, filter.clone())
+        })
+        .await
+    }
+
+    /// Stream users matching `filter`, fetching further pages only as the stream is polled
+    pub fn users_stream(&self, filter: Option<list_users::UserFilter>) -> PageStream<'_, User> {
+        pagination::stream_pages(move |first, after| {
+            self.fetch_users_page(first, after, filter.clone())
+        })
+    }
+
+    async fn fetch_users_page(
+        &self,
+        first: i64,
+        after: Option<String>,
+        filter: Option<list_users::UserFilter>,
+    ) -> Result<(Vec<User>, PageInfo)> {
         let variables = list_users::Variables {
-            first: limit as i64,
+            first,
+            after,
             filter,
         };
 
@@ -1239,7 +2038,11 @@ impl LinearClient {
                 guest: user.guest,
             })
             .collect();
-        Ok(users)
+        let page_info = PageInfo {
+            has_next_page: data.users.page_info.has_next_page,
+            end_cursor: data.users.page_info.end_cursor,
+        };
+        Ok((users, page_info))
     }
 
     pub async fn list_teams(&self) -> Result<Vec<Team>> {
@@ -1274,9 +2077,38 @@ impl LinearClient {
         Ok(teams)
     }
 
-    pub async fn list_projects(&self, limit: i32) -> Result<Vec<Project>> {
+    pub async fn list_projects(&self, limit: impl Into<PageLimit>) -> Result<Vec<Project>> {
+        self.list_projects_filtered(limit, &ProjectFilters::default())
+            .await
+    }
+
+    pub async fn list_projects_filtered(
+        &self,
+        limit: impl Into<PageLimit>,
+        filters: &ProjectFilters,
+    ) -> Result<Vec<Project>> {
+        let filter = LinearClient::build_project_filter(filters);
+        pagination::collect_pages(limit.into(), |first, after| {
+            self.fetch_projects_page(first, after, filter.clone())
+        })
+        .await
+    }
+
+    /// Stream all projects, fetching further pages only as the stream is polled
+    pub fn projects_stream(&self) -> PageStream<'_, Project> {
+        pagination::stream_pages(move |first, after| self.fetch_projects_page(first, after, None))
+    }
+
+    async fn fetch_projects_page(
+        &self,
+        first: i64,
+        after: Option<String>,
+        filter: Option<list_projects::ProjectFilter>,
+    ) -> Result<(Vec<Project>, PageInfo)> {
         let variables = list_projects::Variables {
-            first: limit as i64,
+            first,
+            after,
+            filter,
         };
 
         let data = self.execute_graphql::<ListProjects, _>(variables).await?;
@@ -1299,23 +2131,557 @@ impl LinearClient {
                     name: lead.name,
                     display_name: lead.display_name,
                 }),
+                target_date: project.target_date,
             })
             .collect();
-        Ok(projects)
+        let page_info = PageInfo {
+            has_next_page: data.projects.page_info.has_next_page,
+            end_cursor: data.projects.page_info.end_cursor,
+        };
+        Ok((projects, page_info))
     }
 
-    pub async fn get_issue_comments(
+    /// Fetch a project by ID or slug, with all of its issues fetched page by page
+    pub async fn get_project(&self, id: &str) -> Result<ProjectDetail> {
+        let variables = get_project::Variables { id: id.to_string() };
+
+        let project = self
+            .execute_graphql::<GetProject, _>(variables)
+            .await?
+            .project;
+        let issues = pagination::collect_pages(PageLimit::All, |first, after| {
+            self.fetch_project_issues_page(id, first, after)
+        })
+        .await?;
+        let mut milestones = project.project_milestones.nodes;
+        milestones.sort_by(|a, b| a.sort_order.total_cmp(&b.sort_order));
+        Ok(ProjectDetail {
+            id: project.id,
+            name: project.name,
+            description: Some(project.description).filter(|d| !d.is_empty()),
+            content: project.content.filter(|c| !c.trim().is_empty()),
+            status: ProjectStatus {
+                id: project.status.id,
+                name: project.status.name,
+                #[allow(deprecated)]
+                type_: project.state,
+            },
+            progress: project.progress,
+            health: project.health.map(enum_name),
+            start_date: project.start_date,
+            target_date: project.target_date,
+            url: project.url,
+            created_at: project.created_at,
+            updated_at: project.updated_at,
+            lead: project.lead.map(|lead| ProjectLead {
+                id: lead.id,
+                name: lead.name,
+                display_name: lead.display_name,
+            }),
+            members: project
+                .members
+                .nodes
+                .into_iter()
+                .map(|member| ProjectLead {
+                    id: member.id,
+                    name: member.name,
+                    display_name: member.display_name,
+                })
+                .collect(),
+            teams: project
+                .teams
+                .nodes
+                .into_iter()
+                .map(|team| ProjectTeam {
+                    id: team.id,
+                    key: team.key,
+                    name: team.name,
+                })
+                .collect(),
+            milestones: milestones
+                .into_iter()
+                .map(|milestone| ProjectMilestone {
+                    id: milestone.id,
+                    name: milestone.name,
+                    description: milestone.description,
+                    target_date: milestone.target_date,
+                    progress: milestone.progress,
+                    status: enum_name(milestone.status),
+                })
+                .collect(),
+            issues,
+        })
+    }
+
+    async fn fetch_project_issues_page(
         &self,
-        issue_id: &str,
-        limit: i32,
-    ) -> Result<IssueWithComments> {
-        let variables = get_issue_comments::Variables {
-            issue_id: issue_id.to_string(),
-            first: limit as i64,
+        id: &str,
+        first: i64,
+        after: Option<String>,
+    ) -> Result<(Vec<IssueNode>, PageInfo)> {
+        let variables = list_project_issues::Variables {
+            id: id.to_string(),
+            first,
+            after,
         };
+        let connection = self
+            .execute_graphql::<ListProjectIssues, _>(variables)
+            .await?
+            .project
+            .issues;
 
-        let data = self
-            .execute_graphql::<GetIssueComments, _>(variables)
+        let issues = connection
+            .nodes
+            .into_iter()
+            .map(|issue| IssueNode {
+                id: issue.id,
+                identifier: issue.identifier,
+                title: issue.title,
+                state: IssueState {
+                    id: issue.state.id,
+                    name: issue.state.name,
+                    type_: issue.state.type_,
+                },
+                assignee: issue.assignee.map(|a| a.name),
+                children: Vec::new(),
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_next_page: connection.page_info.has_next_page,
+            end_cursor: connection.page_info.end_cursor,
+        };
+        Ok((issues, page_info))
+    }
+
+    /// A project's `limit` most recent updates, newest first
+    pub async fn list_project_updates(
+        &self,
+        project_id: &str,
+        limit: i64,
+    ) -> Result<Vec<ProjectUpdate>> {
+        let variables = get_project_updates::Variables {
+            id: project_id.to_string(),
+            first: limit,
+        };
+        let data = self
+            .execute_graphql::<GetProjectUpdates, _>(variables)
+            .await?;
+
+        let mut updates: Vec<ProjectUpdate> = data
+            .project
+            .project_updates
+            .nodes
+            .into_iter()
+            .map(|update| ProjectUpdate {
+                id: update.id,
+                body: update.body,
+                health: enum_name(update.health),
+                url: update.url,
+                created_at: update.created_at,
+                updated_at: update.updated_at,
+                author: update.user.name,
+            })
+            .collect();
+        updates.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(updates)
+    }
+
+    /// Post a status update on a project
+    pub async fn create_project_update(
+        &self,
+        input: CreateProjectUpdateInput,
+    ) -> Result<ProjectUpdate> {
+        use create_project_update::ProjectUpdateHealthType;
+
+        let variables = create_project_update::Variables {
+            input: create_project_update::ProjectUpdateCreateInput {
+                project_id: input.project_id,
+                body: Some(input.body),
+                health: input.health.map(|health| match health {
+                    ProjectHealth::OnTrack => ProjectUpdateHealthType::onTrack,
+                    ProjectHealth::AtRisk => ProjectUpdateHealthType::atRisk,
+                    ProjectHealth::OffTrack => ProjectUpdateHealthType::offTrack,
+                }),
+                id: None,
+                body_data: None,
+                is_diff_hidden: None,
+            },
+        };
+
+        let data = self
+            .execute_graphql::<CreateProjectUpdate, _>(variables)
+            .await?;
+        mutation_succeeded(data.project_update_create.success, "Project update post")?;
+
+        let update = data.project_update_create.project_update;
+        Ok(ProjectUpdate {
+            id: update.id,
+            body: update.body,
+            health: enum_name(update.health),
+            url: update.url,
+            created_at: update.created_at,
+            updated_at: update.updated_at,
+            author: update.user.name,
+        })
+    }
+
+    /// The workspace's project statuses, such as Backlog, Planned, and In Progress
+    pub async fn list_project_statuses(&self) -> Result<Vec<ProjectStatus>> {
+        use list_project_statuses::ProjectStatusType;
+
+        let data = self
+            .execute_graphql::<ListProjectStatuses, _>(list_project_statuses::Variables)
+            .await?;
+        Ok(data
+            .project_statuses
+            .nodes
+            .into_iter()
+            .map(|status| ProjectStatus {
+                id: status.id,
+                name: status.name,
+                type_: match status.type_ {
+                    ProjectStatusType::backlog => "backlog".to_string(),
+                    ProjectStatusType::planned => "planned".to_string(),
+                    ProjectStatusType::started => "started".to_string(),
+                    ProjectStatusType::paused => "paused".to_string(),
+                    ProjectStatusType::completed => "completed".to_string(),
+                    ProjectStatusType::canceled => "canceled".to_string(),
+                    ProjectStatusType::Other(other) => other,
+                },
+            })
+            .collect())
+    }
+
+    /// Resolve a project status name or type (e.g. "In Progress" or "started") to its ID
+    pub async fn resolve_project_status_to_id(&self, status: &str) -> Result<String> {
+        let statuses = self.list_project_statuses().await?;
+
+        statuses
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(status))
+            .or_else(|| {
+                statuses
+                    .iter()
+                    .find(|s| s.type_.eq_ignore_ascii_case(status))
+            })
+            .map(|s| s.id.clone())
+            .ok_or_else(|| {
+                let names: Vec<&str> = statuses.iter().map(|s| s.name.as_str()).collect();
+                LinearError::InvalidInput {
+                    message: format!(
+                        "Project status '{status}' not found. Available statuses: {}",
+                        names.join(", ")
+                    ),
+                }
+            })
+    }
+
+    pub async fn create_project(&self, input: CreateProjectInput) -> Result<SavedProject> {
+        let variables = create_project::Variables {
+            input: create_project::ProjectCreateInput {
+                name: input.name,
+                team_ids: input.team_ids,
+                description: input.description,
+                content: input.content,
+                lead_id: input.lead_id,
+                status_id: input.status_id,
+                target_date: input.target_date,
+                ..Default::default()
+            },
+        };
+
+        let data = self.execute_graphql::<CreateProject, _>(variables).await?;
+        mutation_succeeded(data.project_create.success, "Project creation")?;
+        let project = data
+            .project_create
+            .project
+            .ok_or(LinearError::InvalidResponse)?;
+        Ok(SavedProject {
+            id: project.id,
+            name: project.name,
+            url: project.url,
+        })
+    }
+
+    pub async fn update_project(
+        &self,
+        id: &str,
+        input: UpdateProjectInput,
+    ) -> Result<SavedProject> {
+        // ProjectUpdateInput omits unset fields, so clearing a field needs its own mutation
+        let clear_target_date = input.target_date.as_deref() == Some("");
+        if clear_target_date {
+            let variables = clear_project_target_date::Variables { id: id.to_string() };
+            let data = self
+                .execute_graphql::<ClearProjectTargetDate, _>(variables)
+                .await?;
+            mutation_succeeded(data.project_update.success, "Clearing the target date")?;
+        }
+        let clear_lead = input.lead_id.as_deref() == Some("");
+        if clear_lead {
+            let variables = clear_project_lead::Variables { id: id.to_string() };
+            let data = self
+                .execute_graphql::<ClearProjectLead, _>(variables)
+                .await?;
+            mutation_succeeded(data.project_update.success, "Removing the lead")?;
+        }
+
+        let variables = update_project::Variables {
+            id: id.to_string(),
+            input: update_project::ProjectUpdateInput {
+                name: input.name,
+                description: input.description,
+                content: input.content,
+                lead_id: input.lead_id.filter(|_| !clear_lead),
+                team_ids: input.team_ids,
+                status_id: input.status_id,
+                target_date: input.target_date.filter(|_| !clear_target_date),
+                ..Default::default()
+            },
+        };
+
+        let data = self.execute_graphql::<UpdateProject, _>(variables).await?;
+        mutation_succeeded(data.project_update.success, "Project update")?;
+        let project = data
+            .project_update
+            .project
+            .ok_or(LinearError::InvalidResponse)?;
+        Ok(SavedProject {
+            id: project.id,
+            name: project.name,
+            url: project.url,
+        })
+    }
+
+    /// Archive a project; archived projects are hidden from lists but can be restored
+    pub async fn archive_project(&self, id: &str) -> Result<()> {
+        let variables = archive_project::Variables { id: id.to_string() };
+        let data = self.execute_graphql::<ArchiveProject, _>(variables).await?;
+
+        #[allow(deprecated)]
+        mutation_succeeded(data.project_archive.success, "Project archive")
+    }
+
+    /// A project's milestones in their display order, fetched page by page
+    pub async fn list_project_milestones(&self, project_id: &str) -> Result<Vec<ProjectMilestone>> {
+        let mut milestones = pagination::collect_pages(PageLimit::All, |first, after| {
+            self.fetch_project_milestones_page(project_id, first, after)
+        })
+        .await?;
+        milestones.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        Ok(milestones
+            .into_iter()
+            .map(|(_, milestone)| milestone)
+            .collect())
+    }
+
+    /// One page of milestones, each with its sort order
+    async fn fetch_project_milestones_page(
+        &self,
+        project_id: &str,
+        first: i64,
+        after: Option<String>,
+    ) -> Result<(Vec<(f64, ProjectMilestone)>, PageInfo)> {
+        let variables = list_project_milestones::Variables {
+            id: project_id.to_string(),
+            first,
+            after,
+        };
+        let connection = self
+            .execute_graphql::<ListProjectMilestones, _>(variables)
+            .await?
+            .project
+            .project_milestones;
+
+        let milestones = connection
+            .nodes
+            .into_iter()
+            .map(|milestone| {
+                let sort_order = milestone.sort_order;
+                let milestone = ProjectMilestone {
+                    id: milestone.id,
+                    name: milestone.name,
+                    description: milestone.description,
+                    target_date: milestone.target_date,
+                    progress: milestone.progress,
+                    status: enum_name(milestone.status),
+                };
+                (sort_order, milestone)
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_next_page: connection.page_info.has_next_page,
+            end_cursor: connection.page_info.end_cursor,
+        };
+        Ok((milestones, page_info))
+    }
+
+    /// Resolve a milestone name within a project to its ID; IDs pass through unchanged
+    pub async fn resolve_milestone_to_id(
+        &self,
+        project_id: &str,
+        milestone: &str,
+    ) -> Result<String> {
+        if LinearClient::looks_like_uuid(milestone) {
+            return Ok(milestone.to_string());
+        }
+
+        let milestones = self.list_project_milestones(project_id).await?;
+        milestones
+            .iter()
+            .find(|m| m.name.eq_ignore_ascii_case(milestone))
+            .map(|m| m.id.clone())
+            .ok_or_else(|| {
+                let message = if milestones.is_empty() {
+                    format!("Milestone '{milestone}' not found. The project has no milestones")
+                } else {
+                    let names: Vec<&str> = milestones.iter().map(|m| m.name.as_str()).collect();
+                    format!(
+                        "Milestone '{milestone}' not found. Available milestones: {}",
+                        names.join(", ")
+                    )
+                };
+                LinearError::InvalidInput { message }
+            })
+    }
+
+    /// Add a milestone to a project
+    pub async fn create_project_milestone(
+        &self,
+        input: CreateProjectMilestoneInput,
+    ) -> Result<ProjectMilestone> {
+        let variables = create_project_milestone::Variables {
+            input: create_project_milestone::ProjectMilestoneCreateInput {
+                project_id: input.project_id,
+                name: input.name,
+                description: input.description,
+                target_date: input.target_date,
+                ..Default::default()
+            },
+        };
+
+        let data = self
+            .execute_graphql::<CreateProjectMilestone, _>(variables)
+            .await?;
+        mutation_succeeded(data.project_milestone_create.success, "Milestone creation")?;
+
+        let milestone = data.project_milestone_create.project_milestone;
+        Ok(ProjectMilestone {
+            id: milestone.id,
+            name: milestone.name,
+            description: milestone.description,
+            target_date: milestone.target_date,
+            progress: milestone.progress,
+            status: enum_name(milestone.status),
+        })
+    }
+
+    /// Rename, describe, or reschedule a milestone; an empty target date clears it
+    pub async fn update_project_milestone(
+        &self,
+        id: &str,
+        input: UpdateProjectMilestoneInput,
+    ) -> Result<ProjectMilestone> {
+        // ProjectMilestoneUpdateInput omits unset fields, so clearing the target date needs its own mutation
+        let clear_target_date = input.target_date.as_deref() == Some("");
+        if clear_target_date {
+            let variables = clear_project_milestone_target_date::Variables { id: id.to_string() };
+            let data = self
+                .execute_graphql::<ClearProjectMilestoneTargetDate, _>(variables)
+                .await?;
+            mutation_succeeded(
+                data.project_milestone_update.success,
+                "Clearing the target date",
+            )?;
+        }
+
+        let variables = update_project_milestone::Variables {
+            id: id.to_string(),
+            input: update_project_milestone::ProjectMilestoneUpdateInput {
+                name: input.name,
+                description: input.description,
+                target_date: input.target_date.filter(|_| !clear_target_date),
+                ..Default::default()
+            },
+        };
+
+        let data = self
+            .execute_graphql::<UpdateProjectMilestone, _>(variables)
+            .await?;
+        mutation_succeeded(data.project_milestone_update.success, "Milestone update")?;
+
+        let milestone = data.project_milestone_update.project_milestone;
+        Ok(ProjectMilestone {
+            id: milestone.id,
+            name: milestone.name,
+            description: milestone.description,
+            target_date: milestone.target_date,
+            progress: milestone.progress,
+            status: enum_name(milestone.status),
+        })
+    }
+
+    /// Delete a milestone; its issues stay in the project
+    pub async fn delete_project_milestone(&self, id: &str) -> Result<()> {
+        let variables = delete_project_milestone::Variables { id: id.to_string() };
+        let data = self
+            .execute_graphql::<DeleteProjectMilestone, _>(variables)
+            .await?;
+
+        mutation_succeeded(data.project_milestone_delete.success, "Milestone deletion")
+    }
+
+    pub async fn get_issue_comments(
+        &self,
+        issue_id: &str,
+        limit: impl Into<PageLimit>,
+    ) -> Result<IssueWithComments> {
+        let mut collector = pagination::PageCollector::new(limit.into());
+        let mut header = None;
+
+        while !collector.is_done() {
+            let (page, page_info) = self
+                .fetch_comments_page(issue_id, collector.page_size(), collector.cursor())
+                .await?;
+            collector.push_page(page.comments, page_info);
+            header = Some((page.id, page.identifier, page.title));
+        }
+
+        // A zero limit never issues a request, so fall back to the identifier we were given
+        let (id, identifier, title) =
+            header.unwrap_or_else(|| (issue_id.to_string(), issue_id.to_string(), String::new()));
+
+        Ok(IssueWithComments {
+            id,
+            identifier,
+            title,
+            comments: collector.into_items(),
+        })
+    }
+
+    /// Stream an issue's comments, fetching further pages only as the stream is polled
+    pub fn comments_stream<'a>(&'a self, issue_id: &'a str) -> PageStream<'a, Comment> {
+        pagination::stream_pages(move |first, after| async move {
+            let (page, page_info) = self.fetch_comments_page(issue_id, first, after).await?;
+            Ok((page.comments, page_info))
+        })
+    }
+
+    /// Fetch one page of comments along with the issue they belong to
+    async fn fetch_comments_page(
+        &self,
+        issue_id: &str,
+        first: i64,
+        after: Option<String>,
+    ) -> Result<(IssueWithComments, PageInfo)> {
+        let variables = get_issue_comments::Variables {
+            issue_id: issue_id.to_string(),
+            first,
+            after,
+        };
+
+        let data = self
+            .execute_graphql::<GetIssueComments, _>(variables)
             .await?;
         let issue = data.issue;
 
@@ -1343,61 +2709,125 @@ impl LinearClient {
                 },
             })
             .collect();
+        let page_info = PageInfo {
+            has_next_page: issue.comments.page_info.has_next_page,
+            end_cursor: issue.comments.page_info.end_cursor,
+        };
 
-        Ok(IssueWithComments {
-            id: issue.id,
-            identifier: issue.identifier,
-            title: issue.title,
-            comments,
-        })
+        Ok((
+            IssueWithComments {
+                id: issue.id,
+                identifier: issue.identifier,
+                title: issue.title,
+                comments,
+            },
+            page_info,
+        ))
     }
 
-    pub async fn get_my_work(&self, limit: i32) -> Result<MyWork> {
-        let variables = get_my_work::Variables {
-            first: limit as i64,
+    pub async fn get_my_work(&self, limit: impl Into<PageLimit>) -> Result<MyWork> {
+        self.get_my_work_filtered(limit, &MyWorkFilters::default())
+            .await
+    }
+
+    /// Open issues assigned to or created by the viewer, narrowed by due date
+    pub async fn get_my_work_filtered(
+        &self,
+        limit: impl Into<PageLimit>,
+        filters: &MyWorkFilters,
+    ) -> Result<MyWork> {
+        // Completed work is always hidden; overdue work also leaves out canceled issues
+        let (due_before, excluded_state_types): (_, &[&str]) = if filters.overdue {
+            (Some(filter::TODAY.to_string()), filter::CLOSED_STATE_TYPES)
+        } else {
+            (filters.due_before.clone(), &["completed"])
         };
+        let excluded_state_types: Vec<String> =
+            excluded_state_types.iter().map(|t| t.to_string()).collect();
+
+        let limit = limit.into();
+        let mut assigned = pagination::PageCollector::new(limit);
+        let mut created = pagination::PageCollector::new(limit);
+
+        // Both connections share one query, so keep paging until each has finished on its own
+        while !assigned.is_done() || !created.is_done() {
+            let first = match (assigned.is_done(), created.is_done()) {
+                (false, false) => assigned.page_size().max(created.page_size()),
+                (false, true) => assigned.page_size(),
+                _ => created.page_size(),
+            };
+            let variables = get_my_work::Variables {
+                first,
+                assigned_after: assigned.cursor(),
+                created_after: created.cursor(),
+                due_before: due_before.clone(),
+                excluded_state_types: Some(excluded_state_types.clone()),
+            };
 
-        let data = self.execute_graphql::<GetMyWork, _>(variables).await?;
+            let data = self.execute_graphql::<GetMyWork, _>(variables).await?;
 
-        let assigned_issues = data
-            .viewer
-            .assigned_issues
-            .nodes
-            .into_iter()
-            .map(|issue| Issue {
-                id: issue.id,
-                identifier: issue.identifier,
-                title: issue.title,
-                status: issue.state.name,
-                state_id: issue.state.id,
-                assignee: None, // Self-assigned, not needed
-                assignee_id: None,
-                team: Some(issue.team.key),
-                team_id: issue.team.id,
-            })
-            .collect();
+            let assigned_page = data.viewer.assigned_issues;
+            let assigned_issues = assigned_page
+                .nodes
+                .into_iter()
+                .map(|issue| Issue {
+                    id: issue.id,
+                    identifier: issue.identifier,
+                    title: issue.title,
+                    status: issue.state.name,
+                    state_id: issue.state.id,
+                    assignee: None, // Self-assigned, not needed
+                    assignee_id: None,
+                    team: Some(issue.team.key),
+                    team_id: issue.team.id,
+                    priority: Some(issue.priority as i64),
+                    due_date: issue.due_date,
+                    created_at: Some(issue.created_at),
+                    updated_at: Some(issue.updated_at),
+                    ..Default::default()
+                })
+                .collect();
+            assigned.push_page(
+                assigned_issues,
+                PageInfo {
+                    has_next_page: assigned_page.page_info.has_next_page,
+                    end_cursor: assigned_page.page_info.end_cursor,
+                },
+            );
 
-        let created_issues = data
-            .viewer
-            .created_issues
-            .nodes
-            .into_iter()
-            .map(|issue| Issue {
-                id: issue.id,
-                identifier: issue.identifier,
-                title: issue.title,
-                status: issue.state.name,
-                state_id: issue.state.id,
-                assignee: issue.assignee.as_ref().map(|a| a.display_name.clone()),
-                assignee_id: issue.assignee.map(|a| a.id),
-                team: Some(issue.team.key),
-                team_id: issue.team.id,
-            })
-            .collect();
+            let created_page = data.viewer.created_issues;
+            let created_issues = created_page
+                .nodes
+                .into_iter()
+                .map(|issue| Issue {
+                    id: issue.id,
+                    identifier: issue.identifier,
+                    title: issue.title,
+                    status: issue.state.name,
+                    state_id: issue.state.id,
+                    assignee: issue.assignee.as_ref().map(|a| a.display_name.clone()),
+                    assignee_id: issue.assignee.map(|a| a.id),
+                    team: Some(issue.team.key),
+                    team_id: issue.team.id,
+                    priority: Some(issue.priority as i64),
+                    due_date: issue.due_date,
+                    created_at: Some(issue.created_at),
+                    updated_at: Some(issue.updated_at),
+                    ..Default::default()
+                })
+                .collect();
+            created.push_page(
+                created_issues,
+                PageInfo {
+                    has_next_page: created_page.page_info.has_next_page,
+                    end_cursor: created_page.page_info.end_cursor,
+                },
+            );
+        }
 
         Ok(MyWork {
-            assigned_issues,
-            created_issues,
+            assigned_issues: assigned.into_items(),
+            created_issues: created.into_items(),
         })
     }
 
@@ -1429,6 +2859,9 @@ impl LinearClient {
                 assignee_id: issue.assignee.map(|a| a.id),
                 team: Some(issue.team.name),
                 team_id: issue.team.id,
+                created_at: Some(issue.created_at),
+                updated_at: Some(issue.updated_at),
+                ..Default::default()
             })
             .collect();
 
@@ -1594,6 +3027,20 @@ impl LinearClient {
     }
 
     pub async fn update_issue(&self, id: String, input: UpdateIssueInput) -> Result<UpdatedIssue> {
+        // IssueUpdateInput omits unset fields, so clearing a field needs its own mutation
+        let clear_due_date = input.due_date.as_deref() == Some("");
+        if clear_due_date {
+            self.clear_issue_due_date(&id).await?;
+        }
+        let clear_milestone = input.project_milestone_id.as_deref() == Some("");
+        if clear_milestone {
+            self.clear_issue_milestone(&id).await?;
+        }
+        let clear_parent = input.parent_id.as_deref() == Some("");
+        if clear_parent {
+            self.clear_issue_parent(&id).await?;
+        }
+
         let variables = update_issue::Variables {
             id: id.clone(),
             input: update_issue::IssueUpdateInput {
@@ -1607,9 +3054,9 @@ impl LinearClient {
                 // Initialize other available fields to sensible defaults
                 team_id: None,
                 project_id: input.project_id,
-                project_milestone_id: None,
-                parent_id: None,
-                due_date: None,
+                project_milestone_id: input.project_milestone_id.filter(|_| !clear_milestone),
+                parent_id: input.parent_id.filter(|_| !clear_parent),
+                due_date: input.due_date.filter(|_| !clear_due_date),
                 estimate: input.estimate,
                 sort_order: None,
                 added_label_ids: None,
@@ -1632,17 +3079,10 @@ impl LinearClient {
 
         let data = match self.execute_graphql::<UpdateIssue, _>(variables).await {
             Ok(data) => data,
-            Err(LinearError::GraphQL { message, .. }) => {
-                // Check if this is a "not found" error
-                if message.contains("not found") || message.contains("not exist") {
-                    return Err(LinearError::IssueNotFound {
-                        identifier: id,
-                        suggestion: None,
-                    });
-                }
-                return Err(LinearError::GraphQL {
-                    message,
-                    errors: vec![],
+            Err(LinearError::NotFound { .. }) => {
+                return Err(LinearError::IssueNotFound {
+                    identifier: id,
+                    suggestion: None,
                 });
             }
             Err(e) => return Err(e),
@@ -1693,6 +3133,7 @@ impl LinearClient {
                 .collect(),
             priority: Some(issue.priority as i64),
             priority_label: Some(issue.priority_label),
+            due_date: issue.due_date,
             created_at: issue.created_at,
             updated_at: issue.updated_at,
             url: issue.url,
@@ -1865,7 +3306,7 @@ impl LinearClient {
 
         let team = data.team;
 
-        // Handle special "current"/"active" keyword
+        // Handle the "current"/"active", "next", and "previous" keywords
         let input_lower = cycle_input.to_lowercase();
         if input_lower == "current" || input_lower == "active" {
             return match team.active_cycle {
@@ -1875,6 +3316,22 @@ impl LinearClient {
                 }),
             };
         }
+        if input_lower == "next" {
+            return match team.next_cycle.nodes.into_iter().next() {
+                Some(cycle) => Ok(cycle.id),
+                None => Err(LinearError::InvalidInput {
+                    message: "No upcoming cycle found for this team".to_string(),
+                }),
+            };
+        }
+        if input_lower == "previous" {
+            return match team.previous_cycle.nodes.into_iter().next() {
+                Some(cycle) => Ok(cycle.id),
+                None => Err(LinearError::InvalidInput {
+                    message: "No previous cycle found for this team".to_string(),
+                }),
+            };
+        }
 
         // Check if input looks like a UUID
         if cycle_input
@@ -1925,48 +3382,215 @@ impl LinearClient {
 
         Err(LinearError::InvalidInput {
             message: format!(
-                "Cycle '{}' not found. Use 'current' for active cycle, a cycle number, or a cycle name. Available cycles: {}",
+                "Cycle '{}' not found. Use 'current', 'next', 'previous', a cycle number, or a cycle name. Available cycles: {}",
                 cycle_input,
                 format_available_cycles()
             ),
         })
     }
 
-    pub async fn create_attachment(
+    /// All of a team's cycles, newest first, fetched page by page
+    pub async fn list_cycles(&self, team_id: &str) -> Result<Vec<Cycle>> {
+        let mut cycles = pagination::collect_pages(PageLimit::All, |first, after| {
+            self.fetch_cycles_page(team_id, first, after)
+        })
+        .await?;
+        cycles.sort_by(|a, b| b.number.cmp(&a.number));
+        Ok(cycles)
+    }
+
+    async fn fetch_cycles_page(
         &self,
-        input: CreateAttachmentInput,
-    ) -> Result<CreatedAttachment> {
-        let variables = create_attachment::Variables {
-            input: create_attachment::AttachmentCreateInput {
-                issue_id: input.issue_id,
-                url: input.url.clone(),
-                title: input.title.unwrap_or(input.url),
-                icon_url: None,
-                metadata: None,
-                subtitle: None,
-                id: None,
-                create_as_user: None,
-                comment_body: None,
-                comment_body_data: None,
-                group_by_source: None,
-            },
+        team_id: &str,
+        first: i64,
+        after: Option<String>,
+    ) -> Result<(Vec<Cycle>, PageInfo)> {
+        let variables = list_cycles::Variables {
+            team_id: team_id.to_string(),
+            first,
+            after,
         };
 
-        let data = self
-            .execute_graphql::<CreateAttachment, _>(variables)
-            .await?;
-
-        if !data.attachment_create.success {
-            return Err(LinearError::GraphQL {
-                message: "Attachment creation failed".to_string(),
-                errors: vec![],
-            });
-        }
-
-        let attachment = data.attachment_create.attachment;
+        let team = self.execute_graphql::<ListCycles, _>(variables).await?.team;
+        let active_id = team.active_cycle.map(|cycle| cycle.id);
 
-        Ok(CreatedAttachment {
-            id: attachment.id,
+        let cycles = team
+            .cycles
+            .nodes
+            .into_iter()
+            .map(|cycle| Cycle {
+                is_active: active_id.as_deref() == Some(cycle.id.as_str()),
+                id: cycle.id,
+                number: cycle.number as i64,
+                name: cycle.name,
+                starts_at: cycle.starts_at,
+                ends_at: cycle.ends_at,
+                completed_at: cycle.completed_at,
+                progress: cycle.progress,
+                scope: latest(&cycle.scope_history),
+                started_scope: latest(&cycle.in_progress_scope_history),
+                completed_scope: latest(&cycle.completed_scope_history),
+                issue_count: latest(&cycle.issue_count_history) as i64,
+                completed_issue_count: latest(&cycle.completed_issue_count_history) as i64,
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_next_page: team.cycles.page_info.has_next_page,
+            end_cursor: team.cycles.page_info.end_cursor,
+        };
+        Ok((cycles, page_info))
+    }
+
+    /// A cycle and all of its issues, fetched page by page
+    pub async fn get_cycle(&self, id: &str) -> Result<CycleDetail> {
+        let variables = get_cycle::Variables { id: id.to_string() };
+
+        let cycle = self.execute_graphql::<GetCycle, _>(variables).await?.cycle;
+        let issues = pagination::collect_pages(PageLimit::All, |first, after| {
+            self.fetch_cycle_issues_page(id, first, after)
+        })
+        .await?;
+        let active_id = cycle.team.active_cycle.map(|active| active.id);
+
+        Ok(CycleDetail {
+            cycle: Cycle {
+                is_active: active_id.as_deref() == Some(cycle.id.as_str()),
+                id: cycle.id,
+                number: cycle.number as i64,
+                name: cycle.name,
+                starts_at: cycle.starts_at,
+                ends_at: cycle.ends_at,
+                completed_at: cycle.completed_at,
+                progress: cycle.progress,
+                scope: latest(&cycle.scope_history),
+                started_scope: latest(&cycle.in_progress_scope_history),
+                completed_scope: latest(&cycle.completed_scope_history),
+                issue_count: latest(&cycle.issue_count_history) as i64,
+                completed_issue_count: latest(&cycle.completed_issue_count_history) as i64,
+            },
+            team: IssueTeam {
+                id: cycle.team.id,
+                key: cycle.team.key,
+                name: cycle.team.name,
+            },
+            issues,
+        })
+    }
+
+    async fn fetch_cycle_issues_page(
+        &self,
+        id: &str,
+        first: i64,
+        after: Option<String>,
+    ) -> Result<(Vec<CycleIssue>, PageInfo)> {
+        let variables = list_cycle_issues::Variables {
+            id: id.to_string(),
+            first,
+            after,
+        };
+        let connection = self
+            .execute_graphql::<ListCycleIssues, _>(variables)
+            .await?
+            .cycle
+            .issues;
+
+        let issues = connection
+            .nodes
+            .into_iter()
+            .map(|issue| CycleIssue {
+                id: issue.id,
+                identifier: issue.identifier,
+                title: issue.title,
+                estimate: issue.estimate,
+                state: IssueState {
+                    id: issue.state.id,
+                    name: issue.state.name,
+                    type_: issue.state.type_,
+                },
+                assignee: issue.assignee.map(|a| a.name),
+            })
+            .collect();
+        let page_info = PageInfo {
+            has_next_page: connection.page_info.has_next_page,
+            end_cursor: connection.page_info.end_cursor,
+        };
+        Ok((issues, page_info))
+    }
+
+    /// A cycle's scope and completion history, for burndown charts
+    pub async fn get_cycle_history(&self, id: &str) -> Result<CycleHistory> {
+        let variables = get_cycle_history::Variables { id: id.to_string() };
+
+        let cycle = self
+            .execute_graphql::<GetCycleHistory, _>(variables)
+            .await?
+            .cycle;
+        let active_id = cycle.team.active_cycle.map(|active| active.id);
+
+        Ok(CycleHistory {
+            cycle: Cycle {
+                is_active: active_id.as_deref() == Some(cycle.id.as_str()),
+                id: cycle.id,
+                number: cycle.number as i64,
+                name: cycle.name,
+                starts_at: cycle.starts_at,
+                ends_at: cycle.ends_at,
+                completed_at: cycle.completed_at,
+                progress: cycle.progress,
+                scope: latest(&cycle.scope_history),
+                started_scope: latest(&cycle.in_progress_scope_history),
+                completed_scope: latest(&cycle.completed_scope_history),
+                issue_count: latest(&cycle.issue_count_history) as i64,
+                completed_issue_count: latest(&cycle.completed_issue_count_history) as i64,
+            },
+            team: IssueTeam {
+                id: cycle.team.id,
+                key: cycle.team.key,
+                name: cycle.team.name,
+            },
+            scope_history: cycle.scope_history,
+            in_progress_scope_history: cycle.in_progress_scope_history,
+            completed_scope_history: cycle.completed_scope_history,
+            issue_count_history: cycle.issue_count_history,
+            completed_issue_count_history: cycle.completed_issue_count_history,
+        })
+    }
+
+    pub async fn create_attachment(
+        &self,
+        input: CreateAttachmentInput,
+    ) -> Result<CreatedAttachment> {
+        let variables = create_attachment::Variables {
+            input: create_attachment::AttachmentCreateInput {
+                issue_id: input.issue_id,
+                url: input.url.clone(),
+                title: input.title.unwrap_or(input.url),
+                icon_url: None,
+                metadata: None,
+                subtitle: None,
+                id: None,
+                create_as_user: None,
+                comment_body: None,
+                comment_body_data: None,
+                group_by_source: None,
+            },
+        };
+
+        let data = self
+            .execute_graphql::<CreateAttachment, _>(variables)
+            .await?;
+
+        if !data.attachment_create.success {
+            return Err(LinearError::GraphQL {
+                message: "Attachment creation failed".to_string(),
+                errors: vec![],
+            });
+        }
+
+        let attachment = data.attachment_create.attachment;
+
+        Ok(CreatedAttachment {
+            id: attachment.id,
             url: attachment.url,
             title: attachment.title,
             created_at: attachment.created_at,
@@ -2221,13 +3845,27 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_network_timeout() {
+    async fn test_get_issue_entity_not_found() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .with_status(408)
-            .with_body("Request Timeout")
-            .expect(3)  // 3 total attempts
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "errors": [{
+                        "message": "Entity not found: Issue",
+                        "path": ["issue"],
+                        "extensions": {
+                            "type": "invalid input",
+                            "code": "INPUT_ERROR",
+                            "userPresentableMessage": "Could not find referenced Issue."
+                        }
+                    }],
+                    "data": null
+                })
+                .to_string(),
+            )
             .create();
 
         let client = LinearClient::builder()
@@ -2237,46 +3875,67 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.execute_viewer_query().await;
+        let result = client.get_issue("ENG-9999".to_string()).await;
 
         mock.assert();
-        assert!(result.is_err());
+        match result {
+            Err(LinearError::IssueNotFound { identifier, .. }) => {
+                assert_eq!(identifier, "ENG-9999")
+            }
+            other => panic!("Expected IssueNotFound, got {other:?}"),
+        }
     }
 
     #[tokio::test]
-    #[cfg(feature = "integration-tests")]
-    async fn test_real_api() {
-        let api_key = std::env::var("LINEAR_API_KEY")
-            .expect("LINEAR_API_KEY must be set for integration tests");
+    async fn test_http_400_graphql_errors_are_classified() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(400)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "errors": [{
+                        "message": "Argument Validation Error",
+                        "extensions": {
+                            "code": "INPUT_ERROR",
+                            "userPresentableMessage": "priority must not be greater than 4"
+                        }
+                    }]
+                })
+                .to_string(),
+            )
+            .create();
 
         let client = LinearClient::builder()
-            .auth_token(SecretString::new(api_key.into()))
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
             .build()
             .unwrap();
         let result = client.execute_viewer_query().await;
 
-        assert!(result.is_ok(), "Query should succeed with valid API key");
-        let data = result.unwrap();
-        assert!(!data.viewer.id.is_empty(), "Viewer should have an ID");
-    }
-
-    #[test]
-    fn test_list_issues_query_builds() {
-        let _query = ListIssues::build_query(list_issues::Variables {
-            first: 20,
-            filter: None,
-        });
+        mock.assert();
+        let error = result.unwrap_err();
+        assert!(matches!(error, LinearError::InvalidInput { .. }));
+        assert_eq!(
+            error.to_string(),
+            "Invalid input: priority must not be greater than 4"
+        );
     }
 
     #[tokio::test]
-    async fn test_list_issues_success() {
+    async fn test_rate_limit_headers_are_exposed() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_issues_response().to_string())
+            .with_header("X-RateLimit-Requests-Limit", "1500")
+            .with_header("X-RateLimit-Requests-Remaining", "1234")
+            .with_header("X-Complexity", "12")
+            .with_body(mock_viewer_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -2286,44 +3945,33 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.list_issues(20).await;
-
-        mock.assert();
-        assert!(result.is_ok());
-        let issues = result.unwrap();
-        assert_eq!(issues.len(), 3);
-
-        assert_eq!(issues[0].identifier, "TEST-1");
-        assert_eq!(issues[0].title, "Test Issue 1");
-        assert_eq!(issues[0].status, "Todo");
-        assert_eq!(issues[0].assignee, Some("Alice".to_string()));
-        assert_eq!(issues[0].assignee_id, Some("user-1".to_string()));
-        assert_eq!(issues[0].team, Some("ENG".to_string()));
+        assert!(client.rate_limit().is_none());
 
-        assert_eq!(issues[1].identifier, "TEST-2");
-        assert_eq!(issues[1].title, "Test Issue 2");
-        assert_eq!(issues[1].status, "In Progress");
-        assert_eq!(issues[1].assignee, Some("Bob".to_string()));
-        assert_eq!(issues[1].assignee_id, Some("user-2".to_string()));
-        assert_eq!(issues[1].team, Some("DESIGN".to_string()));
+        client.execute_viewer_query().await.unwrap();
 
-        assert_eq!(issues[2].identifier, "TEST-3");
-        assert_eq!(issues[2].title, "Test Issue 3");
-        assert_eq!(issues[2].status, "Done");
-        assert_eq!(issues[2].assignee, None);
-        assert_eq!(issues[2].assignee_id, None);
-        assert_eq!(issues[2].team, Some("QA".to_string()));
+        mock.assert();
+        let budget = client.rate_limit().unwrap();
+        assert_eq!(budget.requests_limit, Some(1500));
+        assert_eq!(budget.requests_remaining, Some(1234));
+        assert_eq!(budget.complexity, Some(12));
+        assert_eq!(
+            client.usage(),
+            RequestUsage {
+                requests: 1,
+                complexity: 12
+            }
+        );
     }
 
     #[tokio::test]
-    async fn test_list_issues_empty() {
+    async fn test_rate_limited_request_waits_for_retry_after() {
         let mut server = mock_linear_server().await;
-        let mock = server
+        let limited = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
-            .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_empty_issues_response().to_string())
+            .with_status(429)
+            .with_header("Retry-After", "1")
+            .with_header("X-RateLimit-Requests-Remaining", "0")
+            .expect(1)
             .create();
 
         let client = LinearClient::builder()
@@ -2333,23 +3981,33 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.list_issues(20).await;
+        let start = std::time::Instant::now();
+        let request = client.execute_viewer_query();
+        let recover = async {
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            limited.remove();
+            server
+                .mock("POST", "/graphql")
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(mock_viewer_response().to_string())
+                .create()
+        };
+        let (result, ok) = tokio::join!(request, recover);
 
-        mock.assert();
         assert!(result.is_ok());
-        let issues = result.unwrap();
-        assert_eq!(issues.len(), 0);
+        ok.assert();
+        assert!(start.elapsed() >= std::time::Duration::from_secs(1));
     }
 
     #[tokio::test]
-    async fn test_list_issues_error() {
+    async fn test_network_timeout() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
-            .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_graphql_error_response().to_string())
+            .with_status(408)
+            .with_body("Request Timeout")
+            .expect(3)  // 3 total attempts
             .create();
 
         let client = LinearClient::builder()
@@ -2359,42 +4017,81 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.list_issues(20).await;
+        let result = client.execute_viewer_query().await;
 
         mock.assert();
         assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(error.to_string().contains("GraphQL error"));
     }
 
     #[tokio::test]
     #[cfg(feature = "integration-tests")]
-    async fn test_list_issues_real_api() {
+    async fn test_real_api() {
         let api_key = std::env::var("LINEAR_API_KEY")
             .expect("LINEAR_API_KEY must be set for integration tests");
 
         let client = LinearClient::builder()
             .auth_token(SecretString::new(api_key.into()))
             .build()
-            .expect("Failed to create client");
-        let result = client.list_issues(5).await;
+            .unwrap();
+        let result = client.execute_viewer_query().await;
 
         assert!(result.is_ok(), "Query should succeed with valid API key");
-        let issues = result.unwrap();
-        assert!(issues.len() <= 5, "Should return at most 5 issues");
+        let data = result.unwrap();
+        assert!(!data.viewer.id.is_empty(), "Viewer should have an ID");
     }
 
     #[tokio::test]
-    async fn test_build_issue_filter_assignee_me() {
+    async fn test_list_issues_follows_page_cursors() {
         let mut server = mock_linear_server().await;
+        let issue = |n: u32| {
+            serde_json::json!({
+                "id": format!("issue-{n}"),
+                "identifier": format!("TEST-{n}"),
+                "title": format!("Test Issue {n}"),
+                "state": { "id": "state-1", "name": "Todo" },
+                "assignee": null,
+                "team": { "id": "team-1", "key": "ENG" },
+                "priority": 0,
+                "priorityLabel": "No priority",
+                "createdAt": "2026-01-01T10:00:00.000Z",
+                "updatedAt": "2026-01-01T10:00:00.000Z",
+                "labels": { "nodes": [] }
+            })
+        };
 
-        // Mock the viewer query
-        let viewer_mock = server
+        let first_page = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": { "first": 3 } }),
+            ))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_viewer_response().to_string())
+            .with_body(
+                serde_json::json!({
+                    "data": { "issues": {
+                        "nodes": [issue(1), issue(2)],
+                        "pageInfo": { "hasNextPage": true, "endCursor": "cursor-2" }
+                    }}
+                })
+                .to_string(),
+            )
+            .create();
+        let second_page = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": { "first": 1, "after": "cursor-2" } }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": { "issues": {
+                        "nodes": [issue(3)],
+                        "pageInfo": { "hasNextPage": true, "endCursor": "cursor-3" }
+                    }}
+                })
+                .to_string(),
+            )
             .create();
 
         let client = LinearClient::builder()
@@ -2404,85 +4101,107 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
+        let issues = client.list_issues(3).await.unwrap();
 
-        let filters = IssueFilters {
-            assignee: Some("me".to_string()),
-            status: None,
-            team: None,
-        };
-
-        let result = client.build_issue_filter(&filters).await;
-        viewer_mock.assert();
-
-        assert!(result.is_ok());
-        let filter = result.unwrap();
-        assert!(filter.is_some());
-
-        let filter = filter.unwrap();
-        let assignee_filter = (*filter.assignee).as_ref().unwrap();
-        assert!(assignee_filter.id.is_some());
-        assert_eq!(
-            assignee_filter.id.as_ref().unwrap().eq,
-            Some("test-user-id".to_string())
-        );
+        first_page.assert();
+        second_page.assert();
+        let identifiers: Vec<_> = issues.iter().map(|i| i.identifier.as_str()).collect();
+        assert_eq!(identifiers, vec!["TEST-1", "TEST-2", "TEST-3"]);
     }
 
     #[tokio::test]
-    async fn test_build_issue_filter_assignee_unassigned() {
+    async fn test_execute_raw_returns_data() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "query": "query($id: String!) { issue(id: $id) { title } }",
+                "variables": { "id": "ENG-1" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data": {"issue": {"title": "Raw"}}}"#)
+            .create();
+
         let client = LinearClient::builder()
             .auth_token(SecretString::new(
                 "test_api_key".to_string().into_boxed_str(),
             ))
+            .base_url(Some(server.url()))
             .build()
             .unwrap();
+        let query = QueryBuilder::new("query($id: String!) { issue(id: $id) { title } }")
+            .variable("id", "ENG-1");
+        let data = client.execute_raw(&query).await.unwrap();
 
-        let filters = IssueFilters {
-            assignee: Some("unassigned".to_string()),
-            status: None,
-            team: None,
-        };
-
-        let result = client.build_issue_filter(&filters).await;
-        assert!(result.is_ok());
-        let filter = result.unwrap();
-        assert!(filter.is_some());
-
-        let filter = filter.unwrap();
-        let assignee_filter = (*filter.assignee).as_ref().unwrap();
-        assert_eq!(assignee_filter.null, Some(true));
+        mock.assert();
+        assert_eq!(data, serde_json::json!({ "issue": { "title": "Raw" } }));
     }
 
     #[tokio::test]
-    async fn test_build_issue_filter_status() {
+    async fn test_execute_raw_paginated_merges_pages() {
+        let mut server = mock_linear_server().await;
+        let page = |ids: &[&str], has_next_page: bool, cursor: &str| {
+            let nodes: Vec<_> = ids
+                .iter()
+                .map(|id| serde_json::json!({ "id": id }))
+                .collect();
+            serde_json::json!({
+                "data": { "issues": {
+                    "nodes": nodes,
+                    "pageInfo": { "hasNextPage": has_next_page, "endCursor": cursor }
+                }}
+            })
+            .to_string()
+        };
+        let query_text = "query($after: String) { issues(after: $after) { nodes { id } \
+                          pageInfo { hasNextPage endCursor } } }";
+
+        let first_page = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": {} }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(page(&["a", "b"], true, "cursor-b"))
+            .create();
+        let second_page = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": { "after": "cursor-b" } }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(page(&["c"], false, "cursor-c"))
+            .create();
+
         let client = LinearClient::builder()
             .auth_token(SecretString::new(
                 "test_api_key".to_string().into_boxed_str(),
             ))
+            .base_url(Some(server.url()))
             .build()
             .unwrap();
+        let data = client
+            .execute_raw_paginated(QueryBuilder::new(query_text))
+            .await
+            .unwrap();
 
-        let filters = IssueFilters {
-            assignee: None,
-            status: Some("in progress".to_string()),
-            team: None,
-        };
-
-        let result = client.build_issue_filter(&filters).await;
-        assert!(result.is_ok());
-        let filter = result.unwrap();
-        assert!(filter.is_some());
-
-        let filter = filter.unwrap();
-        let state_filter = (*filter.state).as_ref().unwrap();
-        assert!(state_filter.name.is_some());
-        assert_eq!(
-            state_filter.name.as_ref().unwrap().eq,
-            Some("In Progress".to_string())
-        );
+        first_page.assert();
+        second_page.assert();
+        let ids: Vec<_> = data["issues"]["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|node| node["id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert_eq!(data["issues"]["pageInfo"]["hasNextPage"], false);
     }
 
     #[tokio::test]
-    async fn test_build_issue_filter_team() {
+    async fn test_execute_raw_paginated_requires_after_variable() {
         let client = LinearClient::builder()
             .auth_token(SecretString::new(
                 "test_api_key".to_string().into_boxed_str(),
@@ -2490,121 +4209,161 @@ mod tests {
             .build()
             .unwrap();
 
-        let filters = IssueFilters {
-            assignee: None,
-            status: None,
-            team: Some("ENG".to_string()),
-        };
-
-        let result = client.build_issue_filter(&filters).await;
-        assert!(result.is_ok());
-        let filter = result.unwrap();
-        assert!(filter.is_some());
+        let result = client
+            .execute_raw_paginated(QueryBuilder::new("{ issues { nodes { id } } }"))
+            .await;
 
-        let filter = filter.unwrap();
-        let team_filter = (*filter.team).as_ref().unwrap();
-        assert!(team_filter.key.is_some());
-        assert_eq!(
-            team_filter.key.as_ref().unwrap().eq,
-            Some("ENG".to_string())
-        );
+        assert!(matches!(result, Err(LinearError::InvalidInput { .. })));
     }
 
-    #[tokio::test]
-    async fn test_build_issue_filter_combined() {
-        let mut server = mock_linear_server().await;
-
-        // Mock the viewer query
-        let viewer_mock = server
-            .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
-            .with_status(200)
-            .with_header("content-type", "application/json")
-            .with_body(mock_viewer_response().to_string())
-            .create();
-
-        let client = LinearClient::builder()
-            .auth_token(SecretString::new(
-                "test_api_key".to_string().into_boxed_str(),
-            ))
-            .base_url(Some(server.url()))
-            .build()
-            .unwrap();
+    /// Executor that answers every query with the same `data` and records what was asked
+    struct CannedExecutor {
+        data: serde_json::Value,
+        operations: std::sync::Mutex<Vec<String>>,
+    }
 
-        let filters = IssueFilters {
-            assignee: Some("me".to_string()),
-            status: Some("todo".to_string()),
-            team: Some("DESIGN".to_string()),
-        };
+    #[async_trait::async_trait]
+    impl GraphQLExecutor for CannedExecutor {
+        async fn execute<Q, V>(&self, variables: V) -> Result<Q::ResponseData>
+        where
+            Q: GraphQLQuery + Send + Sync,
+            Q::ResponseData: Debug + serde::de::DeserializeOwned + Send,
+            Q::Variables: Debug + Send + Sync + Clone,
+            V: Into<Q::Variables> + Send + Debug,
+        {
+            let body = Q::build_query(variables.into());
+            self.operations
+                .lock()
+                .unwrap()
+                .push(body.operation_name.to_string());
+            Ok(serde_json::from_value(self.data.clone())?)
+        }
 
-        let result = client.build_issue_filter(&filters).await;
-        viewer_mock.assert();
+        async fn execute_raw(&self, query: &QueryBuilder) -> Result<serde_json::Value> {
+            self.operations
+                .lock()
+                .unwrap()
+                .push(query.query().to_string());
+            Ok(self.data.clone())
+        }
+    }
 
-        assert!(result.is_ok());
-        let filter = result.unwrap();
-        assert!(filter.is_some());
+    #[tokio::test]
+    async fn test_client_with_custom_executor() {
+        let client = LinearClient::with_executor(CannedExecutor {
+            data: serde_json::json!({
+                "teams": { "nodes": [{
+                    "id": "team-1",
+                    "key": "ENG",
+                    "name": "Engineering",
+                    "description": null,
+                    "members": { "nodes": [] }
+                }]}
+            }),
+            operations: std::sync::Mutex::new(Vec::new()),
+        });
 
-        let filter = filter.unwrap();
+        let teams = client.list_teams().await.unwrap();
+        let raw = client
+            .execute_raw(&QueryBuilder::new("{ teams { nodes { key } } }"))
+            .await
+            .unwrap();
 
-        // Check assignee filter
-        let assignee_filter = (*filter.assignee).as_ref().unwrap();
+        assert_eq!(teams.len(), 1);
+        assert_eq!(teams[0].key, "ENG");
+        assert_eq!(raw["teams"]["nodes"][0]["key"], "ENG");
         assert_eq!(
-            assignee_filter.id.as_ref().unwrap().eq,
-            Some("test-user-id".to_string())
+            *client.executor().operations.lock().unwrap(),
+            vec!["ListTeams", "{ teams { nodes { key } } }"]
         );
+    }
 
-        // Check status filter
-        let state_filter = (*filter.state).as_ref().unwrap();
-        assert_eq!(
-            state_filter.name.as_ref().unwrap().eq,
-            Some("Todo".to_string())
-        );
+    #[tokio::test]
+    async fn test_execute_batch_runs_each_query() {
+        let executor = CannedExecutor {
+            data: serde_json::json!({
+                "viewer": { "id": "user-1", "name": "Test User", "email": "test@example.com" }
+            }),
+            operations: std::sync::Mutex::new(Vec::new()),
+        };
 
-        // Check team filter
-        let team_filter = (*filter.team).as_ref().unwrap();
-        assert_eq!(
-            team_filter.key.as_ref().unwrap().eq,
-            Some("DESIGN".to_string())
-        );
+        let results = executor
+            .execute_batch(vec![
+                (Viewer, viewer::Variables {}),
+                (Viewer, viewer::Variables {}),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].viewer.email, "test@example.com");
+        assert_eq!(executor.operations.lock().unwrap().len(), 2);
     }
 
     #[tokio::test]
-    async fn test_build_issue_filter_empty() {
+    async fn test_issues_stream_fetches_pages_on_demand() {
+        use futures::StreamExt;
+
+        let mut server = mock_linear_server().await;
+        let first_page = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": { "first": 250 } }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": { "issues": {
+                        "nodes": mock_issues_response()["data"]["issues"]["nodes"],
+                        "pageInfo": { "hasNextPage": true, "endCursor": "cursor-3" }
+                    }}
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create();
+
         let client = LinearClient::builder()
             .auth_token(SecretString::new(
                 "test_api_key".to_string().into_boxed_str(),
             ))
+            .base_url(Some(server.url()))
             .build()
             .unwrap();
 
-        let filters = IssueFilters {
-            assignee: None,
-            status: None,
-            team: None,
-        };
+        // Taking only the first page's issues must not request the second page
+        let issues: Vec<Issue> = client
+            .issues_stream(None)
+            .take(3)
+            .map(|issue| issue.unwrap())
+            .collect()
+            .await;
 
-        let result = client.build_issue_filter(&filters).await;
-        assert!(result.is_ok());
-        let filter = result.unwrap();
-        assert!(filter.is_none());
+        first_page.assert();
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[2].identifier, "TEST-3");
     }
 
     #[test]
-    fn test_get_issue_query_builds() {
-        let _query = GetIssue::build_query(get_issue::Variables {
-            id: "ENG-123".to_string(),
+    fn test_list_issues_query_builds() {
+        let _query = ListIssues::build_query(list_issues::Variables {
+            first: 20,
+            after: None,
+            filter: None,
+            include_archived: None,
         });
     }
 
     #[tokio::test]
-    async fn test_get_issue_success() {
+    async fn test_list_issues_success() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
             .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_detailed_issue_response().to_string())
+            .with_body(mock_issues_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -2614,55 +4373,44 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.get_issue("ENG-123".to_string()).await;
+        let result = client.list_issues(20).await;
 
         mock.assert();
         assert!(result.is_ok());
-        let issue = result.unwrap();
-
-        assert_eq!(issue.identifier, "ENG-123");
-        assert_eq!(issue.title, "Fix login race condition");
-        assert_eq!(issue.state.name, "In Progress");
-        assert_eq!(issue.state.type_, "started");
-
-        assert!(issue.assignee.is_some());
-        let assignee = issue.assignee.unwrap();
-        assert_eq!(assignee.name, "John Doe");
-        assert_eq!(assignee.email, "john@example.com");
-
-        assert!(issue.team.is_some());
-        let team = issue.team.unwrap();
-        assert_eq!(team.key, "ENG");
-        assert_eq!(team.name, "Engineering");
-
-        assert!(issue.project.is_some());
-        assert_eq!(issue.project.unwrap().name, "Web App");
-
-        assert_eq!(issue.labels.len(), 2);
-        assert_eq!(issue.labels[0].name, "bug");
-        assert_eq!(issue.labels[1].name, "authentication");
+        let issues = result.unwrap();
+        assert_eq!(issues.len(), 3);
 
-        assert_eq!(issue.priority, Some(2));
-        assert_eq!(issue.priority_label, Some("High".to_string()));
+        assert_eq!(issues[0].identifier, "TEST-1");
+        assert_eq!(issues[0].title, "Test Issue 1");
+        assert_eq!(issues[0].status, "Todo");
+        assert_eq!(issues[0].assignee, Some("Alice".to_string()));
+        assert_eq!(issues[0].assignee_id, Some("user-1".to_string()));
+        assert_eq!(issues[0].team, Some("ENG".to_string()));
 
-        assert!(issue.description.is_some());
-        assert!(issue
-            .description
-            .unwrap()
-            .contains("race conditions when logging in"));
+        assert_eq!(issues[1].identifier, "TEST-2");
+        assert_eq!(issues[1].title, "Test Issue 2");
+        assert_eq!(issues[1].status, "In Progress");
+        assert_eq!(issues[1].assignee, Some("Bob".to_string()));
+        assert_eq!(issues[1].assignee_id, Some("user-2".to_string()));
+        assert_eq!(issues[1].team, Some("DESIGN".to_string()));
 
-        assert_eq!(issue.url, "https://linear.app/test/issue/ENG-123");
+        assert_eq!(issues[2].identifier, "TEST-3");
+        assert_eq!(issues[2].title, "Test Issue 3");
+        assert_eq!(issues[2].status, "Done");
+        assert_eq!(issues[2].assignee, None);
+        assert_eq!(issues[2].assignee_id, None);
+        assert_eq!(issues[2].team, Some("QA".to_string()));
     }
 
     #[tokio::test]
-    async fn test_get_issue_minimal_response() {
+    async fn test_list_issues_empty() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
             .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_minimal_issue_response().to_string())
+            .with_body(mock_empty_issues_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -2672,23 +4420,16 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.get_issue("ENG-456".to_string()).await;
+        let result = client.list_issues(20).await;
 
         mock.assert();
         assert!(result.is_ok());
-        let issue = result.unwrap();
-
-        assert_eq!(issue.identifier, "ENG-456");
-        assert_eq!(issue.title, "Simple issue");
-        assert_eq!(issue.state.name, "Todo");
-        assert!(issue.assignee.is_none());
-        assert!(issue.project.is_none());
-        assert!(issue.description.is_none());
-        assert_eq!(issue.labels.len(), 0);
+        let issues = result.unwrap();
+        assert_eq!(issues.len(), 0);
     }
 
     #[tokio::test]
-    async fn test_get_issue_error() {
+    async fn test_list_issues_error() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
@@ -2705,7 +4446,7 @@ mod tests {
             .base_url(Some(server.url()))
             .build()
             .unwrap();
-        let result = client.get_issue("INVALID".to_string()).await;
+        let result = client.list_issues(20).await;
 
         mock.assert();
         assert!(result.is_err());
@@ -2715,7 +4456,7 @@ mod tests {
 
     #[tokio::test]
     #[cfg(feature = "integration-tests")]
-    async fn test_get_issue_real_api() {
+    async fn test_list_issues_real_api() {
         let api_key = std::env::var("LINEAR_API_KEY")
             .expect("LINEAR_API_KEY must be set for integration tests");
 
@@ -2723,39 +4464,24 @@ mod tests {
             .auth_token(SecretString::new(api_key.into()))
             .build()
             .expect("Failed to create client");
+        let result = client.list_issues(5).await;
 
-        // Try to get a specific issue - this will vary by team
-        // In a real test, you'd use a known issue ID
-        let result = client.get_issue("ENG-1".to_string()).await;
-
-        // The test should either succeed or fail with "Issue not found"
-        // but not with authentication or network errors
-        match result {
-            Ok(_) => {
-                // Success case - issue exists
-            }
-            Err(e) => {
-                // Should be a GraphQL error about the issue not existing
-                assert!(
-                    e.to_string().contains("GraphQL errors")
-                        || e.to_string().contains("Issue not found")
-                );
-            }
-        }
+        assert!(result.is_ok(), "Query should succeed with valid API key");
+        let issues = result.unwrap();
+        assert!(issues.len() <= 5, "Should return at most 5 issues");
     }
 
-    // CREATE ISSUE TESTS - Testing the core create functionality
-
     #[tokio::test]
-    async fn test_create_issue_success_with_all_fields() {
+    async fn test_build_issue_filter_assignee_me() {
         let mut server = mock_linear_server().await;
-        let mock = server
+
+        // Mock the viewer query
+        let viewer_mock = server
             .mock("POST", "/graphql")
             .match_header("authorization", "test_api_key")
-            .match_header("user-agent", "linear-cli/0.1.0")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_create_issue_success_response().to_string())
+            .with_body(mock_viewer_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -2766,48 +4492,1228 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateIssueInput {
-            title: "Test Created Issue".to_string(),
-            description: Some("Test description for created issue".to_string()),
-            team_id: Some("team-123".to_string()),
+        let filters = IssueFilters {
+            assignee: Some("me".to_string()),
+            status: None,
+            team: None,
+            ..Default::default()
+        };
+
+        let result = client.build_issue_filter(&filters).await;
+        viewer_mock.assert();
+
+        assert!(result.is_ok());
+        let filter = result.unwrap();
+        assert!(filter.is_some());
+
+        let filter = filter.unwrap();
+        let assignee_filter = (*filter.assignee).as_ref().unwrap();
+        assert!(assignee_filter.id.is_some());
+        assert_eq!(
+            assignee_filter.id.as_ref().unwrap().eq,
+            Some("test-user-id".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_assignee_unassigned() {
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            assignee: Some("unassigned".to_string()),
+            status: None,
+            team: None,
+            ..Default::default()
+        };
+
+        let result = client.build_issue_filter(&filters).await;
+        assert!(result.is_ok());
+        let filter = result.unwrap();
+        assert!(filter.is_some());
+
+        let filter = filter.unwrap();
+        let assignee_filter = (*filter.assignee).as_ref().unwrap();
+        assert_eq!(assignee_filter.null, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_status() {
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            assignee: None,
+            status: Some("in progress".to_string()),
+            team: None,
+            ..Default::default()
+        };
+
+        let result = client.build_issue_filter(&filters).await;
+        assert!(result.is_ok());
+        let filter = result.unwrap();
+        assert!(filter.is_some());
+
+        let filter = filter.unwrap();
+        let state_filter = (*filter.state).as_ref().unwrap();
+        assert!(state_filter.name.is_some());
+        assert_eq!(
+            state_filter.name.as_ref().unwrap().eq,
+            Some("In Progress".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_team() {
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            assignee: None,
+            status: None,
+            team: Some("ENG".to_string()),
+            ..Default::default()
+        };
+
+        let result = client.build_issue_filter(&filters).await;
+        assert!(result.is_ok());
+        let filter = result.unwrap();
+        assert!(filter.is_some());
+
+        let filter = filter.unwrap();
+        let team_filter = (*filter.team).as_ref().unwrap();
+        assert!(team_filter.key.is_some());
+        assert_eq!(
+            team_filter.key.as_ref().unwrap().eq,
+            Some("ENG".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_combined() {
+        let mut server = mock_linear_server().await;
+
+        // Mock the viewer query
+        let viewer_mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_viewer_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            assignee: Some("me".to_string()),
+            status: Some("todo".to_string()),
+            team: Some("DESIGN".to_string()),
+            ..Default::default()
+        };
+
+        let result = client.build_issue_filter(&filters).await;
+        viewer_mock.assert();
+
+        assert!(result.is_ok());
+        let filter = result.unwrap();
+        assert!(filter.is_some());
+
+        let filter = filter.unwrap();
+
+        // Check assignee filter
+        let assignee_filter = (*filter.assignee).as_ref().unwrap();
+        assert_eq!(
+            assignee_filter.id.as_ref().unwrap().eq,
+            Some("test-user-id".to_string())
+        );
+
+        // Check status filter
+        let state_filter = (*filter.state).as_ref().unwrap();
+        assert_eq!(
+            state_filter.name.as_ref().unwrap().eq,
+            Some("Todo".to_string())
+        );
+
+        // Check team filter
+        let team_filter = (*filter.team).as_ref().unwrap();
+        assert_eq!(
+            team_filter.key.as_ref().unwrap().eq,
+            Some("DESIGN".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_empty() {
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            assignee: None,
+            status: None,
+            team: None,
+            ..Default::default()
+        };
+
+        let result = client.build_issue_filter(&filters).await;
+        assert!(result.is_ok());
+        let filter = result.unwrap();
+        assert!(filter.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_extended_fields() {
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            labels: vec!["bug".to_string()],
+            project: Some("Mobile App".to_string()),
+            cycle: Some("next".to_string()),
+            priority: Some(1),
+            creator: Some("me".to_string()),
+            state_types: vec!["started".to_string()],
+            updated_since: Some("-P7D".to_string()),
+            parent: Some("eng-42".to_string()),
+            no_estimate: true,
+            ..Default::default()
+        };
+
+        let filter = client.build_issue_filter(&filters).await.unwrap().unwrap();
+        assert_eq!(
+            serde_json::to_value(filter).unwrap(),
+            serde_json::json!({
+                "labels": { "some": { "name": { "eqIgnoreCase": "bug" } } },
+                "project": { "name": { "eqIgnoreCase": "Mobile App" } },
+                "cycle": { "isNext": { "eq": true } },
+                "priority": { "eq": 1.0 },
+                "creator": { "isMe": { "eq": true } },
+                "state": { "type": { "in": ["started"] } },
+                "updatedAt": { "gt": "-P7D" },
+                "parent": { "number": { "eq": 42.0 }, "team": { "key": { "eq": "ENG" } } },
+                "estimate": { "null": true }
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_build_issue_filter_rejects_invalid_cycle_and_parent() {
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            cycle: Some("someday".to_string()),
+            ..Default::default()
+        };
+        let result = client.build_issue_filter(&filters).await;
+        assert!(matches!(result, Err(LinearError::InvalidInput { .. })));
+
+        let filters = IssueFilters {
+            parent: Some("not-an-issue".to_string()),
+            ..Default::default()
+        };
+        let result = client.build_issue_filter(&filters).await;
+        assert!(matches!(result, Err(LinearError::InvalidInput { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_list_issues_filtered_sends_include_archived() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": { "includeArchived": true } }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_empty_issues_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let filters = IssueFilters {
+            include_archived: true,
+            ..Default::default()
+        };
+        let issues = client
+            .list_issues_filtered(10, Some(filters))
+            .await
+            .unwrap();
+
+        mock.assert();
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_get_issue_query_builds() {
+        let _query = GetIssue::build_query(get_issue::Variables {
+            id: "ENG-123".to_string(),
+        });
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_success() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_detailed_issue_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+        let result = client.get_issue("ENG-123".to_string()).await;
+
+        mock.assert();
+        assert!(result.is_ok());
+        let issue = result.unwrap();
+
+        assert_eq!(issue.identifier, "ENG-123");
+        assert_eq!(issue.title, "Fix login race condition");
+        assert_eq!(issue.state.name, "In Progress");
+        assert_eq!(issue.state.type_, "started");
+
+        assert!(issue.assignee.is_some());
+        let assignee = issue.assignee.unwrap();
+        assert_eq!(assignee.name, "John Doe");
+        assert_eq!(assignee.email, "john@example.com");
+
+        assert!(issue.team.is_some());
+        let team = issue.team.unwrap();
+        assert_eq!(team.key, "ENG");
+        assert_eq!(team.name, "Engineering");
+
+        assert!(issue.project.is_some());
+        assert_eq!(issue.project.unwrap().name, "Web App");
+        assert_eq!(issue.milestone.as_deref(), Some("Beta"));
+
+        assert_eq!(issue.labels.len(), 2);
+        assert_eq!(issue.labels[0].name, "bug");
+        assert_eq!(issue.labels[1].name, "authentication");
+
+        assert_eq!(issue.priority, Some(2));
+        assert_eq!(issue.priority_label, Some("High".to_string()));
+
+        assert!(issue.description.is_some());
+        assert!(issue
+            .description
+            .unwrap()
+            .contains("race conditions when logging in"));
+
+        assert_eq!(issue.url, "https://linear.app/test/issue/ENG-123");
+
+        let parent = issue.parent.unwrap();
+        assert_eq!(parent.identifier, "ENG-100");
+        assert!(parent.assignee.is_none());
+        assert_eq!(issue.children.len(), 1);
+        assert_eq!(issue.children[0].identifier, "ENG-124");
+        assert_eq!(issue.children[0].assignee, Some("John Doe".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_tree() {
+        let mut server = mock_linear_server().await;
+        let sub_issue = |id: &str, parent: &str| {
+            serde_json::json!({
+                "id": id,
+                "identifier": id.to_uppercase(),
+                "title": format!("Sub-issue {id}"),
+                "parent": { "id": parent },
+                "state": { "id": "state-1", "name": "Todo", "type": "unstarted" },
+                "assignee": null
+            })
+        };
+        let mut level = |parent_ids: serde_json::Value, nodes: Vec<serde_json::Value>| {
+            server
+                .mock("POST", "/graphql")
+                .match_body(mockito::Matcher::PartialJson(
+                    serde_json::json!({ "variables": { "parentIds": parent_ids } }),
+                ))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "data": { "issues": {
+                            "nodes": nodes,
+                            "pageInfo": { "hasNextPage": false, "endCursor": null }
+                        }}
+                    })
+                    .to_string(),
+                )
+                .create()
+        };
+        let first_level = level(
+            serde_json::json!(["issue-detailed-123"]),
+            vec![
+                sub_issue("eng-1", "issue-detailed-123"),
+                sub_issue("eng-2", "issue-detailed-123"),
+            ],
+        );
+        let second_level = level(
+            serde_json::json!(["eng-1", "eng-2"]),
+            vec![sub_issue("eng-3", "eng-2")],
+        );
+        let third_level = level(serde_json::json!(["eng-3"]), vec![]);
+        let root = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "variables": { "id": "ENG-123" } }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_detailed_issue_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+        let tree = client.get_issue_tree("ENG-123".to_string()).await.unwrap();
+
+        root.assert();
+        first_level.assert();
+        second_level.assert();
+        third_level.assert();
+        assert_eq!(tree.identifier, "ENG-123");
+        let children: Vec<_> = tree
+            .children
+            .iter()
+            .map(|c| c.identifier.as_str())
+            .collect();
+        assert_eq!(children, vec!["ENG-1", "ENG-2"]);
+        assert!(tree.children[0].children.is_empty());
+        assert_eq!(tree.children[1].children[0].identifier, "ENG-3");
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_minimal_response() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_minimal_issue_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+        let result = client.get_issue("ENG-456".to_string()).await;
+
+        mock.assert();
+        assert!(result.is_ok());
+        let issue = result.unwrap();
+
+        assert_eq!(issue.identifier, "ENG-456");
+        assert_eq!(issue.title, "Simple issue");
+        assert_eq!(issue.state.name, "Todo");
+        assert!(issue.assignee.is_none());
+        assert!(issue.project.is_none());
+        assert!(issue.description.is_none());
+        assert_eq!(issue.labels.len(), 0);
+        assert!(issue.parent.is_none());
+        assert!(issue.children.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_issue_error() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_graphql_error_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+        let result = client.get_issue("INVALID".to_string()).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("GraphQL error"));
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "integration-tests")]
+    async fn test_get_issue_real_api() {
+        let api_key = std::env::var("LINEAR_API_KEY")
+            .expect("LINEAR_API_KEY must be set for integration tests");
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(api_key.into()))
+            .build()
+            .expect("Failed to create client");
+
+        // Try to get a specific issue - this will vary by team
+        // In a real test, you'd use a known issue ID
+        let result = client.get_issue("ENG-1".to_string()).await;
+
+        // The test should either succeed or fail with "Issue not found"
+        // but not with authentication or network errors
+        match result {
+            Ok(_) => {
+                // Success case - issue exists
+            }
+            Err(e) => {
+                // Should be a GraphQL error about the issue not existing
+                assert!(
+                    e.to_string().contains("GraphQL errors")
+                        || e.to_string().contains("Issue not found")
+                );
+            }
+        }
+    }
+
+    // CREATE ISSUE TESTS - Testing the core create functionality
+
+    #[tokio::test]
+    async fn test_create_issue_success_with_all_fields() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .match_header("user-agent", "linear-cli/0.1.0")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_create_issue_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateIssueInput {
+            title: "Test Created Issue".to_string(),
+            description: Some("Test description for created issue".to_string()),
+            team_id: Some("team-123".to_string()),
+            state_id: None,
             assignee_id: Some("user-456".to_string()),
             priority: Some(2),
             label_ids: Some(vec!["label-789".to_string()]),
             project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.create_issue(input).await;
+
+        mock.assert();
+        assert!(result.is_ok());
+        let issue = result.unwrap();
+        assert_eq!(issue.id, "created-issue-123");
+        assert_eq!(issue.identifier, "ENG-456");
+        assert_eq!(issue.title, "Test Created Issue");
+        assert_eq!(
+            issue.description,
+            Some("Test description for created issue".to_string())
+        );
+        assert_eq!(issue.team.as_ref().unwrap().key, "ENG");
+        assert_eq!(issue.assignee.as_ref().unwrap().name, "Test User");
+        assert_eq!(issue.priority, Some(2));
+        assert_eq!(issue.priority_label, Some("High".to_string()));
+        assert_eq!(issue.labels.len(), 1);
+        assert_eq!(issue.labels[0].name, "bug");
+        assert_eq!(issue.url, "https://linear.app/test/issue/ENG-456");
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_minimal_required_fields() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_create_issue_minimal_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateIssueInput {
+            title: "Minimal Issue".to_string(),
+            description: None,
+            team_id: Some("team-123".to_string()),
+            state_id: None,
+            assignee_id: None,
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.create_issue(input).await;
+
+        mock.assert();
+        assert!(result.is_ok());
+        let issue = result.unwrap();
+        assert_eq!(issue.id, "created-issue-minimal-789");
+        assert_eq!(issue.identifier, "ENG-789");
+        assert_eq!(issue.title, "Minimal Issue");
+        assert_eq!(issue.description, None);
+        assert_eq!(issue.team.as_ref().unwrap().key, "ENG");
+        assert_eq!(issue.assignee, None);
+        assert_eq!(issue.priority, Some(0));
+        assert_eq!(issue.labels.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_failure_response() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_create_issue_failure_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateIssueInput {
+            title: "Test Issue".to_string(),
+            description: None,
+            team_id: Some("team-123".to_string()),
+            state_id: None,
+            assignee_id: None,
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.create_issue(input).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("Issue creation failed"));
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_validation_errors() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_create_issue_validation_error_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateIssueInput {
+            title: "".to_string(), // Empty title should trigger validation error
+            description: None,
+            team_id: Some("team-123".to_string()),
+            state_id: None,
+            assignee_id: None,
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.create_issue(input).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("Title is required"));
+    }
+
+    #[tokio::test]
+    async fn test_create_issue_graphql_errors() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_graphql_error_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateIssueInput {
+            title: "Test Issue".to_string(),
+            description: None,
+            team_id: Some("team-123".to_string()),
+            state_id: None,
+            assignee_id: None,
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.create_issue(input).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("GraphQL error"));
+    }
+
+    // UPDATE ISSUE TESTS - Following TDD approach
+
+    #[tokio::test]
+    async fn test_update_issue_success() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = UpdateIssueInput {
+            title: Some("Updated Issue Title".to_string()),
+            description: Some("Updated issue description".to_string()),
+            assignee_id: Some("user-789".to_string()),
+            state_id: Some("state-456".to_string()),
+            priority: Some(3),
+            label_ids: Some(vec!["label-456".to_string()]),
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.update_issue("ENG-123".to_string(), input).await;
+
+        mock.assert();
+        assert!(result.is_ok());
+        let issue = result.unwrap();
+        assert_eq!(issue.id, "updated-issue-123");
+        assert_eq!(issue.identifier, "ENG-123");
+        assert_eq!(issue.title, "Updated Issue Title");
+        assert_eq!(
+            issue.description,
+            Some("Updated issue description".to_string())
+        );
+        assert_eq!(issue.state.name, "In Progress");
+        assert_eq!(issue.assignee.as_ref().unwrap().name, "Jane Doe");
+        assert_eq!(issue.priority, Some(3));
+        assert_eq!(issue.priority_label, Some("Normal".to_string()));
+        assert_eq!(issue.labels.len(), 1);
+        assert_eq!(issue.labels[0].name, "enhancement");
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_clears_due_date() {
+        let mut server = mock_linear_server().await;
+        let clear = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ClearIssueDueDate",
+                "variables": { "id": "ENG-123" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data":{"issueUpdate":{"success":true}}}"#)
+            .create();
+        let update = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "UpdateIssue" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = UpdateIssueInput {
+            title: None,
+            description: None,
+            assignee_id: None,
+            state_id: None,
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: Some(String::new()),
+        };
+        let issue = client
+            .update_issue("ENG-123".to_string(), input)
+            .await
+            .unwrap();
+
+        clear.assert();
+        update.assert();
+        assert_eq!(issue.identifier, "ENG-123");
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_sets_and_clears_milestone() {
+        let mut server = mock_linear_server().await;
+        let clear = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ClearIssueMilestone",
+                "variables": { "id": "ENG-123" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data":{"issueUpdate":{"success":true}}}"#)
+            .expect(1)
+            .create();
+        let set = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "UpdateIssue",
+                "variables": { "input": { "projectMilestoneId": "milestone-1" } }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_success_response().to_string())
+            .create();
+        let update = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "UpdateIssue",
+                "variables": { "input": {} }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = UpdateIssueInput {
+            project_milestone_id: Some("milestone-1".to_string()),
+            ..Default::default()
+        };
+        client
+            .update_issue("ENG-123".to_string(), input)
+            .await
+            .unwrap();
+        set.assert();
+
+        let input = UpdateIssueInput {
+            project_milestone_id: Some(String::new()),
+            ..Default::default()
+        };
+        client
+            .update_issue("ENG-123".to_string(), input)
+            .await
+            .unwrap();
+        clear.assert();
+        update.assert();
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_detaches_parent_with_null() {
+        let mut server = mock_linear_server().await;
+        let clear = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::PartialJson(serde_json::json!({
+                    "operationName": "ClearIssueParent",
+                    "variables": { "id": "ENG-123" }
+                })),
+                mockito::Matcher::Regex(r"parentId: null".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data":{"issueUpdate":{"success":true}}}"#)
+            .expect(1)
+            .create();
+        let update = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "UpdateIssue",
+                "variables": { "input": {} }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_success_response().to_string())
+            .expect(1)
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = UpdateIssueInput {
+            parent_id: Some(String::new()),
+            ..Default::default()
+        };
+        client
+            .update_issue("ENG-123".to_string(), input)
+            .await
+            .unwrap();
+        clear.assert();
+        update.assert();
+    }
+
+    #[tokio::test]
+    async fn test_archive_unarchive_and_delete_issue() {
+        let mut server = mock_linear_server().await;
+        let mut mutation = |operation: &str, field: &str, variables: serde_json::Value| {
+            server
+                .mock("POST", "/graphql")
+                .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                    "operationName": operation,
+                    "variables": variables
+                })))
+                .with_status(200)
+                .with_header("content-type", "application/json")
+                .with_body(
+                    serde_json::json!({
+                        "data": { field: { "success": true, "lastSyncId": 1.0 } }
+                    })
+                    .to_string(),
+                )
+                .create()
+        };
+        let archive = mutation(
+            "ArchiveIssue",
+            "issueArchive",
+            serde_json::json!({ "id": "ENG-1" }),
+        );
+        let unarchive = mutation(
+            "UnarchiveIssue",
+            "issueUnarchive",
+            serde_json::json!({ "id": "ENG-1" }),
+        );
+        let delete = mutation(
+            "DeleteIssue",
+            "issueDelete",
+            serde_json::json!({ "id": "ENG-2", "permanentlyDelete": true }),
+        );
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        client.archive_issue("ENG-1").await.unwrap();
+        client.unarchive_issue("ENG-1").await.unwrap();
+        client.delete_issue("ENG-2", true).await.unwrap();
+
+        archive.assert();
+        unarchive.assert();
+        delete.assert();
+    }
+
+    #[tokio::test]
+    async fn test_archive_missing_issue() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": null,
+                    "errors": [{
+                        "message": "Entity not found: Issue",
+                        "extensions": { "code": "INVALID_INPUT" }
+                    }]
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+        let result = client.archive_issue("ENG-404").await;
+
+        mock.assert();
+        assert!(matches!(
+            result,
+            Err(LinearError::IssueNotFound { ref identifier, .. }) if identifier == "ENG-404"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_my_work_overdue_filter() {
+        let mut server = mock_linear_server().await;
+        let empty = serde_json::json!({
+            "nodes": [],
+            "pageInfo": { "hasNextPage": false, "endCursor": null }
+        });
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "variables": {
+                    "dueBefore": "P0D",
+                    "excludedStateTypes": ["completed", "canceled"]
+                }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": { "viewer": {
+                        "id": "user-1",
+                        "name": "Test User",
+                        "displayName": "test",
+                        "assignedIssues": empty,
+                        "createdIssues": empty
+                    }}
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+        let filters = MyWorkFilters {
+            due_before: None,
+            overdue: true,
+        };
+        let my_work = client.get_my_work_filtered(10, &filters).await.unwrap();
+
+        mock.assert();
+        assert!(my_work.assigned_issues.is_empty());
+        assert!(my_work.created_issues.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_partial_fields() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        // Only update title and status
+        let input = UpdateIssueInput {
+            title: Some("New Title".to_string()),
+            description: None,
+            assignee_id: None,
+            state_id: Some("state-456".to_string()),
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
+            estimate: None,
+            cycle_id: None,
+            parent_id: None,
+            due_date: None,
+        };
+
+        let result = client.update_issue("ENG-123".to_string(), input).await;
+
+        mock.assert();
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_issue_failure_response() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_update_issue_failure_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = UpdateIssueInput {
+            title: Some("Updated Title".to_string()),
+            description: None,
+            assignee_id: None,
+            state_id: None,
+            priority: None,
+            label_ids: None,
+            project_id: None,
+            project_milestone_id: None,
             estimate: None,
             cycle_id: None,
+            parent_id: None,
+            due_date: None,
         };
 
-        let result = client.create_issue(input).await;
+        let result = client.update_issue("ENG-123".to_string(), input).await;
 
         mock.assert();
-        assert!(result.is_ok());
-        let issue = result.unwrap();
-        assert_eq!(issue.id, "created-issue-123");
-        assert_eq!(issue.identifier, "ENG-456");
-        assert_eq!(issue.title, "Test Created Issue");
-        assert_eq!(
-            issue.description,
-            Some("Test description for created issue".to_string())
-        );
-        assert_eq!(issue.team.as_ref().unwrap().key, "ENG");
-        assert_eq!(issue.assignee.as_ref().unwrap().name, "Test User");
-        assert_eq!(issue.priority, Some(2));
-        assert_eq!(issue.priority_label, Some("High".to_string()));
-        assert_eq!(issue.labels.len(), 1);
-        assert_eq!(issue.labels[0].name, "bug");
-        assert_eq!(issue.url, "https://linear.app/test/issue/ENG-456");
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("Issue update failed"));
     }
 
     #[tokio::test]
-    async fn test_create_issue_minimal_required_fields() {
+    async fn test_update_issue_not_found() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
             .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_create_issue_minimal_success_response().to_string())
+            .with_body(mock_update_issue_not_found_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -2818,42 +5724,78 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateIssueInput {
-            title: "Minimal Issue".to_string(),
+        let input = UpdateIssueInput {
+            title: Some("Updated Title".to_string()),
             description: None,
-            team_id: Some("team-123".to_string()),
             assignee_id: None,
+            state_id: None,
             priority: None,
             label_ids: None,
             project_id: None,
+            project_milestone_id: None,
             estimate: None,
             cycle_id: None,
+            parent_id: None,
+            due_date: None,
         };
 
-        let result = client.create_issue(input).await;
+        let result = client.update_issue("INVALID-123".to_string(), input).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        // The error handling correctly converts GraphQL "not found" to IssueNotFound error
+        assert!(error.to_string().contains("not found"));
+    }
+
+    // CREATE COMMENT TESTS - Following TDD approach
+
+    #[tokio::test]
+    async fn test_create_comment_success() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_create_comment_success_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateCommentInput {
+            body: "This is a test comment".to_string(),
+            issue_id: "issue-789".to_string(),
+        };
+
+        let result = client.create_comment(input).await;
 
         mock.assert();
         assert!(result.is_ok());
-        let issue = result.unwrap();
-        assert_eq!(issue.id, "created-issue-minimal-789");
-        assert_eq!(issue.identifier, "ENG-789");
-        assert_eq!(issue.title, "Minimal Issue");
-        assert_eq!(issue.description, None);
-        assert_eq!(issue.team.as_ref().unwrap().key, "ENG");
-        assert_eq!(issue.assignee, None);
-        assert_eq!(issue.priority, Some(0));
-        assert_eq!(issue.labels.len(), 0);
+        let comment = result.unwrap();
+        assert_eq!(comment.id, "comment-123");
+        assert_eq!(comment.body, "This is a test comment");
+        assert_eq!(comment.user.name, "Test User");
+        assert_eq!(comment.user.email, "test@example.com");
+        assert_eq!(comment.issue.identifier, "ENG-123");
+        assert_eq!(comment.issue.title, "Test Issue");
     }
 
     #[tokio::test]
-    async fn test_create_issue_failure_response() {
+    async fn test_create_comment_failure_response() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
             .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_create_issue_failure_response().to_string())
+            .with_body(mock_create_comment_failure_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -2864,35 +5806,344 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateIssueInput {
-            title: "Test Issue".to_string(),
-            description: None,
-            team_id: Some("team-123".to_string()),
-            assignee_id: None,
-            priority: None,
-            label_ids: None,
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
+        let input = CreateCommentInput {
+            body: "Test comment".to_string(),
+            issue_id: "issue-789".to_string(),
+        };
+
+        let result = client.create_comment(input).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        // When the comment field is null, the GraphQL client can't parse the response
+        // so we get a network/parsing error instead of the expected business logic error
+        assert!(error.to_string().contains("error"));
+    }
+
+    #[tokio::test]
+    async fn test_create_comment_graphql_error() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .match_header("authorization", "test_api_key")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_graphql_error_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let input = CreateCommentInput {
+            body: "Test comment".to_string(),
+            issue_id: "invalid-issue".to_string(),
+        };
+
+        let result = client.create_comment(input).await;
+
+        mock.assert();
+        assert!(result.is_err());
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("GraphQL error"));
+    }
+
+    // TEAM STATES TESTS - Following TDD approach for state resolution
+
+    #[tokio::test]
+    async fn test_list_cycles_takes_latest_scope() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_cycles_response().to_string())
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let cycles = client.list_cycles("team-123").await.unwrap();
+
+        mock.assert();
+        let numbers: Vec<_> = cycles.iter().map(|c| c.number).collect();
+        assert_eq!(numbers, [43, 42, 41]);
+
+        let active = &cycles[1];
+        assert!(active.is_active);
+        assert_eq!(active.name.as_deref(), Some("Launch prep"));
+        assert_eq!(active.scope, 20.0);
+        assert_eq!(active.started_scope, 6.0);
+        assert_eq!(active.completed_scope, 5.0);
+        assert_eq!(active.issue_count, 9);
+        assert_eq!(active.completed_issue_count, 2);
+
+        assert!(!cycles[0].is_active);
+        assert_eq!(cycles[0].scope, 0.0);
+        assert!(cycles[2].completed_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_cycles_follows_pages() {
+        let mut server = mock_linear_server().await;
+        let mut first_page = mock_cycles_response();
+        first_page["data"]["team"]["cycles"]["pageInfo"] =
+            serde_json::json!({ "hasNextPage": true, "endCursor": "cursor-1" });
+        let second_page = serde_json::json!({
+            "data": { "team": {
+                "activeCycle": { "id": "cycle-42" },
+                "cycles": {
+                    "nodes": [{
+                        "id": "cycle-1",
+                        "number": 1,
+                        "name": null,
+                        "startsAt": "2024-01-01T00:00:00Z",
+                        "endsAt": "2024-01-15T00:00:00Z",
+                        "completedAt": "2024-01-15T00:00:00Z",
+                        "progress": 1.0,
+                        "scopeHistory": [],
+                        "inProgressScopeHistory": [],
+                        "completedScopeHistory": [],
+                        "issueCountHistory": [],
+                        "completedIssueCountHistory": []
+                    }],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                }
+            } }
+        });
+        let second = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ListCycles",
+                "variables": { "after": "cursor-1" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(second_page.to_string())
+            .expect(1)
+            .create();
+        let first = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "ListCycles" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first_page.to_string())
+            .expect(1)
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let cycles = client.list_cycles("team-123").await.unwrap();
+
+        first.assert();
+        second.assert();
+        let numbers: Vec<_> = cycles.iter().map(|c| c.number).collect();
+        assert_eq!(numbers, [43, 42, 41, 1]);
+        assert!(cycles[1].is_active);
+    }
+
+    #[tokio::test]
+    async fn test_get_cycle_includes_issues() {
+        let mut server = mock_linear_server().await;
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_cycle_response().to_string())
+            .expect(2)
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let detail = client.get_cycle("cycle-42").await.unwrap();
+
+        mock.assert();
+        assert!(detail.cycle.is_active);
+        assert_eq!(detail.team.key, "ENG");
+        assert_eq!(detail.issues.len(), 2);
+        assert_eq!(detail.issues[0].estimate, Some(5.0));
+        assert_eq!(detail.issues[0].state.type_, "completed");
+        assert_eq!(detail.issues[0].assignee.as_deref(), Some("Alice"));
+        assert_eq!(detail.issues[1].assignee, None);
+
+        // The cycle's fields sit alongside the team and issues in JSON
+        let json = serde_json::to_value(&detail).unwrap();
+        assert_eq!(json["number"], 42);
+        assert_eq!(json["completedScope"], 5.0);
+        assert_eq!(json["issues"][1]["identifier"], "ENG-2");
+    }
+
+    #[tokio::test]
+    async fn test_get_cycle_follows_issue_pages() {
+        let mut server = mock_linear_server().await;
+        let issue_page = |identifier: &str, has_next_page: bool, end_cursor: Option<&str>| {
+            serde_json::json!({
+                "data": { "cycle": { "issues": {
+                    "nodes": [{
+                        "id": format!("id-{identifier}"),
+                        "identifier": identifier,
+                        "title": "Carry over",
+                        "estimate": null,
+                        "state": { "id": "state-todo-123", "name": "Todo", "type": "unstarted" },
+                        "assignee": null
+                    }],
+                    "pageInfo": { "hasNextPage": has_next_page, "endCursor": end_cursor }
+                } } }
+            })
+            .to_string()
         };
+        server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "GetCycle" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(mock_cycle_response().to_string())
+            .create();
+        let second = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ListCycleIssues",
+                "variables": { "after": "cursor-1" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(issue_page("ENG-2", false, None))
+            .create();
+        let first = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "ListCycleIssues" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(issue_page("ENG-1", true, Some("cursor-1")))
+            .create();
+
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let detail = client.get_cycle("cycle-42").await.unwrap();
+
+        first.assert();
+        second.assert();
+        let identifiers: Vec<&str> = detail
+            .issues
+            .iter()
+            .map(|issue| issue.identifier.as_str())
+            .collect();
+        assert_eq!(identifiers, ["ENG-1", "ENG-2"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_cycle_history_keeps_every_day() {
+        let mut server = mock_linear_server().await;
+        let mut response = mock_cycle_response();
+        response["data"]["cycle"]
+            .as_object_mut()
+            .unwrap()
+            .remove("issues");
+        let mock = server
+            .mock("POST", "/graphql")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(response.to_string())
+            .create();
 
-        let result = client.create_issue(input).await;
+        let client = LinearClient::builder()
+            .auth_token(SecretString::new(
+                "test_api_key".to_string().into_boxed_str(),
+            ))
+            .base_url(Some(server.url()))
+            .build()
+            .unwrap();
+
+        let history = client.get_cycle_history("cycle-42").await.unwrap();
 
         mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(error.to_string().contains("Issue creation failed"));
+        assert_eq!(history.scope_history, [8.0, 20.0]);
+        assert_eq!(history.completed_scope_history, [0.0, 5.0]);
+        assert_eq!(history.cycle.scope, 20.0);
+        assert_eq!(history.team.key, "ENG");
+    }
+
+    #[test]
+    fn test_build_project_filter() {
+        assert!(LinearClient::build_project_filter(&ProjectFilters::default()).is_none());
+
+        let filters = ProjectFilters {
+            team: Some("eng".to_string()),
+            state: Some("In Progress".to_string()),
+            lead: Some("me".to_string()),
+        };
+        let filter = LinearClient::build_project_filter(&filters).unwrap();
+        assert_eq!(
+            serde_json::to_value(filter).unwrap(),
+            serde_json::json!({
+                "accessibleTeams": { "some": { "key": { "eqIgnoreCase": "eng" } } },
+                "status": { "or": [
+                    { "name": { "eqIgnoreCase": "In Progress" } },
+                    { "type": { "eq": "in progress" } }
+                ] },
+                "lead": { "isMe": { "eq": true } }
+            })
+        );
+
+        let filters = ProjectFilters {
+            lead: Some("Alice".to_string()),
+            ..Default::default()
+        };
+        let filter = LinearClient::build_project_filter(&filters).unwrap();
+        assert_eq!(
+            serde_json::to_value(filter).unwrap(),
+            serde_json::json!({
+                "lead": { "or": [
+                    { "name": { "eqIgnoreCase": "Alice" } },
+                    { "displayName": { "eqIgnoreCase": "Alice" } }
+                ] }
+            })
+        );
     }
 
     #[tokio::test]
-    async fn test_create_issue_validation_errors() {
+    async fn test_get_project_includes_people_milestones_and_issues() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_create_issue_validation_error_response().to_string())
+            .with_body(mock_project_response().to_string())
+            .expect(2)
             .create();
 
         let client = LinearClient::builder()
@@ -2903,35 +6154,70 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateIssueInput {
-            title: "".to_string(), // Empty title should trigger validation error
-            description: None,
-            team_id: Some("team-123".to_string()),
-            assignee_id: None,
-            priority: None,
-            label_ids: None,
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
-        };
-
-        let result = client.create_issue(input).await;
+        let project = client.get_project("proj-123").await.unwrap();
 
         mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(error.to_string().contains("Title is required"));
+        assert_eq!(project.status.name, "In Progress");
+        assert_eq!(project.status.type_, "started");
+        assert_eq!(project.health.as_deref(), Some("atRisk"));
+        assert_eq!(project.lead.unwrap().display_name, "alice");
+        assert_eq!(project.members.len(), 2);
+        assert_eq!(project.teams[0].key, "ENG");
+        let milestone_names: Vec<&str> =
+            project.milestones.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(milestone_names, ["Beta", "GA"]);
+        assert_eq!(project.milestones[0].progress, 0.5);
+        assert_eq!(project.milestones[0].status, "next");
+        assert_eq!(project.issues.len(), 2);
+        assert_eq!(project.issues[1].state.type_, "unstarted");
+        assert!(project.content.unwrap().starts_with("## Goals"));
     }
 
     #[tokio::test]
-    async fn test_create_issue_graphql_errors() {
+    async fn test_get_project_follows_issue_pages() {
         let mut server = mock_linear_server().await;
-        let mock = server
+        let issue_page = |identifier: &str, has_next_page: bool, end_cursor: Option<&str>| {
+            serde_json::json!({
+                "data": { "project": { "issues": {
+                    "nodes": [{
+                        "id": format!("id-{identifier}"),
+                        "identifier": identifier,
+                        "title": "Launch task",
+                        "state": { "id": "state-todo-123", "name": "Todo", "type": "unstarted" },
+                        "assignee": null
+                    }],
+                    "pageInfo": { "hasNextPage": has_next_page, "endCursor": end_cursor }
+                } } }
+            })
+            .to_string()
+        };
+        server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "GetProject" }),
+            ))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_graphql_error_response().to_string())
+            .with_body(mock_project_response().to_string())
+            .create();
+        let second = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ListProjectIssues",
+                "variables": { "after": "cursor-1" }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(issue_page("ENG-2", false, None))
+            .create();
+        let first = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "ListProjectIssues" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(issue_page("ENG-1", true, Some("cursor-1")))
             .create();
 
         let client = LinearClient::builder()
@@ -2942,37 +6228,27 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateIssueInput {
-            title: "Test Issue".to_string(),
-            description: None,
-            team_id: Some("team-123".to_string()),
-            assignee_id: None,
-            priority: None,
-            label_ids: None,
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
-        };
-
-        let result = client.create_issue(input).await;
+        let project = client.get_project("proj-123").await.unwrap();
 
-        mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(error.to_string().contains("GraphQL error"));
+        first.assert();
+        second.assert();
+        let identifiers: Vec<&str> = project
+            .issues
+            .iter()
+            .map(|issue| issue.identifier.as_str())
+            .collect();
+        assert_eq!(identifiers, ["ENG-1", "ENG-2"]);
     }
 
-    // UPDATE ISSUE TESTS - Following TDD approach
-
     #[tokio::test]
-    async fn test_update_issue_success() {
+    async fn test_resolve_project_status_to_id() {
         let mut server = mock_linear_server().await;
-        let mock = server
+        server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_update_issue_success_response().to_string())
+            .with_body(mock_project_statuses_response().to_string())
+            .expect(3)
             .create();
 
         let client = LinearClient::builder()
@@ -2983,47 +6259,32 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = UpdateIssueInput {
-            title: Some("Updated Issue Title".to_string()),
-            description: Some("Updated issue description".to_string()),
-            assignee_id: Some("user-789".to_string()),
-            state_id: Some("state-456".to_string()),
-            priority: Some(3),
-            label_ids: Some(vec!["label-456".to_string()]),
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
-        };
-
-        let result = client.update_issue("ENG-123".to_string(), input).await;
+        // Names match first, then status types
+        let by_name = client.resolve_project_status_to_id("in progress").await;
+        assert_eq!(by_name.unwrap(), "status-started");
+        let by_type = client.resolve_project_status_to_id("completed").await;
+        assert_eq!(by_type.unwrap(), "status-done");
 
-        mock.assert();
-        assert!(result.is_ok());
-        let issue = result.unwrap();
-        assert_eq!(issue.id, "updated-issue-123");
-        assert_eq!(issue.identifier, "ENG-123");
-        assert_eq!(issue.title, "Updated Issue Title");
-        assert_eq!(
-            issue.description,
-            Some("Updated issue description".to_string())
-        );
-        assert_eq!(issue.state.name, "In Progress");
-        assert_eq!(issue.assignee.as_ref().unwrap().name, "Jane Doe");
-        assert_eq!(issue.priority, Some(3));
-        assert_eq!(issue.priority_label, Some("Normal".to_string()));
-        assert_eq!(issue.labels.len(), 1);
-        assert_eq!(issue.labels[0].name, "enhancement");
+        let missing = client.resolve_project_status_to_id("Shipped").await;
+        let message = missing.unwrap_err().to_string();
+        assert!(message.contains("Backlog, Planned, In Progress, Completed"));
     }
 
     #[tokio::test]
-    async fn test_update_issue_partial_fields() {
+    async fn test_list_project_updates_newest_first() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::PartialJson(serde_json::json!({
+                    "operationName": "GetProjectUpdates",
+                    "variables": { "first": 10 }
+                })),
+                mockito::Matcher::Regex("orderBy: createdAt".to_string()),
+            ]))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_update_issue_success_response().to_string())
+            .with_body(mock_project_updates_response().to_string())
             .create();
 
         let client = LinearClient::builder()
@@ -3034,34 +6295,44 @@ mod tests {
             .build()
             .unwrap();
 
-        // Only update title and status
-        let input = UpdateIssueInput {
-            title: Some("New Title".to_string()),
-            description: None,
-            assignee_id: None,
-            state_id: Some("state-456".to_string()),
-            priority: None,
-            label_ids: None,
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
-        };
-
-        let result = client.update_issue("ENG-123".to_string(), input).await;
+        let updates = client.list_project_updates("proj-123", 10).await.unwrap();
 
         mock.assert();
-        assert!(result.is_ok());
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].id, "update-2");
+        assert_eq!(updates[0].health, "atRisk");
+        assert_eq!(updates[1].author, "Alice Smith");
     }
 
     #[tokio::test]
-    async fn test_update_issue_failure_response() {
+    async fn test_create_project_update_sends_health() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "CreateProjectUpdate",
+                "variables": { "input": {
+                    "projectId": "proj-123",
+                    "body": "All good.",
+                    "health": "offTrack"
+                } }
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_update_issue_failure_response().to_string())
+            .with_body(
+                serde_json::json!({
+                    "data": { "projectUpdateCreate": { "success": true, "projectUpdate": {
+                        "id": "update-3",
+                        "body": "All good.",
+                        "health": "offTrack",
+                        "url": "https://linear.app/project/proj-123/updates#update-3",
+                        "createdAt": "2023-05-15T09:00:00Z",
+                        "updatedAt": "2023-05-15T09:00:00Z",
+                        "user": { "name": "Alice Smith" }
+                    } } }
+                })
+                .to_string(),
+            )
             .create();
 
         let client = LinearClient::builder()
@@ -3072,35 +6343,58 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = UpdateIssueInput {
-            title: Some("Updated Title".to_string()),
-            description: None,
-            assignee_id: None,
-            state_id: None,
-            priority: None,
-            label_ids: None,
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
+        let input = CreateProjectUpdateInput {
+            project_id: "proj-123".to_string(),
+            body: "All good.".to_string(),
+            health: Some(ProjectHealth::OffTrack),
         };
-
-        let result = client.update_issue("ENG-123".to_string(), input).await;
+        let update = client.create_project_update(input).await.unwrap();
 
         mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(error.to_string().contains("Issue update failed"));
+        assert_eq!(update.id, "update-3");
+        assert_eq!(update.health, "offTrack");
     }
 
     #[tokio::test]
-    async fn test_update_issue_not_found() {
+    async fn test_list_project_milestones_follows_pages() {
         let mut server = mock_linear_server().await;
-        let mock = server
+        let mut first_page = mock_project_milestones_response();
+        first_page["data"]["project"]["projectMilestones"]["pageInfo"] =
+            serde_json::json!({ "hasNextPage": true, "endCursor": "cursor-1" });
+        let second_page = serde_json::json!({
+            "data": { "project": { "projectMilestones": {
+                "nodes": [{
+                    "id": "milestone-0",
+                    "name": "Alpha",
+                    "description": null,
+                    "targetDate": null,
+                    "progress": 1.0,
+                    "status": "done",
+                    "sortOrder": 0.5
+                }],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            } } }
+        });
+        let second = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ListProjectMilestones",
+                "variables": { "after": "cursor-1" }
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_update_issue_not_found_response().to_string())
+            .with_body(second_page.to_string())
+            .expect(1)
+            .create();
+        let first = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "ListProjectMilestones" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(first_page.to_string())
+            .expect(1)
             .create();
 
         let client = LinearClient::builder()
@@ -3111,38 +6405,27 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = UpdateIssueInput {
-            title: Some("Updated Title".to_string()),
-            description: None,
-            assignee_id: None,
-            state_id: None,
-            priority: None,
-            label_ids: None,
-            project_id: None,
-            estimate: None,
-            cycle_id: None,
-        };
-
-        let result = client.update_issue("INVALID-123".to_string(), input).await;
+        let milestones = client.list_project_milestones("proj-123").await.unwrap();
 
-        mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        // The error handling correctly converts GraphQL "not found" to IssueNotFound error
-        assert!(error.to_string().contains("not found"));
+        first.assert();
+        second.assert();
+        let names: Vec<&str> = milestones.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["Alpha", "Beta", "GA"]);
     }
 
-    // CREATE COMMENT TESTS - Following TDD approach
-
     #[tokio::test]
-    async fn test_create_comment_success() {
+    async fn test_resolve_milestone_to_id() {
         let mut server = mock_linear_server().await;
         let mock = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ListProjectMilestones",
+                "variables": { "id": "proj-123" }
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_create_comment_success_response().to_string())
+            .with_body(mock_project_milestones_response().to_string())
+            .expect(3)
             .create();
 
         let client = LinearClient::builder()
@@ -3153,33 +6436,66 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateCommentInput {
-            body: "This is a test comment".to_string(),
-            issue_id: "issue-789".to_string(),
-        };
+        let milestones = client.list_project_milestones("proj-123").await.unwrap();
+        assert_eq!(milestones[0].name, "Beta");
+        assert_eq!(milestones[0].status, "done");
+        assert_eq!(milestones[1].target_date.as_deref(), Some("2023-09-01"));
 
-        let result = client.create_comment(input).await;
+        let id = client
+            .resolve_milestone_to_id("proj-123", "beta")
+            .await
+            .unwrap();
+        assert_eq!(id, "milestone-1");
+
+        let err = client
+            .resolve_milestone_to_id("proj-123", "RC")
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Available milestones: Beta, GA"));
 
+        let uuid = "123e4567-e89b-12d3-a456-426614174000";
+        let id = client
+            .resolve_milestone_to_id("proj-123", uuid)
+            .await
+            .unwrap();
+        assert_eq!(id, uuid);
         mock.assert();
-        assert!(result.is_ok());
-        let comment = result.unwrap();
-        assert_eq!(comment.id, "comment-123");
-        assert_eq!(comment.body, "This is a test comment");
-        assert_eq!(comment.user.name, "Test User");
-        assert_eq!(comment.user.email, "test@example.com");
-        assert_eq!(comment.issue.identifier, "ENG-123");
-        assert_eq!(comment.issue.title, "Test Issue");
     }
 
     #[tokio::test]
-    async fn test_create_comment_failure_response() {
+    async fn test_update_project_milestone_clears_target_date() {
         let mut server = mock_linear_server().await;
-        let mock = server
+        let clear = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ClearProjectMilestoneTargetDate",
+                "variables": { "id": "milestone-1" }
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_create_comment_failure_response().to_string())
+            .with_body(r#"{"data":{"projectMilestoneUpdate":{"success":true}}}"#)
+            .create();
+        let update = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "UpdateProjectMilestone",
+                "variables": { "id": "milestone-1", "input": { "name": "Beta 2" } }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": { "projectMilestoneUpdate": { "success": true, "projectMilestone": {
+                        "id": "milestone-1",
+                        "name": "Beta 2",
+                        "description": null,
+                        "targetDate": null,
+                        "progress": 0.25,
+                        "status": "overdue"
+                    } } }
+                })
+                .to_string(),
+            )
             .create();
 
         let client = LinearClient::builder()
@@ -3190,30 +6506,63 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateCommentInput {
-            body: "Test comment".to_string(),
-            issue_id: "issue-789".to_string(),
+        let input = UpdateProjectMilestoneInput {
+            name: Some("Beta 2".to_string()),
+            target_date: Some(String::new()),
+            ..Default::default()
         };
+        let milestone = client
+            .update_project_milestone("milestone-1", input)
+            .await
+            .unwrap();
 
-        let result = client.create_comment(input).await;
-
-        mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        // When the comment field is null, the GraphQL client can't parse the response
-        // so we get a network/parsing error instead of the expected business logic error
-        assert!(error.to_string().contains("error"));
+        clear.assert();
+        update.assert();
+        assert_eq!(milestone.name, "Beta 2");
+        assert_eq!(milestone.status, "overdue");
+        assert!(milestone.target_date.is_none());
     }
 
     #[tokio::test]
-    async fn test_create_comment_graphql_error() {
+    async fn test_update_project_clears_target_date_and_lead() {
         let mut server = mock_linear_server().await;
-        let mock = server
+        let clear_target_date = server
             .mock("POST", "/graphql")
-            .match_header("authorization", "test_api_key")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "ClearProjectTargetDate",
+                "variables": { "id": "proj-123" }
+            })))
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(mock_graphql_error_response().to_string())
+            .with_body(r#"{"data":{"projectUpdate":{"success":true}}}"#)
+            .create();
+        let clear_lead = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({ "operationName": "ClearProjectLead" }),
+            ))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"data":{"projectUpdate":{"success":true}}}"#)
+            .create();
+        let update = server
+            .mock("POST", "/graphql")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "operationName": "UpdateProject",
+                "variables": { "input": { "name": "Mobile" } }
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "data": { "projectUpdate": { "success": true, "project": {
+                        "id": "proj-123",
+                        "name": "Mobile",
+                        "url": "https://linear.app/project/proj-123"
+                    } } }
+                })
+                .to_string(),
+            )
             .create();
 
         let client = LinearClient::builder()
@@ -3224,21 +6573,20 @@ mod tests {
             .build()
             .unwrap();
 
-        let input = CreateCommentInput {
-            body: "Test comment".to_string(),
-            issue_id: "invalid-issue".to_string(),
+        let input = UpdateProjectInput {
+            name: Some("Mobile".to_string()),
+            lead_id: Some(String::new()),
+            target_date: Some(String::new()),
+            ..Default::default()
         };
+        let project = client.update_project("proj-123", input).await.unwrap();
 
-        let result = client.create_comment(input).await;
-
-        mock.assert();
-        assert!(result.is_err());
-        let error = result.unwrap_err();
-        assert!(error.to_string().contains("GraphQL error"));
+        clear_target_date.assert();
+        clear_lead.assert();
+        update.assert();
+        assert_eq!(project.name, "Mobile");
     }
 
-    // TEAM STATES TESTS - Following TDD approach for state resolution
-
     #[tokio::test]
     async fn test_get_team_states_success() {
         let mut server = mock_linear_server().await;
@@ -0,0 +1,193 @@
+// ABOUTME: Client-side request budget: a semaphore capping in-flight requests and a token bucket
+// ABOUTME: capping requests per hour, plus running totals of the query complexity spent
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+/// Limits applied before a request is sent; `None` leaves that dimension unlimited
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LimiterConfig {
+    /// Most requests allowed in flight at once
+    pub max_concurrent_requests: Option<usize>,
+    /// Sustained request rate; bursts of up to a minute's worth are allowed
+    pub requests_per_hour: Option<u32>,
+}
+
+/// Requests sent and complexity points spent through one client
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RequestUsage {
+    pub requests: u64,
+    pub complexity: u64,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    per_second: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(requests_per_hour: u32) -> Self {
+        let capacity = (f64::from(requests_per_hour) / 60.0).max(1.0);
+        Self {
+            capacity,
+            tokens: capacity,
+            per_second: f64::from(requests_per_hour) / SECONDS_PER_HOUR,
+            refilled_at: Instant::now(),
+        }
+    }
+
+    /// Take a token, or report how long until one is available
+    fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now.duration_since(self.refilled_at).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.capacity);
+        self.refilled_at = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - self.tokens) / self.per_second,
+            ))
+        }
+    }
+}
+
+/// Gate every request must pass before it is sent
+#[derive(Debug, Default)]
+pub struct RequestLimiter {
+    concurrency: Option<Arc<Semaphore>>,
+    bucket: Option<Mutex<TokenBucket>>,
+    requests: AtomicU64,
+    complexity: AtomicU64,
+}
+
+/// Held for the duration of a request; releases its concurrency slot when dropped
+#[derive(Debug)]
+pub struct RequestPermit {
+    _slot: Option<OwnedSemaphorePermit>,
+}
+
+impl RequestLimiter {
+    pub fn new(config: LimiterConfig) -> Self {
+        Self {
+            concurrency: config
+                .max_concurrent_requests
+                .map(|max| Arc::new(Semaphore::new(max.max(1)))),
+            bucket: config
+                .requests_per_hour
+                .filter(|&rate| rate > 0)
+                .map(|rate| Mutex::new(TokenBucket::new(rate))),
+            ..Default::default()
+        }
+    }
+
+    /// Wait for a concurrency slot and a request token
+    pub async fn acquire(&self, verbose: bool) -> RequestPermit {
+        let slot = match &self.concurrency {
+            Some(semaphore) => semaphore.clone().acquire_owned().await.ok(),
+            None => None,
+        };
+
+        if let Some(bucket) = &self.bucket {
+            loop {
+                let wait = match bucket.lock().await.try_take(Instant::now()) {
+                    Ok(()) => break,
+                    Err(wait) => wait,
+                };
+                if verbose {
+                    log::debug!("Client request budget spent; waiting {wait:?} for the next slot");
+                }
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        RequestPermit { _slot: slot }
+    }
+
+    /// Add the complexity Linear charged for a request
+    pub fn record_complexity(&self, complexity: u64) {
+        self.complexity.fetch_add(complexity, Ordering::Relaxed);
+    }
+
+    pub fn usage(&self) -> RequestUsage {
+        RequestUsage {
+            requests: self.requests.load(Ordering::Relaxed),
+            complexity: self.complexity.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_bucket_refills_at_hourly_rate() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(3600);
+        assert_eq!(bucket.capacity, 60.0);
+
+        for _ in 0..60 {
+            assert!(bucket.try_take(start).is_ok());
+        }
+        let wait = bucket.try_take(start).unwrap_err();
+        assert!(wait <= Duration::from_secs(1));
+
+        assert!(bucket.try_take(start + Duration::from_secs(1)).is_ok());
+    }
+
+    #[test]
+    fn test_token_bucket_allows_at_least_one_request() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(10);
+
+        assert!(bucket.try_take(start).is_ok());
+        let wait = bucket.try_take(start).unwrap_err();
+        assert_eq!(wait.as_secs(), 360);
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_is_capped() {
+        let limiter = Arc::new(RequestLimiter::new(LimiterConfig {
+            max_concurrent_requests: Some(2),
+            requests_per_hour: None,
+        }));
+
+        let first = limiter.acquire(false).await;
+        let _second = limiter.acquire(false).await;
+        let third = tokio::time::timeout(Duration::from_millis(50), limiter.acquire(false)).await;
+        assert!(third.is_err());
+
+        drop(first);
+        let third = tokio::time::timeout(Duration::from_millis(50), limiter.acquire(false)).await;
+        assert!(third.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_usage_counts_requests_and_complexity() {
+        let limiter = RequestLimiter::new(LimiterConfig::default());
+
+        drop(limiter.acquire(false).await);
+        drop(limiter.acquire(false).await);
+        limiter.record_complexity(40);
+        limiter.record_complexity(2);
+
+        assert_eq!(
+            limiter.usage(),
+            RequestUsage {
+                requests: 2,
+                complexity: 42
+            }
+        );
+    }
+}
@@ -0,0 +1,431 @@
+// ABOUTME: Cursor-based pagination helpers for Linear connection queries
+// ABOUTME: Walks pageInfo cursors for typed and raw queries until a limit or the final page
+
+use crate::constants::pagination::MAX_PAGE_SIZE;
+use crate::{LinearError, Result};
+use futures::stream::{self, Stream, TryStreamExt};
+use serde_json::{Map, Value};
+use std::future::Future;
+use std::pin::Pin;
+
+/// How many items a paginated list call should return
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageLimit {
+    /// Stop once this many items have been collected
+    Count(usize),
+    /// Keep fetching until the API reports no further pages
+    All,
+}
+
+impl PageLimit {
+    /// Number of items still wanted after `collected` items have been gathered
+    fn remaining(&self, collected: usize) -> Option<usize> {
+        match self {
+            PageLimit::Count(limit) => Some(limit.saturating_sub(collected)),
+            PageLimit::All => None,
+        }
+    }
+
+    /// Page size to request next, capped at the API maximum
+    fn page_size(&self, collected: usize) -> i64 {
+        let size = self
+            .remaining(collected)
+            .map_or(MAX_PAGE_SIZE, |remaining| remaining.min(MAX_PAGE_SIZE));
+        size as i64
+    }
+}
+
+impl From<i32> for PageLimit {
+    fn from(limit: i32) -> Self {
+        PageLimit::Count(limit.max(0) as usize)
+    }
+}
+
+impl From<usize> for PageLimit {
+    fn from(limit: usize) -> Self {
+        PageLimit::Count(limit)
+    }
+}
+
+/// Cursor state returned alongside each page of a connection
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    pub end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    /// Whether another page can be requested after a page of `fetched` nodes.
+    /// A page without a cursor or without nodes cannot advance, so treat it as the last one.
+    fn can_advance(&self, fetched: usize) -> bool {
+        self.has_next_page && self.end_cursor.is_some() && fetched > 0
+    }
+}
+
+/// Accumulates nodes across pages of a single connection
+#[derive(Debug)]
+pub(crate) struct PageCollector<T> {
+    limit: PageLimit,
+    items: Vec<T>,
+    cursor: Option<String>,
+    done: bool,
+}
+
+impl<T> PageCollector<T> {
+    pub(crate) fn new(limit: PageLimit) -> Self {
+        Self {
+            limit,
+            items: Vec::new(),
+            cursor: None,
+            done: limit.remaining(0) == Some(0),
+        }
+    }
+
+    pub(crate) fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub(crate) fn cursor(&self) -> Option<String> {
+        self.cursor.clone()
+    }
+
+    pub(crate) fn page_size(&self) -> i64 {
+        self.limit.page_size(self.items.len())
+    }
+
+    /// Record a fetched page and decide whether another page is needed
+    pub(crate) fn push_page(&mut self, nodes: Vec<T>, page_info: PageInfo) {
+        if self.done {
+            return;
+        }
+
+        let fetched = nodes.len();
+        self.items.extend(nodes);
+
+        if let PageLimit::Count(limit) = self.limit {
+            self.items.truncate(limit);
+        }
+
+        let limit_reached = self.limit.remaining(self.items.len()) == Some(0);
+        self.done = limit_reached || !page_info.can_advance(fetched);
+        self.cursor = page_info.end_cursor;
+    }
+
+    pub(crate) fn into_items(self) -> Vec<T> {
+        self.items
+    }
+}
+
+/// Fetch pages with `fetch(first, after)` until `limit` items are collected or pages run out
+pub(crate) async fn collect_pages<T, F, Fut>(limit: PageLimit, mut fetch: F) -> Result<Vec<T>>
+where
+    F: FnMut(i64, Option<String>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, PageInfo)>>,
+{
+    let mut collector = PageCollector::new(limit);
+
+    while !collector.is_done() {
+        let (nodes, page_info) = fetch(collector.page_size(), collector.cursor()).await?;
+        collector.push_page(nodes, page_info);
+    }
+
+    Ok(collector.into_items())
+}
+
+/// Lazily paginated stream of items from a Linear connection
+pub type PageStream<'a, T> = Pin<Box<dyn Stream<Item = Result<T>> + Send + 'a>>;
+
+/// Stream items page by page, requesting the next page only once the current one is consumed
+pub(crate) fn stream_pages<'a, T, F, Fut>(fetch: F) -> PageStream<'a, T>
+where
+    T: Send + 'a,
+    F: FnMut(i64, Option<String>) -> Fut + Send + 'a,
+    Fut: Future<Output = Result<(Vec<T>, PageInfo)>> + Send + 'a,
+{
+    let pages = stream::try_unfold(
+        (fetch, None::<String>, false),
+        |(mut fetch, cursor, done)| async move {
+            if done {
+                return Ok::<_, LinearError>(None);
+            }
+
+            let (nodes, page_info) = fetch(MAX_PAGE_SIZE as i64, cursor).await?;
+            let done = !page_info.can_advance(nodes.len());
+
+            Ok(Some((nodes, (fetch, page_info.end_cursor, done))))
+        },
+    );
+
+    Box::pin(
+        pages
+            .map_ok(|nodes| stream::iter(nodes.into_iter().map(Ok)))
+            .try_flatten(),
+    )
+}
+
+fn is_connection(value: &Value) -> bool {
+    value
+        .as_object()
+        .is_some_and(|map| map.contains_key("nodes") && map.contains_key("pageInfo"))
+}
+
+/// First connection (an object selecting both `nodes` and `pageInfo`) in a raw response
+fn find_connection(value: &Value) -> Option<&Map<String, Value>> {
+    if is_connection(value) {
+        return value.as_object();
+    }
+    match value {
+        Value::Object(map) => map.values().find_map(find_connection),
+        Value::Array(items) => items.iter().find_map(find_connection),
+        _ => None,
+    }
+}
+
+fn find_connection_mut(value: &mut Value) -> Option<&mut Map<String, Value>> {
+    if is_connection(value) {
+        return value.as_object_mut();
+    }
+    match value {
+        Value::Object(map) => map.values_mut().find_map(find_connection_mut),
+        Value::Array(items) => items.iter_mut().find_map(find_connection_mut),
+        _ => None,
+    }
+}
+
+/// Cursor for the page after a raw response, or `None` when it was the last page
+pub(crate) fn raw_next_cursor(data: &Value) -> Result<Option<String>> {
+    let connection = find_connection(data).ok_or_else(|| LinearError::InvalidInput {
+        message: "Paginated queries must select `nodes` and `pageInfo { hasNextPage endCursor }`"
+            .to_string(),
+    })?;
+
+    let page_info = &connection["pageInfo"];
+    let page_info = PageInfo {
+        has_next_page: page_info["hasNextPage"].as_bool().unwrap_or(false),
+        end_cursor: page_info["endCursor"].as_str().map(str::to_string),
+    };
+    let fetched = connection["nodes"].as_array().map_or(0, Vec::len);
+
+    Ok(page_info
+        .can_advance(fetched)
+        .then_some(page_info.end_cursor)
+        .flatten())
+}
+
+/// Append a later raw page's `nodes` to the first page and take over its `pageInfo`
+pub(crate) fn merge_raw_page(merged: &mut Value, mut page: Value) {
+    let (Some(target), Some(source)) =
+        (find_connection_mut(merged), find_connection_mut(&mut page))
+    else {
+        return;
+    };
+
+    if let (Some(nodes), Value::Array(more)) = (
+        target.get_mut("nodes").and_then(Value::as_array_mut),
+        source["nodes"].take(),
+    ) {
+        nodes.extend(more);
+    }
+    target.insert("pageInfo".to_string(), source["pageInfo"].take());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::sync::{Arc, Mutex};
+
+    fn page(start: usize, len: usize, has_next_page: bool) -> (Vec<usize>, PageInfo) {
+        let nodes: Vec<usize> = (start..start + len).collect();
+        let page_info = PageInfo {
+            has_next_page,
+            end_cursor: Some(format!("cursor-{}", start + len)),
+        };
+        (nodes, page_info)
+    }
+
+    #[test]
+    fn test_page_limit_from_i32() {
+        assert_eq!(PageLimit::from(20), PageLimit::Count(20));
+        assert_eq!(PageLimit::from(-5), PageLimit::Count(0));
+    }
+
+    #[test]
+    fn test_page_size_is_capped() {
+        assert_eq!(PageLimit::Count(20).page_size(0), 20);
+        assert_eq!(PageLimit::Count(600).page_size(0), MAX_PAGE_SIZE as i64);
+        assert_eq!(PageLimit::Count(600).page_size(500), 100);
+        assert_eq!(PageLimit::All.page_size(1000), MAX_PAGE_SIZE as i64);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_stops_at_limit() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let requests_clone = requests.clone();
+
+        let items = collect_pages(PageLimit::Count(300), |first, after| {
+            requests_clone.lock().unwrap().push((first, after.clone()));
+            let start = after.map_or(0, |c| c.trim_start_matches("cursor-").parse().unwrap());
+            async move { Ok(page(start, first as usize, true)) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items.len(), 300);
+        assert_eq!(items[299], 299);
+        let requests = requests.lock().unwrap();
+        assert_eq!(
+            *requests,
+            vec![(250, None), (50, Some("cursor-250".to_string()))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_all_until_last_page() {
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+
+        let items = collect_pages(PageLimit::All, |_first, after| {
+            *calls_clone.lock().unwrap() += 1;
+            let result = match after.as_deref() {
+                None => page(0, 2, true),
+                Some("cursor-2") => page(2, 2, true),
+                _ => page(4, 1, false),
+            };
+            async move { Ok(result) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![0, 1, 2, 3, 4]);
+        assert_eq!(*calls.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_zero_limit_skips_requests() {
+        let items: Vec<usize> = collect_pages(PageLimit::Count(0), |_, _| async {
+            panic!("no request expected for a zero limit")
+        })
+        .await
+        .unwrap();
+
+        assert!(items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_stops_without_cursor() {
+        let items = collect_pages(PageLimit::All, |_, _| async {
+            Ok((
+                vec![1, 2],
+                PageInfo {
+                    has_next_page: true,
+                    end_cursor: None,
+                },
+            ))
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_propagates_errors() {
+        let result: Result<Vec<usize>> = collect_pages(PageLimit::All, |_, _| async {
+            Err(crate::LinearError::InvalidResponse)
+        })
+        .await;
+
+        assert!(matches!(result, Err(crate::LinearError::InvalidResponse)));
+    }
+
+    #[tokio::test]
+    async fn test_stream_pages_yields_every_page() {
+        let items: Vec<usize> = stream_pages(|first, after| {
+            assert_eq!(first, MAX_PAGE_SIZE as i64);
+            let result = match after.as_deref() {
+                None => page(0, 2, true),
+                _ => page(2, 2, false),
+            };
+            async move { Ok(result) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(items, vec![0, 1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_stream_pages_fetches_lazily() {
+        let calls = Arc::new(Mutex::new(0));
+        let calls_clone = calls.clone();
+
+        let mut stream = stream_pages(move |_first, after| {
+            *calls_clone.lock().unwrap() += 1;
+            let start = after.map_or(0, |c| c.trim_start_matches("cursor-").parse().unwrap());
+            async move { Ok(page(start, 2, true)) }
+        });
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 0);
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert_eq!(*calls.lock().unwrap(), 1);
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+        assert_eq!(*calls.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_stream_pages_ends_after_error() {
+        let mut stream = stream_pages(|_, _| async {
+            Err::<(Vec<usize>, PageInfo), _>(crate::LinearError::InvalidResponse)
+        });
+
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(crate::LinearError::InvalidResponse))
+        ));
+        assert!(stream.next().await.is_none());
+    }
+
+    fn raw_page(ids: &[&str], has_next_page: bool, end_cursor: &str) -> Value {
+        let nodes: Vec<Value> = ids
+            .iter()
+            .map(|id| serde_json::json!({ "id": id }))
+            .collect();
+        serde_json::json!({
+            "team": {
+                "issues": {
+                    "nodes": nodes,
+                    "pageInfo": { "hasNextPage": has_next_page, "endCursor": end_cursor }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_raw_next_cursor() {
+        assert_eq!(
+            raw_next_cursor(&raw_page(&["a"], true, "c1")).unwrap(),
+            Some("c1".to_string())
+        );
+        assert_eq!(
+            raw_next_cursor(&raw_page(&["a"], false, "c1")).unwrap(),
+            None
+        );
+        assert_eq!(raw_next_cursor(&raw_page(&[], true, "c1")).unwrap(), None);
+        assert!(raw_next_cursor(&serde_json::json!({ "viewer": { "id": "u" } })).is_err());
+    }
+
+    #[test]
+    fn test_merge_raw_page() {
+        let mut merged = raw_page(&["a", "b"], true, "c2");
+        merge_raw_page(&mut merged, raw_page(&["c"], false, "c3"));
+
+        let issues = &merged["team"]["issues"];
+        assert_eq!(issues["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(issues["nodes"][2]["id"], "c");
+        assert_eq!(issues["pageInfo"]["hasNextPage"], false);
+        assert_eq!(issues["pageInfo"]["endCursor"], "c3");
+    }
+}
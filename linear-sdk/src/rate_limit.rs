@@ -0,0 +1,173 @@
+// ABOUTME: Parses Linear's rate-limit response headers into the client's current API budget
+// ABOUTME: Works out how long to wait after a rate-limited response from Retry-After or resets
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const REQUESTS_LIMIT: &str = "x-ratelimit-requests-limit";
+const REQUESTS_REMAINING: &str = "x-ratelimit-requests-remaining";
+const REQUESTS_RESET: &str = "x-ratelimit-requests-reset";
+const COMPLEXITY: &str = "x-complexity";
+const COMPLEXITY_LIMIT: &str = "x-ratelimit-complexity-limit";
+const COMPLEXITY_REMAINING: &str = "x-ratelimit-complexity-remaining";
+const COMPLEXITY_RESET: &str = "x-ratelimit-complexity-reset";
+
+/// Rate-limit budget reported by the most recent API response
+///
+/// Linear limits both the number of requests and the summed query complexity per hour; each
+/// field is `None` when the response didn't carry the matching header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub requests_limit: Option<u64>,
+    pub requests_remaining: Option<u64>,
+    pub requests_reset: Option<SystemTime>,
+    /// Complexity points charged for the request that produced this status
+    pub complexity: Option<u64>,
+    pub complexity_limit: Option<u64>,
+    pub complexity_remaining: Option<u64>,
+    pub complexity_reset: Option<SystemTime>,
+    /// Server-requested wait from a `Retry-After` header
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitStatus {
+    /// Read the rate-limit headers of a response
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            requests_limit: number(headers, REQUESTS_LIMIT),
+            requests_remaining: number(headers, REQUESTS_REMAINING),
+            requests_reset: number(headers, REQUESTS_RESET).map(epoch_millis),
+            complexity: number(headers, COMPLEXITY),
+            complexity_limit: number(headers, COMPLEXITY_LIMIT),
+            complexity_remaining: number(headers, COMPLEXITY_REMAINING),
+            complexity_reset: number(headers, COMPLEXITY_RESET).map(epoch_millis),
+            retry_after: headers
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| parse_retry_after(value, SystemTime::now())),
+        }
+    }
+
+    /// Whether any rate-limit header was present
+    pub fn is_known(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// How long to wait before the API accepts requests again: `Retry-After` when given,
+    /// otherwise the reset time of whichever budget is exhausted
+    pub fn wait_time(&self, now: SystemTime) -> Option<Duration> {
+        if let Some(retry_after) = self.retry_after {
+            return Some(retry_after);
+        }
+
+        let until = |reset: Option<SystemTime>| reset.map(|reset| reset.duration_since(now));
+        let exhausted = [
+            (self.requests_remaining, until(self.requests_reset)),
+            (self.complexity_remaining, until(self.complexity_reset)),
+        ];
+
+        exhausted
+            .into_iter()
+            .filter(|(remaining, _)| *remaining == Some(0))
+            .filter_map(|(_, wait)| wait)
+            .map(|wait| wait.unwrap_or_default())
+            .max()
+    }
+}
+
+fn number(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+fn epoch_millis(millis: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(millis)
+}
+
+/// `Retry-After` is either a number of seconds or an HTTP date
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(date.duration_since(now).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_from_headers() {
+        let status = RateLimitStatus::from_headers(&headers(&[
+            ("X-RateLimit-Requests-Limit", "1500"),
+            ("X-RateLimit-Requests-Remaining", "1499"),
+            ("X-RateLimit-Requests-Reset", "1790000000000"),
+            ("X-Complexity", "52"),
+            ("X-RateLimit-Complexity-Remaining", "249948"),
+        ]));
+
+        assert_eq!(status.requests_limit, Some(1500));
+        assert_eq!(status.requests_remaining, Some(1499));
+        assert_eq!(status.requests_reset, Some(epoch_millis(1_790_000_000_000)));
+        assert_eq!(status.complexity, Some(52));
+        assert_eq!(status.complexity_remaining, Some(249_948));
+        assert_eq!(status.complexity_limit, None);
+        assert!(status.is_known());
+        assert!(!RateLimitStatus::from_headers(&HeaderMap::new()).is_known());
+    }
+
+    #[test]
+    fn test_wait_time_prefers_retry_after() {
+        let status = RateLimitStatus::from_headers(&headers(&[
+            ("Retry-After", "7"),
+            ("X-RateLimit-Requests-Remaining", "0"),
+            ("X-RateLimit-Requests-Reset", "1790000000000"),
+        ]));
+
+        assert_eq!(
+            status.wait_time(SystemTime::now()),
+            Some(Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn test_wait_time_until_exhausted_budget_resets() {
+        let now = epoch_millis(1_790_000_000_000);
+        let status = RateLimitStatus {
+            requests_remaining: Some(0),
+            requests_reset: Some(now + Duration::from_secs(30)),
+            complexity_remaining: Some(10),
+            complexity_reset: Some(now + Duration::from_secs(90)),
+            ..Default::default()
+        };
+        assert_eq!(status.wait_time(now), Some(Duration::from_secs(30)));
+
+        let status = RateLimitStatus {
+            requests_remaining: Some(5),
+            requests_reset: Some(now + Duration::from_secs(30)),
+            ..Default::default()
+        };
+        assert_eq!(status.wait_time(now), None);
+    }
+
+    #[test]
+    fn test_parse_retry_after_http_date() {
+        let now = httpdate::parse_http_date("Wed, 21 Oct 2026 07:28:00 GMT").unwrap();
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2026 07:28:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
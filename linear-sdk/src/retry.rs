@@ -1,5 +1,5 @@
-// ABOUTME: Retry logic with exponential backoff for handling transient failures
-// ABOUTME: Implements retry mechanism for network errors and rate limits
+// ABOUTME: Retry logic with jittered exponential backoff for handling transient failures
+// ABOUTME: Implements retry mechanism for network errors and waits out rate-limit resets
 
 use crate::constants::retry;
 use crate::error::LinearError;
@@ -13,6 +13,10 @@ pub struct RetryConfig {
     pub initial_delay: Duration,
     pub max_delay: Duration,
     pub backoff_multiplier: f64,
+    /// Longest rate-limit reset to sleep through; rate limits resetting later fail immediately
+    pub max_rate_limit_wait: Duration,
+    /// Rate-limit waits allowed before giving up; these don't use up `max_attempts`
+    pub max_rate_limit_retries: u32,
 }
 
 impl Default for RetryConfig {
@@ -22,10 +26,24 @@ impl Default for RetryConfig {
             initial_delay: retry::INITIAL_DELAY,
             max_delay: retry::MAX_DELAY,
             backoff_multiplier: retry::BACKOFF_MULTIPLIER,
+            max_rate_limit_wait: retry::MAX_RATE_LIMIT_WAIT,
+            max_rate_limit_retries: retry::MAX_RATE_LIMIT_RETRIES,
         }
     }
 }
 
+/// Pick a delay uniformly between zero and `ceiling` ("full jitter"), so clients that failed
+/// together don't retry in lockstep
+fn full_jitter(ceiling: Duration) -> Duration {
+    Duration::from_millis(fastrand::u64(0..=ceiling.as_millis() as u64))
+}
+
+/// Run `operation` until it succeeds, fails with a non-retryable error, or runs out of attempts.
+///
+/// Rate-limit errors that carry a reset time wait until that reset (up to
+/// `max_rate_limit_wait`), and those without one wait at least `max_delay`; these waits are
+/// capped by `max_rate_limit_retries` rather than `max_attempts`. Every other retryable error
+/// waits a jittered exponential backoff.
 pub async fn retry_with_backoff<F, Fut, T>(
     config: &RetryConfig,
     verbose: bool,
@@ -35,41 +53,59 @@ where
     F: FnMut() -> Fut,
     Fut: std::future::Future<Output = Result<T, LinearError>>,
 {
-    let mut delay = config.initial_delay;
-
-    for attempt in 0..config.max_attempts {
-        if attempt > 0 {
-            if verbose {
-                log::debug!(
-                    "Retrying operation (attempt {}/{})",
-                    attempt + 1,
-                    config.max_attempts
-                );
-            }
-            sleep(delay).await;
-            delay = std::cmp::min(
-                Duration::from_millis(
-                    (delay.as_millis() as f64 * config.backoff_multiplier) as u64,
-                ),
-                config.max_delay,
-            );
-        }
+    let mut backoff = config.initial_delay;
+    let mut attempt = 1;
+    let mut rate_limit_waits = 0;
 
-        match operation().await {
+    loop {
+        let error = match operation().await {
             Ok(result) => return Ok(result),
-            Err(error) => {
-                if !error.is_retryable() || attempt == config.max_attempts - 1 {
+            Err(error) => error,
+        };
+
+        if !error.is_retryable() {
+            return Err(error);
+        }
+
+        let delay = match error {
+            LinearError::RateLimit { reset_seconds } => {
+                if rate_limit_waits >= config.max_rate_limit_retries {
                     return Err(error);
                 }
-
-                if verbose {
-                    log::debug!("Request failed (retryable): {error}");
+                rate_limit_waits += 1;
+                if reset_seconds > 0 {
+                    let reset = Duration::from_secs(reset_seconds);
+                    if reset > config.max_rate_limit_wait {
+                        return Err(error);
+                    }
+                    reset
+                } else {
+                    // No reset time to go on, so back off at least the longest delay
+                    config.max_delay + full_jitter(backoff)
                 }
             }
+            _ => {
+                if attempt >= config.max_attempts {
+                    return Err(error);
+                }
+                attempt += 1;
+                let delay = full_jitter(backoff);
+                backoff =
+                    std::cmp::min(backoff.mul_f64(config.backoff_multiplier), config.max_delay);
+                delay
+            }
+        };
+
+        if verbose {
+            log::debug!("Request failed (retryable): {error}");
+            log::debug!(
+                "Retrying operation in {delay:?} (attempt {attempt}/{}, rate-limit wait {rate_limit_waits}/{})",
+                config.max_attempts,
+                config.max_rate_limit_retries
+            );
         }
+        sleep(delay).await;
     }
-
-    unreachable!()
 }
 
 #[cfg(test)]
@@ -184,4 +220,156 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(*call_count.lock().unwrap(), 3); // Initial attempt + 2 retries
     }
+
+    #[test]
+    fn test_full_jitter_stays_within_ceiling() {
+        let ceiling = Duration::from_millis(50);
+        for _ in 0..100 {
+            assert!(full_jitter(ceiling) <= ceiling);
+        }
+        assert_eq!(full_jitter(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_waits_until_reset() {
+        let config = RetryConfig::default();
+        let call_count = Arc::new(Mutex::new(0));
+        let call_count_clone = call_count.clone();
+        let start = std::time::Instant::now();
+
+        let result = retry_with_backoff(&config, false, || {
+            let count = call_count_clone.clone();
+            async move {
+                let mut c = count.lock().unwrap();
+                *c += 1;
+                if *c == 1 {
+                    Err(LinearError::RateLimit { reset_seconds: 1 })
+                } else {
+                    Ok::<i32, LinearError>(42)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(*call_count.lock().unwrap(), 2);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_without_reset_waits_max_delay() {
+        let config = RetryConfig {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(300),
+            ..Default::default()
+        };
+        let call_count = Arc::new(Mutex::new(0));
+        let call_count_clone = call_count.clone();
+        let start = std::time::Instant::now();
+
+        let result = retry_with_backoff(&config, false, || {
+            let count = call_count_clone.clone();
+            async move {
+                let mut c = count.lock().unwrap();
+                *c += 1;
+                if *c == 1 {
+                    Err(LinearError::RateLimit { reset_seconds: 0 })
+                } else {
+                    Ok::<i32, LinearError>(42)
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(*call_count.lock().unwrap(), 2);
+        assert!(start.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_waits_do_not_use_up_attempts() {
+        let config = RetryConfig {
+            max_attempts: 2,
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            ..Default::default()
+        };
+        let call_count = Arc::new(Mutex::new(0));
+        let call_count_clone = call_count.clone();
+
+        // Three rate limits and a network error still succeed with only two attempts
+        let result = retry_with_backoff(&config, false, || {
+            let count = call_count_clone.clone();
+            async move {
+                let mut c = count.lock().unwrap();
+                *c += 1;
+                match *c {
+                    1..=3 => Err(LinearError::RateLimit { reset_seconds: 0 }),
+                    4 => Err(LinearError::Network {
+                        message: "Temporary failure".to_string(),
+                        retryable: true,
+                        source: Box::new(std::io::Error::other("Temporary failure")),
+                    }),
+                    _ => Ok::<i32, LinearError>(42),
+                }
+            }
+        })
+        .await;
+
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(*call_count.lock().unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_retries_are_capped() {
+        let config = RetryConfig {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            max_rate_limit_retries: 2,
+            ..Default::default()
+        };
+        let call_count = Arc::new(Mutex::new(0));
+        let call_count_clone = call_count.clone();
+
+        let result = retry_with_backoff(&config, false, || {
+            let count = call_count_clone.clone();
+            async move {
+                *count.lock().unwrap() += 1;
+                Err::<i32, LinearError>(LinearError::RateLimit { reset_seconds: 0 })
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(LinearError::RateLimit { .. })));
+        assert_eq!(*call_count.lock().unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_reset_beyond_max_wait_fails_fast() {
+        let config = RetryConfig {
+            max_rate_limit_wait: Duration::from_secs(10),
+            ..Default::default()
+        };
+        let call_count = Arc::new(Mutex::new(0));
+        let call_count_clone = call_count.clone();
+
+        let result = retry_with_backoff(&config, false, || {
+            let count = call_count_clone.clone();
+            async move {
+                *count.lock().unwrap() += 1;
+                Err::<i32, LinearError>(LinearError::RateLimit {
+                    reset_seconds: 3600,
+                })
+            }
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(LinearError::RateLimit {
+                reset_seconds: 3600
+            })
+        ));
+        assert_eq!(*call_count.lock().unwrap(), 1);
+    }
 }
@@ -45,7 +45,12 @@ pub fn mock_issues_response() -> serde_json::Value {
                         "team": {
                             "id": "team-123",
                             "key": "ENG"
-                        }
+                        },
+                        "priority": 2,
+                        "priorityLabel": "High",
+                        "createdAt": "2026-01-01T10:00:00.000Z",
+                        "updatedAt": "2026-02-01T10:00:00.000Z",
+                        "labels": { "nodes": [] }
                     },
                     {
                         "id": "issue-2",
@@ -62,7 +67,12 @@ pub fn mock_issues_response() -> serde_json::Value {
                         "team": {
                             "id": "team-456",
                             "key": "DESIGN"
-                        }
+                        },
+                        "priority": 3,
+                        "priorityLabel": "Medium",
+                        "createdAt": "2026-01-02T10:00:00.000Z",
+                        "updatedAt": "2026-02-02T10:00:00.000Z",
+                        "labels": { "nodes": [] }
                     },
                     {
                         "id": "issue-3",
@@ -76,9 +86,18 @@ pub fn mock_issues_response() -> serde_json::Value {
                         "team": {
                             "id": "team-789",
                             "key": "QA"
-                        }
+                        },
+                        "priority": 0,
+                        "priorityLabel": "No priority",
+                        "createdAt": "2026-01-03T10:00:00.000Z",
+                        "updatedAt": "2026-02-03T10:00:00.000Z",
+                        "labels": { "nodes": [] }
                     }
-                ]
+                ],
+                "pageInfo": {
+                    "hasNextPage": false,
+                    "endCursor": "issue-3"
+                }
             }
         }
     })
@@ -89,7 +108,11 @@ pub fn mock_empty_issues_response() -> serde_json::Value {
     json!({
         "data": {
             "issues": {
-                "nodes": []
+                "nodes": [],
+                "pageInfo": {
+                    "hasNextPage": false,
+                    "endCursor": null
+                }
             }
         }
     })
@@ -153,8 +176,13 @@ pub fn mock_detailed_issue_response() -> serde_json::Value {
                     "name": "Engineering"
                 },
                 "project": {
+                    "id": "project-web-789",
                     "name": "Web App"
                 },
+                "projectMilestone": {
+                    "id": "milestone-beta-1",
+                    "name": "Beta"
+                },
                 "labels": {
                     "nodes": [
                         {
@@ -167,6 +195,34 @@ pub fn mock_detailed_issue_response() -> serde_json::Value {
                         }
                     ]
                 },
+                "parent": {
+                    "id": "issue-parent-100",
+                    "identifier": "ENG-100",
+                    "title": "Authentication hardening",
+                    "state": {
+                        "id": "state-progress-456",
+                        "name": "In Progress",
+                        "type": "started"
+                    },
+                    "assignee": null
+                },
+                "children": {
+                    "nodes": [
+                        {
+                            "id": "issue-child-124",
+                            "identifier": "ENG-124",
+                            "title": "Add session lock",
+                            "state": {
+                                "id": "state-todo-456",
+                                "name": "Todo",
+                                "type": "unstarted"
+                            },
+                            "assignee": {
+                                "name": "John Doe"
+                            }
+                        }
+                    ]
+                },
                 "priority": 2.0,
                 "priorityLabel": "High",
                 "createdAt": "2024-01-15T10:30:00Z",
@@ -201,6 +257,10 @@ pub fn mock_minimal_issue_response() -> serde_json::Value {
                 "labels": {
                     "nodes": []
                 },
+                "parent": null,
+                "children": {
+                    "nodes": []
+                },
                 "priority": 0.0,
                 "priorityLabel": "None",
                 "createdAt": "2024-01-01T00:00:00Z",
@@ -503,6 +563,116 @@ pub fn mock_team_states_response() -> serde_json::Value {
     })
 }
 
+#[cfg(test)]
+pub fn mock_cycles_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "team": {
+                "activeCycle": { "id": "cycle-42" },
+                "cycles": {
+                    "nodes": [
+                        {
+                            "id": "cycle-41",
+                            "number": 41.0,
+                            "name": null,
+                            "startsAt": "2026-09-21T00:00:00.000Z",
+                            "endsAt": "2026-10-05T00:00:00.000Z",
+                            "completedAt": "2026-10-05T00:00:00.000Z",
+                            "progress": 0.8,
+                            "scopeHistory": [10.0, 12.0, 15.0],
+                            "inProgressScopeHistory": [0.0, 4.0, 0.0],
+                            "completedScopeHistory": [0.0, 5.0, 12.0],
+                            "issueCountHistory": [5.0, 6.0, 7.0],
+                            "completedIssueCountHistory": [0.0, 3.0, 6.0]
+                        },
+                        {
+                            "id": "cycle-42",
+                            "number": 42.0,
+                            "name": "Launch prep",
+                            "startsAt": "2026-10-05T00:00:00.000Z",
+                            "endsAt": "2026-10-19T00:00:00.000Z",
+                            "completedAt": null,
+                            "progress": 0.25,
+                            "scopeHistory": [8.0, 20.0],
+                            "inProgressScopeHistory": [0.0, 6.0],
+                            "completedScopeHistory": [0.0, 5.0],
+                            "issueCountHistory": [4.0, 9.0],
+                            "completedIssueCountHistory": [0.0, 2.0]
+                        },
+                        {
+                            "id": "cycle-43",
+                            "number": 43.0,
+                            "name": null,
+                            "startsAt": "2026-10-19T00:00:00.000Z",
+                            "endsAt": "2026-11-02T00:00:00.000Z",
+                            "completedAt": null,
+                            "progress": 0.0,
+                            "scopeHistory": [],
+                            "inProgressScopeHistory": [],
+                            "completedScopeHistory": [],
+                            "issueCountHistory": [],
+                            "completedIssueCountHistory": []
+                        }
+                    ],
+                    "pageInfo": {
+                        "hasNextPage": false,
+                        "endCursor": null
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+pub fn mock_cycle_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "cycle": {
+                "id": "cycle-42",
+                "number": 42.0,
+                "name": "Launch prep",
+                "startsAt": "2026-10-05T00:00:00.000Z",
+                "endsAt": "2026-10-19T00:00:00.000Z",
+                "completedAt": null,
+                "progress": 0.25,
+                "scopeHistory": [8.0, 20.0],
+                "inProgressScopeHistory": [0.0, 6.0],
+                "completedScopeHistory": [0.0, 5.0],
+                "issueCountHistory": [4.0, 9.0],
+                "completedIssueCountHistory": [0.0, 2.0],
+                "team": {
+                    "id": "team-123",
+                    "key": "ENG",
+                    "name": "Engineering",
+                    "activeCycle": { "id": "cycle-42" }
+                },
+                "issues": {
+                    "nodes": [
+                        {
+                            "id": "issue-1",
+                            "identifier": "ENG-1",
+                            "title": "Ship the launch page",
+                            "estimate": 5.0,
+                            "state": { "id": "state-done-999", "name": "Done", "type": "completed" },
+                            "assignee": { "name": "Alice" }
+                        },
+                        {
+                            "id": "issue-2",
+                            "identifier": "ENG-2",
+                            "title": "Write release notes",
+                            "estimate": null,
+                            "state": { "id": "state-todo-123", "name": "Todo", "type": "unstarted" },
+                            "assignee": null
+                        }
+                    ],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                }
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 pub fn mock_team_states_minimal_response() -> serde_json::Value {
     serde_json::json!({
@@ -578,6 +748,7 @@ pub fn mock_projects_response() -> serde_json::Value {
                         "description": "iOS and Android mobile application",
                         "state": "active",
                         "progress": 0.75,
+                        "targetDate": "2023-09-30",
                         "url": "https://linear.app/project/proj-123",
                         "createdAt": "2023-01-01T00:00:00Z",
                         "updatedAt": "2023-06-01T00:00:00Z",
@@ -640,6 +811,161 @@ pub fn mock_projects_response() -> serde_json::Value {
     })
 }
 
+#[cfg(test)]
+pub fn mock_project_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "project": {
+                "id": "proj-123",
+                "name": "Mobile App",
+                "description": "iOS and Android mobile application",
+                "content": "## Goals\n\nShip the **beta** to testers.",
+                "state": "started",
+                "status": { "id": "status-started", "name": "In Progress" },
+                "progress": 0.5,
+                "health": "atRisk",
+                "startDate": "2023-01-09",
+                "targetDate": "2023-09-30",
+                "url": "https://linear.app/project/proj-123",
+                "createdAt": "2023-01-01T00:00:00Z",
+                "updatedAt": "2023-06-01T00:00:00Z",
+                "lead": { "id": "lead-456", "name": "Alice Smith", "displayName": "alice" },
+                "members": {
+                    "nodes": [
+                        { "id": "lead-456", "name": "Alice Smith", "displayName": "alice" },
+                        { "id": "user-789", "name": "Bob Johnson", "displayName": "bob" }
+                    ]
+                },
+                "teams": {
+                    "nodes": [{ "id": "team-123", "key": "ENG", "name": "Engineering" }]
+                },
+                "projectMilestones": {
+                    "nodes": [
+                        {
+                            "id": "milestone-2",
+                            "name": "GA",
+                            "description": null,
+                            "targetDate": null,
+                            "progress": 0.0,
+                            "status": "unstarted",
+                            "sortOrder": 2.0
+                        },
+                        {
+                            "id": "milestone-1",
+                            "name": "Beta",
+                            "description": "Feature complete for testers",
+                            "targetDate": "2023-07-01",
+                            "progress": 0.5,
+                            "status": "next",
+                            "sortOrder": 1.0
+                        }
+                    ]
+                },
+                "issues": {
+                    "nodes": [
+                        {
+                            "id": "issue-1",
+                            "identifier": "ENG-1",
+                            "title": "Build the login screen",
+                            "state": { "id": "state-done-999", "name": "Done", "type": "completed" },
+                            "assignee": { "name": "Alice Smith" }
+                        },
+                        {
+                            "id": "issue-2",
+                            "identifier": "ENG-2",
+                            "title": "Push notifications",
+                            "state": { "id": "state-todo-123", "name": "Todo", "type": "unstarted" },
+                            "assignee": null
+                        }
+                    ],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+pub fn mock_project_milestones_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "project": {
+                "projectMilestones": {
+                    "nodes": [
+                        {
+                            "id": "milestone-2",
+                            "name": "GA",
+                            "description": null,
+                            "targetDate": "2023-09-01",
+                            "progress": 0.0,
+                            "status": "unstarted",
+                            "sortOrder": 2.0
+                        },
+                        {
+                            "id": "milestone-1",
+                            "name": "Beta",
+                            "description": "Feature complete for testers",
+                            "targetDate": "2023-07-01",
+                            "progress": 1.0,
+                            "status": "done",
+                            "sortOrder": 1.0
+                        }
+                    ],
+                    "pageInfo": { "hasNextPage": false, "endCursor": null }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+pub fn mock_project_updates_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "project": {
+                "projectUpdates": {
+                    "nodes": [
+                        {
+                            "id": "update-1",
+                            "body": "Kicked off the beta.",
+                            "health": "onTrack",
+                            "url": "https://linear.app/project/proj-123/updates#update-1",
+                            "createdAt": "2023-05-01T09:00:00Z",
+                            "updatedAt": "2023-05-01T09:00:00Z",
+                            "user": { "name": "Alice Smith" }
+                        },
+                        {
+                            "id": "update-2",
+                            "body": "Push notifications are **blocked** on review.",
+                            "health": "atRisk",
+                            "url": "https://linear.app/project/proj-123/updates#update-2",
+                            "createdAt": "2023-05-08T09:00:00Z",
+                            "updatedAt": "2023-05-08T10:00:00Z",
+                            "user": { "name": "Alice Smith" }
+                        }
+                    ]
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+pub fn mock_project_statuses_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "projectStatuses": {
+                "nodes": [
+                    { "id": "status-backlog", "name": "Backlog", "type": "backlog" },
+                    { "id": "status-planned", "name": "Planned", "type": "planned" },
+                    { "id": "status-started", "name": "In Progress", "type": "started" },
+                    { "id": "status-done", "name": "Completed", "type": "completed" }
+                ]
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 pub fn mock_empty_projects_response() -> serde_json::Value {
     serde_json::json!({